@@ -1,26 +1,61 @@
-use iced::widget::{Space, center, column, container, row};
+use iced::widget::{Space, button, center, column, container, row};
 use iced::{Color, Element, Length};
 
-use iced_color_picker::{Hsv, HsvComponent, Spectrum, color_picker};
+use iced_color_picker::{
+    History, Hsv, HsvComponent, Spectrum, cmyk_sliders, color_button, color_picker,
+    component_inputs, hex_input, hue_triangle, style,
+};
 
 fn main() -> iced::Result {
     iced::run(State::update, State::view)
 }
 
 #[derive(Debug, Clone, Copy)]
-struct UpdateColor(Hsv);
+enum Message {
+    UpdateColor(Hsv),
+    /// A drag ended, so the next change gets an undo step of its own.
+    Commit,
+    Undo,
+    Redo,
+}
 
-#[derive(Debug, Default)]
+#[derive(Debug)]
 struct State {
     color: Hsv,
+    history: History<Hsv>,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            color: Hsv::default(),
+            history: History::new(Hsv::default()),
+        }
+    }
 }
 
 impl State {
-    pub fn update(&mut self, new_color: UpdateColor) {
-        self.color = new_color.0;
+    pub fn update(&mut self, message: Message) {
+        match message {
+            Message::UpdateColor(color) => {
+                self.color = color;
+                self.history.push(color);
+            }
+            Message::Commit => self.history.commit(),
+            Message::Undo => {
+                if let Some(color) = self.history.undo() {
+                    self.color = *color;
+                }
+            }
+            Message::Redo => {
+                if let Some(color) = self.history.redo() {
+                    self.color = *color;
+                }
+            }
+        }
     }
 
-    pub fn view(&self) -> Element<'_, UpdateColor> {
+    pub fn view(&self) -> Element<'_, Message> {
         let preview = container(Space::new().width(Length::Shrink))
             .style(|_| container::Style {
                 background: Some(Color::from(self.color).into()),
@@ -29,26 +64,65 @@ impl State {
             .width(250)
             .height(32);
 
-        let vertical_picker_sat = color_picker(self.color, UpdateColor)
+        let vertical_picker_sat = color_picker(self.color, Message::UpdateColor)
             .spectrum(Spectrum::new_vertical(HsvComponent::Saturation))
             .width(32)
             .height(250);
 
-        let vertical_picker_val = color_picker(self.color, UpdateColor)
+        let vertical_picker_val = color_picker(self.color, Message::UpdateColor)
             .spectrum(Spectrum::new_vertical(HsvComponent::Value))
             .width(32)
             .height(250);
 
-        let horizontal_hue_picker = color_picker(self.color, UpdateColor)
+        let horizontal_hue_picker = color_picker(self.color, Message::UpdateColor)
             .spectrum(Spectrum::new_horizontal(HsvComponent::Hue))
             .width(250)
             .height(32);
 
+        let horizontal_alpha_picker = color_picker(self.color, Message::UpdateColor)
+            .spectrum(Spectrum::new_horizontal(HsvComponent::Alpha))
+            .width(250)
+            .height(32);
+
+        // A saturation/value square paired with a hue bar. Both are bound to the same
+        // color, so a change made in either one is reflected in the other.
+        let sv_square = color_picker(self.color, Message::UpdateColor)
+            .spectrum(Spectrum::saturation_value())
+            .on_release(|_: Hsv| Message::Commit)
+            // Follow the theme with one of the editor styles
+            .style(
+                |theme: &iced::Theme| match theme.extended_palette().is_dark {
+                    true => style::editor_dark(theme),
+                    false => style::editor_light(theme),
+                },
+            )
+            .width(250)
+            .height(250);
+
+        let hue_bar = color_picker(self.color, Message::UpdateColor)
+            .spectrum(Spectrum::hue_vertical())
+            .width(32)
+            .height(250);
+
+        let undo = button("Undo").on_press_maybe(self.history.can_undo().then_some(Message::Undo));
+        let redo = button("Redo").on_press_maybe(self.history.can_redo().then_some(Message::Redo));
+
+        let triangle = hue_triangle(self.color, Message::UpdateColor).size(250);
+
         center(
             column![
-                preview,
                 row![
-                    color_picker(self.color, UpdateColor)
+                    preview,
+                    hex_input(self.color, Message::UpdateColor).width(100),
+                    color_button(self.color, Message::UpdateColor),
+                    undo,
+                    redo,
+                ]
+                .spacing(4),
+                row![sv_square, hue_bar].spacing(4),
+                triangle,
+                row![
+                    color_picker(self.color, Message::UpdateColor)
                         .spectrum(Spectrum::new_matrix(
                             HsvComponent::Value,
                             HsvComponent::Saturation
@@ -59,7 +133,10 @@ impl State {
                     vertical_picker_val,
                 ]
                 .spacing(4),
-                horizontal_hue_picker
+                horizontal_hue_picker,
+                horizontal_alpha_picker,
+                cmyk_sliders(self.color, Message::UpdateColor).width(250),
+                component_inputs(self.color, Message::UpdateColor).width(250),
             ]
             .spacing(4),
         )
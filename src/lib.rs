@@ -1,5 +1,49 @@
 pub mod widget;
 
-pub use widget::hsv::{self, Hsv, hsv, hsva};
-pub use widget::style::{self, Catalog, MarkerShape, Style, StyleFn};
-pub use widget::{ColorPicker, HsvComponent, Spectrum, color_picker};
+#[cfg(feature = "image")]
+pub use widget::SourceImage;
+pub use widget::cmyk::{self, Cmyk};
+pub use widget::cmyk_sliders::{self, CmykSliders, cmyk_sliders};
+#[cfg(feature = "widgets")]
+pub use widget::color_button::{self, ColorButton, color_button};
+#[cfg(feature = "widgets")]
+pub use widget::color_dialog::{self, ColorDialog, color_dialog};
+#[cfg(feature = "widgets")]
+pub use widget::color_field::{self, ColorField};
+#[cfg(feature = "widgets")]
+pub use widget::component_inputs::{self, ComponentInputs, component_inputs};
+#[cfg(feature = "widgets")]
+pub use widget::contrast_checker::{self, ContrastChecker, contrast_checker};
+pub use widget::css;
+pub use widget::display_p3::{self, DisplayP3};
+#[cfg(feature = "image")]
+pub use widget::eyedropper::{self, Eyedropper, eyedropper};
+pub use widget::harmony::{self, Harmony};
+pub use widget::harmony_wheel::{self, HarmonyWheel, harmony_wheel};
+#[cfg(feature = "widgets")]
+pub use widget::hex_input::{self, HexInput, hex_input};
+pub use widget::history::{self, History};
+pub use widget::hsl::{self, Hsl};
+pub use widget::hsv::{self, Hsv, HsvDelta, OutOfGamut, ParseError, TextSize, hsv, hsva};
+pub use widget::hue_triangle::{self, HueTriangle, hue_triangle};
+pub use widget::hwb::{self, Hwb};
+pub use widget::lab::{self, Lab, Lch};
+pub use widget::named;
+pub use widget::oklab::{self, Oklab};
+pub use widget::oklch::{self, Oklch};
+pub use widget::palette;
+#[cfg(feature = "widgets")]
+pub use widget::panel::{self, ColorPickerPanel, color_picker_panel};
+#[cfg(feature = "screen")]
+pub use widget::screen::{self, pick_from_screen};
+pub use widget::style::{
+    self, Catalog, Checkerboard, Contrast, Crosshair, MarkerShape, Style, StyleFn, Ticks,
+};
+pub use widget::swatch::{self, Swatch, swatch};
+pub use widget::swatch_grid::{self, SwatchGrid, swatch_grid};
+pub use widget::temperature::{self, color_temperature};
+pub use widget::vision::{self, ColorBlindness};
+pub use widget::{
+    ColorDrag, ColorModel, ColorPicker, Corner, Gamut, HsvComponent, MarkerFrame, Readout,
+    Spectrum, SpectrumRenderer, color_picker,
+};
@@ -1,17 +1,126 @@
 //! A widget to display and pick colors.
 
+pub mod cmyk;
+pub mod cmyk_sliders;
+#[cfg(feature = "widgets")]
+pub mod color_button;
+#[cfg(feature = "widgets")]
+pub mod color_dialog;
+#[cfg(feature = "widgets")]
+pub mod color_field;
+#[cfg(feature = "widgets")]
+pub mod component_inputs;
+#[cfg(feature = "widgets")]
+pub mod contrast_checker;
+pub mod css;
+pub mod display_p3;
+#[cfg(feature = "image")]
+pub mod eyedropper;
+pub mod harmony;
+pub mod harmony_wheel;
+#[cfg(feature = "widgets")]
+pub mod hex_input;
+pub mod history;
+pub mod hsl;
 pub mod hsv;
+pub mod hue_triangle;
+pub mod hwb;
+pub mod lab;
+mod mask;
+pub mod named;
+pub mod oklab;
+pub mod oklch;
+pub mod palette;
+#[cfg(feature = "widgets")]
+pub mod panel;
+#[cfg(feature = "screen")]
+pub mod screen;
+mod shader;
+#[cfg_attr(not(feature = "image"), allow(dead_code))]
+mod source_image;
 pub mod spectrums;
+mod srgb;
 pub mod style;
+pub mod swatch;
+pub mod swatch_grid;
+pub mod temperature;
+pub mod vision;
 
-pub use hsv::{Hsv, hsv};
-pub use spectrums::{HsvComponent, Spectrum};
+pub use cmyk::Cmyk;
+pub use cmyk_sliders::{CmykSliders, cmyk_sliders};
+#[cfg(feature = "widgets")]
+pub use color_button::{ColorButton, color_button};
+#[cfg(feature = "widgets")]
+pub use color_dialog::{ColorDialog, color_dialog};
+#[cfg(feature = "widgets")]
+pub use color_field::ColorField;
+#[cfg(feature = "widgets")]
+pub use component_inputs::{ComponentInputs, component_inputs};
+#[cfg(feature = "widgets")]
+pub use contrast_checker::{ContrastChecker, contrast_checker};
+pub use display_p3::DisplayP3;
+#[cfg(feature = "image")]
+pub use eyedropper::{Eyedropper, eyedropper};
+pub use harmony::Harmony;
+pub use harmony_wheel::{HarmonyWheel, harmony_wheel};
+#[cfg(feature = "widgets")]
+pub use hex_input::{HexInput, hex_input};
+pub use history::History;
+pub use hsl::Hsl;
+pub use hsv::{Hsv, HsvDelta, hsv};
+pub use hue_triangle::{HueTriangle, hue_triangle};
+pub use hwb::Hwb;
+pub use lab::{Lab, Lch};
+pub use oklab::Oklab;
+pub use oklch::Oklch;
+#[cfg(feature = "widgets")]
+pub use panel::{ColorPickerPanel, color_picker_panel};
+#[cfg(feature = "screen")]
+pub use screen::pick_from_screen;
+pub use shader::SpectrumRenderer;
+#[cfg(feature = "image")]
+pub use source_image::SourceImage;
+pub use spectrums::{ColorModel, Gamut, HsvComponent, Spectrum};
+pub use swatch::{Swatch, swatch};
+pub use swatch_grid::{SwatchGrid, swatch_grid};
+pub use temperature::color_temperature;
+pub use vision::ColorBlindness;
 
-use iced_core::widget::{Tree, Widget, tree};
-use iced_core::{Color, Element, Length, Point, Rectangle, Size, layout, mouse, touch};
-use iced_graphics::geometry::{self, Frame, Path};
+use iced_core::alignment::Vertical;
+use iced_core::border::{self, Radius};
+use iced_core::clipboard;
+use iced_core::keyboard::{self, key};
+use iced_core::time::{Duration, Instant};
+use iced_core::widget::{Id, Operation, Tree, Widget, operation, tree};
+use iced_core::{
+    Color, Element, Font, Length, Point, Rectangle, Shell, Size, Vector, layout, mouse, renderer,
+    text, touch, window,
+};
+use iced_graphics::geometry::{self, Fill, Frame, LineCap, LineJoin, Path, Stroke};
+use std::f32::consts::{FRAC_PI_2, PI};
+use std::num::NonZeroUsize;
 
-use style::{Catalog, MarkerShape, Style, StyleFn};
+use mask::Mask;
+use style::{Catalog, Checkerboard, Crosshair, MarkerShape, Style, StyleFn, Ticks};
+
+/// Height of the row of preset swatches.
+const PRESET_ROW_HEIGHT: f32 = 20.0;
+/// Space between the spectrum and the preset row, and between each swatch.
+const PRESET_SPACING: f32 = 4.0;
+/// Fraction of an axis the marker moves with each press of an arrow key.
+const KEY_STEP: f32 = 0.01;
+/// Pixels of a precise scroll, e.g. on a touchpad, counted as one line of a mouse wheel.
+const PIXELS_PER_LINE: f32 = 20.0;
+/// How many times slower the cursor picks while fine adjusting, with Shift or a second finger.
+const FINE_ADJUST: f32 = 10.0;
+/// How many times closer the view zooms with each line scrolled while holding Ctrl.
+const ZOOM_STEP: f32 = 1.25;
+/// How many times closer the view can be zoomed in at most.
+const MAX_ZOOM: f32 = 64.0;
+/// Changes smaller than this are treated as conversion noise when snapping to a step.
+const SNAP_EPSILON: f32 = 1e-4;
+/// How far in pixels a finger can move while being held before it drags instead.
+const LONG_PRESS_SLOP: f32 = 8.0;
 
 /// Creates a new [ColorPicker] with the current [Hsv] (or [Color]) value, and a closure to produce a message when a color is picked.
 pub fn color_picker<'a, Message, Theme, FromHsv>(
@@ -37,10 +146,48 @@ where
     height: Length,
     on_select: Box<dyn Fn(Hsv) -> Message + 'a>,
     on_select_alt: Option<Box<dyn Fn(Hsv) -> Message + 'a>>,
+    select_button: mouse::Button,
+    alt_button: Option<mouse::Button>,
+    long_press: Option<Duration>,
+    on_delta: Option<Box<dyn Fn(HsvDelta) -> Message + 'a>>,
+    on_select_raw: Option<Box<dyn Fn(f32, f32) -> Message + 'a>>,
+    on_gamut: Option<Box<dyn Fn(bool) -> Message + 'a>>,
+    commit_on_release: bool,
+    on_release: Option<Box<dyn Fn(Hsv) -> Message + 'a>>,
+    on_enter: Option<Box<dyn Fn() -> Message + 'a>>,
+    on_leave: Option<Box<dyn Fn() -> Message + 'a>>,
+    on_hover: Option<Box<dyn Fn(Hsv) -> Message + 'a>>,
+    on_drag: Option<Box<dyn Fn(ColorDrag) -> Message + 'a>>,
+    dropping: Option<Hsv>,
     spectrum: Spectrum,
+    spectrum_alt: Option<Spectrum>,
+    spectrum_base: Option<Hsv>,
+    default_color: Option<Hsv>,
+    exact_redraw: bool,
+    animation: Option<Duration>,
+    quantization: NonZeroUsize,
+    /// Draws the saturation/value spectrum with a shader, see [ColorPicker::shader].
+    shader: Option<shader::DrawShader>,
+    color_blindness: Option<ColorBlindness>,
+    zoomable: bool,
+    step: Option<Hsv>,
+    scroll_step: f32,
+    min_size: Size,
+    presets: Vec<Hsv>,
+    extra_markers: Vec<Hsv>,
+    preview_chip: Option<Corner>,
+    hover_readout: Option<Readout>,
+    source_image: Option<source_image::SourceImage>,
+    mask: Option<Mask>,
+    marker_with: Option<MarkerFn<'a>>,
+    disabled: bool,
+    id: Option<Id>,
     class: Theme::Class<'a>,
 }
 
+/// Draws a custom marker, see [ColorPicker::marker_with].
+type MarkerFn<'a> = Box<dyn Fn(&mut dyn MarkerFrame, Point, Hsv) + 'a>;
+
 impl<'a, Message, Theme> ColorPicker<'a, Message, Theme>
 where
     Theme: Catalog,
@@ -52,7 +199,41 @@ where
             height: Length::Fill,
             on_select: Box::new(on_select),
             on_select_alt: None,
+            select_button: mouse::Button::Left,
+            alt_button: Some(mouse::Button::Right),
+            long_press: None,
+            on_delta: None,
+            on_select_raw: None,
+            on_gamut: None,
+            commit_on_release: false,
+            on_release: None,
+            on_enter: None,
+            on_leave: None,
+            on_hover: None,
+            on_drag: None,
+            dropping: None,
             spectrum: Spectrum::default(),
+            spectrum_alt: None,
+            spectrum_base: None,
+            default_color: None,
+            exact_redraw: false,
+            animation: None,
+            quantization: spectrums::QUANTIZATION,
+            shader: None,
+            color_blindness: None,
+            zoomable: false,
+            step: None,
+            scroll_step: KEY_STEP,
+            min_size: Size::ZERO,
+            presets: Vec::new(),
+            extra_markers: Vec::new(),
+            preview_chip: None,
+            hover_readout: None,
+            source_image: None,
+            mask: None,
+            marker_with: None,
+            disabled: false,
+            id: None,
             class: Theme::default(),
         }
     }
@@ -63,6 +244,227 @@ where
         self
     }
 
+    /// Change the [Spectrum] used to pick colors with the right mouse button.
+    ///
+    /// This lets the same area pick different components depending on the button,
+    /// e.g. saturation and value with the left button and hue with the right.
+    /// The spectrum drawn is unchanged, while the marker follows the alternate
+    /// spectrum for as long as the right button is held.
+    ///
+    /// Has no effect unless [ColorPicker::on_select_alt] is set.
+    pub fn spectrum_alt(mut self, spectrum: Spectrum) -> Self {
+        self.spectrum_alt = Some(spectrum);
+        self
+    }
+
+    /// Draw the spectrum using a fixed base color for the components that aren't bound to an axis,
+    /// instead of the selected color.
+    ///
+    /// The marker still shows the selected color.
+    pub fn spectrum_base(mut self, base: impl Into<Hsv>) -> Self {
+        self.spectrum_base = Some(base.into());
+        self
+    }
+
+    /// Reset to the color when the spectrum is double-clicked, like many sliders do.
+    ///
+    /// The first click of the two picks as usual, before the second one resets.
+    pub fn default_color(mut self, color: impl Into<Hsv>) -> Self {
+        self.default_color = Some(color.into());
+        self
+    }
+
+    /// Redraw on any change to the color, no matter how small.
+    ///
+    /// By default, changes are compared at 8 bits per component and tenths of a degree of
+    /// hue, so floating point noise from repeated conversions doesn't cause needless redraws.
+    pub fn exact_redraw(mut self, exact: bool) -> Self {
+        self.exact_redraw = exact;
+        self
+    }
+
+    /// Slide the marker over to a color set from outside the picker, e.g. by an undo or a
+    /// text input, over the duration instead of jumping to it.
+    ///
+    /// Colors picked with the picker itself are still followed right away.
+    pub fn animate_marker(mut self, duration: Duration) -> Self {
+        self.animation = Some(duration);
+        self
+    }
+
+    /// Set the size in pixels of the blocks the spectrum is drawn with, 2 by default.
+    ///
+    /// Small pickers can afford coarser blocks, while large ones need finer blocks to hide
+    /// them. Sliders are drawn with gradients instead, which don't have blocks.
+    pub fn quantization(mut self, quantization: NonZeroUsize) -> Self {
+        self.quantization = quantization;
+        self
+    }
+
+    /// Draw the saturation/value spectrum per pixel with a shader, rather than as blocks,
+    /// when drawn with the `Renderer`, e.g. `iced::Renderer`.
+    ///
+    /// The renderer has to support custom primitives, like that of wgpu. Other spectra, and
+    /// pickers drawn with any other renderer, are still drawn as geometry.
+    #[cfg(feature = "shader")]
+    pub fn shader<Renderer>(mut self) -> Self
+    where
+        Renderer: iced_wgpu::primitive::Renderer + 'static,
+    {
+        self.shader = Some(shader::draw::<Renderer>);
+        self
+    }
+
+    /// Set the mouse buttons that pick with `on_select` and with `on_select_alt`, left and
+    /// right by default.
+    ///
+    /// Leave out the second button to have presses of it ignored, e.g. so right-clicking
+    /// can open a context menu. The middle button pans the view of a zoomable picker instead.
+    pub fn mouse_buttons(mut self, select: mouse::Button, alt: Option<mouse::Button>) -> Self {
+        self.select_button = select;
+        self.alt_button = alt;
+        self
+    }
+
+    /// Pick with `on_select_alt` when a finger is held still on the spectrum for the
+    /// duration, as touch has no right button.
+    ///
+    /// A finger that moves before then drags with `on_select` as usual, and one lifted
+    /// before then picks where it was pressed.
+    pub fn long_press(mut self, duration: Duration) -> Self {
+        self.long_press = Some(duration);
+        self
+    }
+
+    /// Let the spectrum be zoomed into by scrolling while holding Ctrl, and panned by
+    /// dragging with the middle mouse button, for precise picks in small pickers.
+    pub fn zoomable(mut self, zoomable: bool) -> Self {
+        self.zoomable = zoomable;
+        self
+    }
+
+    /// Snap every picked color to multiples of the given step, e.g. `Hsv { h: 5.0, s: 0.05, v: 0.05, a: 0.1 }`.
+    ///
+    /// A step of zero leaves that component as picked. Presets are published as they are.
+    pub fn step(mut self, step: Hsv) -> Self {
+        self.step = Some(step);
+        self
+    }
+
+    /// Set the fraction of the axis that each line scrolled over a 1-D spectrum moves the color by.
+    ///
+    /// Scrolling up increases the component bound to the axis. Defaults to `0.01`, and a
+    /// step of `0` leaves scrolling to the widgets around the [ColorPicker].
+    pub fn scroll_step(mut self, step: f32) -> Self {
+        self.scroll_step = step;
+        self
+    }
+
+    /// Show a row of preset swatches below the spectrum, which select their color when clicked.
+    ///
+    /// The row is added to the height of the [ColorPicker]. The swatch matching the
+    /// current color is highlighted.
+    pub fn presets(mut self, presets: impl IntoIterator<Item = impl Into<Hsv>>) -> Self {
+        self.presets = presets.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Show other colors on the spectrum, e.g. palette entries or comparison targets, as
+    /// small round markers that can't be dragged.
+    ///
+    /// They're placed by the components bound to the axes, like the marker of the picker,
+    /// and aren't shown over a source image.
+    pub fn extra_markers(mut self, markers: &[Hsv]) -> Self {
+        self.extra_markers = markers.to_vec();
+        self
+    }
+
+    /// Draw a small chip in a corner of the spectrum showing the current color.
+    ///
+    /// The chip doesn't take part in hit testing, so pressing it picks from the spectrum as
+    /// usual, unless the color can be dragged from it with [ColorPicker::on_drag].
+    pub fn show_preview_chip(mut self, corner: Corner) -> Self {
+        self.preview_chip = Some(corner);
+        self
+    }
+
+    /// Show the value of the color under the cursor next to it while hovering the spectrum,
+    /// before anything is picked.
+    pub fn show_hover_readout(mut self, readout: Readout) -> Self {
+        self.hover_readout = Some(readout);
+        self
+    }
+
+    /// Pick the color being dragged, if any, when it's dropped on the spectrum, see
+    /// [ColorPicker::on_drag].
+    pub fn accept_drop(mut self, dragged: Option<Hsv>) -> Self {
+        self.dropping = dragged;
+        self
+    }
+
+    /// Pick colors from an image, drawn in place of the spectrum.
+    ///
+    /// The marker is shown wherever the color was last picked, since a color may
+    /// appear anywhere in the image.
+    #[cfg(feature = "image")]
+    pub fn source_image(mut self, image: SourceImage) -> Self {
+        self.source_image = Some(image);
+        self
+    }
+
+    /// Draw the spectrum, marker and preview chip as they appear with a color vision
+    /// deficiency, so choices can be checked for color-blind users.
+    ///
+    /// Picked colors are left as they are, and a source image is drawn unchanged.
+    pub fn simulate(mut self, color_blindness: ColorBlindness) -> Self {
+        self.color_blindness = Some(color_blindness);
+        self
+    }
+
+    /// Only draw the spectrum inside of the given [Path], relative to the top left of the [ColorPicker].
+    ///
+    /// Presses outside of the mask are ignored.
+    pub fn mask(mut self, mask: Path) -> Self {
+        self.mask = Some(Mask::new(&mask));
+        self
+    }
+
+    /// Draw the marker with a closure instead of the [MarkerShape] of the [Style].
+    ///
+    /// The closure is given the position of the marker, relative to the top left of the
+    /// spectrum, and the color to show. Like the built-in markers, it's only redrawn when
+    /// the color changes.
+    pub fn marker_with(mut self, draw: impl Fn(&mut dyn MarkerFrame, Point, Hsv) + 'a) -> Self {
+        self.marker_with = Some(Box::new(draw));
+        self
+    }
+
+    /// Set both the width and height of the [ColorPicker].
+    ///
+    /// Later calls to [ColorPicker::width] or [ColorPicker::height] override this.
+    pub fn size(mut self, size: impl Into<Length>) -> Self {
+        let size = size.into();
+        self.width = size;
+        self.height = size;
+        self
+    }
+
+    /// Make the [ColorPicker] a square with sides of the given length in pixels.
+    ///
+    /// Later calls to [ColorPicker::width] or [ColorPicker::height] override this.
+    pub fn square(self, length: f32) -> Self {
+        self.size(Length::Fixed(length))
+    }
+
+    /// Set the minimum size of the spectrum, so it never shrinks too small to pick from.
+    ///
+    /// This applies to both [Length::Fill] and [Length::Shrink], though the [ColorPicker]
+    /// still never grows beyond the space its parent allows.
+    pub fn min_size(mut self, min_size: impl Into<Size>) -> Self {
+        self.min_size = min_size.into();
+        self
+    }
+
     /// Set the width of the [ColorPicker].
     pub fn width(mut self, width: impl Into<Length>) -> Self {
         self.width = width.into();
@@ -75,7 +477,8 @@ where
         self
     }
 
-    /// Set function that will be called when a color is picked with the right mouse button.
+    /// Set function that will be called when a color is picked with the right mouse button,
+    /// or the one bound with [ColorPicker::mouse_buttons].
     pub fn on_select_alt<FromHsv: From<Hsv>>(
         mut self,
         on_select_alt: impl Fn(FromHsv) -> Message + 'a,
@@ -84,6 +487,112 @@ where
         self
     }
 
+    /// Set function that will be called with the change from the previously published color,
+    /// alongside the regular `on_select` messages.
+    pub fn on_delta(mut self, on_delta: impl Fn(HsvDelta) -> Message + 'a) -> Self {
+        self.on_delta = Some(Box::new(on_delta));
+        self
+    }
+
+    /// Set function that will be called with the relative `(x, y)` position of the cursor,
+    /// each on `[0, 1]`, whenever a color is picked from the spectrum.
+    ///
+    /// Handy for diagnostics, or anchoring overlays to where the color was picked.
+    pub fn on_select_raw(mut self, on_select_raw: impl Fn(f32, f32) -> Message + 'a) -> Self {
+        self.on_select_raw = Some(Box::new(on_select_raw));
+        self
+    }
+
+    /// Set function that will be called with whether the color is within sRGB, whenever a
+    /// color is picked from the spectrum.
+    ///
+    /// Colors outside of sRGB, as found in [Oklch](ColorModel::Oklch) and
+    /// [Lch](ColorModel::Lch) spectra, are clamped before they're published, so this is
+    /// the only way to tell they were. See [Spectrum::gamut_warning] to show where they are.
+    pub fn on_gamut(mut self, on_gamut: impl Fn(bool) -> Message + 'a) -> Self {
+        self.on_gamut = Some(Box::new(on_gamut));
+        self
+    }
+
+    /// Only publish `on_select` (and `on_delta`) once a drag ends, rather than for every move.
+    ///
+    /// The marker still follows the cursor during the drag. Handy when every change of
+    /// color triggers expensive work.
+    pub fn commit_on_release(mut self, commit_on_release: bool) -> Self {
+        self.commit_on_release = commit_on_release;
+        self
+    }
+
+    /// Set function that will be called once a drag ends, with the final color picked.
+    ///
+    /// Useful to record an undo step, or persist the selection, once rather than for every
+    /// intermediate `on_select` message.
+    pub fn on_release<FromHsv: From<Hsv>>(
+        mut self,
+        on_release: impl Fn(FromHsv) -> Message + 'a,
+    ) -> Self {
+        self.on_release = Some(Box::new(move |color| on_release(color.into())));
+        self
+    }
+
+    /// Set the message produced when the cursor enters the [ColorPicker].
+    pub fn on_enter(mut self, message: Message) -> Self
+    where
+        Message: Clone + 'a,
+    {
+        self.on_enter = Some(Box::new(move || message.clone()));
+        self
+    }
+
+    /// Set the message produced when the cursor leaves the [ColorPicker], including when
+    /// it leaves the window.
+    pub fn on_leave(mut self, message: Message) -> Self
+    where
+        Message: Clone + 'a,
+    {
+        self.on_leave = Some(Box::new(move || message.clone()));
+        self
+    }
+
+    /// Set function that will be called with the color under the cursor as it moves over the
+    /// spectrum, without anything being pressed, e.g. to drive a live preview elsewhere.
+    ///
+    /// Pair it with [ColorPicker::on_leave] to know when the preview ends.
+    pub fn on_hover<FromHsv: From<Hsv>>(
+        mut self,
+        on_hover: impl Fn(FromHsv) -> Message + 'a,
+    ) -> Self {
+        self.on_hover = Some(Box::new(move |color| on_hover(color.into())));
+        self
+    }
+
+    /// Let the current color be dragged from the preview chip onto other widgets, e.g. a
+    /// [SwatchGrid] or another picker, producing a message as the drag starts and ends.
+    ///
+    /// Hold on to the color of a [ColorDrag::Started] drag until it has [ColorDrag::Ended],
+    /// and hand it to the widgets it can be dropped on, e.g. with [ColorPicker::accept_drop].
+    /// Only has an effect along with [ColorPicker::show_preview_chip].
+    pub fn on_drag(mut self, on_drag: impl Fn(ColorDrag) -> Message + 'a) -> Self {
+        self.on_drag = Some(Box::new(on_drag));
+        self
+    }
+
+    /// Disable the [ColorPicker], so it ignores input and is drawn dimmed, e.g. until a
+    /// checkbox enabling it is ticked.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Set the [Id] of the [ColorPicker], to focus it with an [Operation].
+    ///
+    /// Operations reading text also find the picker under it, described in words as
+    /// e.g. `color slider, hue from 0° to 360°: #408040, hsv(120.0, 50%, 50%)`.
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
     /// Set the [Style] of the [ColorPicker].
     pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
     where
@@ -98,13 +607,275 @@ where
         self.class = class;
         self
     }
+
+    /// Publish a picked color, followed by its delta if `on_delta` is set.
+    fn publish(
+        &self,
+        shell: &mut Shell<'_, Message>,
+        on_select: &dyn Fn(Hsv) -> Message,
+        last_published: &mut Option<Hsv>,
+        current_color: Hsv,
+        new_color: Hsv,
+    ) {
+        shell.publish(on_select(new_color));
+
+        if let Some(on_delta) = &self.on_delta {
+            let previous = last_published.unwrap_or(current_color);
+            shell.publish(on_delta(new_color.delta(previous)));
+        }
+
+        *last_published = Some(new_color);
+    }
+
+    /// Publish a color picked during a drag, or only preview it with the marker until
+    /// the drag ends when committing on release.
+    fn drag(
+        &self,
+        shell: &mut Shell<'_, Message>,
+        on_select: &dyn Fn(Hsv) -> Message,
+        last_published: &mut Option<Hsv>,
+        preview: &mut Option<Hsv>,
+        current_color: Hsv,
+        new_color: Hsv,
+    ) {
+        match self.commit_on_release {
+            true => *preview = Some(new_color),
+            false => self.publish(shell, on_select, last_published, current_color, new_color),
+        }
+    }
+
+    /// End a drag, publishing the previewed color if there is one, followed by the
+    /// final color if `on_release` is set.
+    fn release(
+        &self,
+        shell: &mut Shell<'_, Message>,
+        on_select: Option<&dyn Fn(Hsv) -> Message>,
+        last_published: &mut Option<Hsv>,
+        preview: &mut Option<Hsv>,
+        current_color: Hsv,
+    ) {
+        if let Some(color) = preview.take()
+            && let Some(on_select) = on_select
+        {
+            self.publish(shell, on_select, last_published, current_color, color);
+        }
+
+        if let Some(on_release) = &self.on_release {
+            shell.publish(on_release(last_published.unwrap_or(current_color)));
+        }
+    }
+
+    /// Publish where the cursor picked from the spectrum, if `on_select_raw` is set, and
+    /// whether the color there is within sRGB, if `on_gamut` is set.
+    fn publish_raw(
+        &self,
+        shell: &mut Shell<'_, Message>,
+        spectrum: Spectrum,
+        current_color: Hsv,
+        bounds: Rectangle,
+        cursor: Point,
+    ) {
+        if let Some(on_select_raw) = &self.on_select_raw
+            && let Some((x, y)) = spectrum.fetch_percent(bounds, cursor)
+        {
+            shell.publish(on_select_raw(x, y));
+        }
+
+        // Images are sampled in sRGB, so they never leave it
+        if let Some(on_gamut) = &self.on_gamut {
+            let in_gamut = self.source_image.is_some()
+                || spectrum.fetch_in_gamut(current_color, bounds, cursor);
+
+            shell.publish(on_gamut(in_gamut));
+        }
+    }
+
+    /// Describes the picker in words, as read out by assistive technology: what it picks
+    /// along each axis, and the color it holds in hex and HSV.
+    fn description(&self) -> String {
+        let color = format!("{}, {}", self.color.to_hex(self.color.a < 1.0), self.color);
+
+        if self.source_image.is_some() {
+            return format!("image color picker: {color}");
+        }
+
+        let axes = self.spectrum.axis_ranges(self.color);
+        let role = match axes.len() {
+            1 => "color slider",
+            _ => "color area",
+        };
+        let axes = axes
+            .into_iter()
+            .map(|(component, start, end)| {
+                format!(
+                    "{} from {} to {}",
+                    component_name(component),
+                    component_value(component, start),
+                    component_value(component, end),
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(" by ");
+
+        format!("{role}, {axes}: {color}")
+    }
+
+    /// Picks the color under the cursor, from the source image if there is one.
+    fn fetch(
+        &self,
+        spectrum: Spectrum,
+        current_color: Hsv,
+        bounds: Rectangle,
+        cursor: Point,
+        picked_at: &mut Option<(f32, f32)>,
+    ) -> Hsv {
+        *picked_at = spectrum.fetch_percent(bounds, cursor);
+
+        self.peek(spectrum, current_color, bounds, cursor)
+    }
+
+    /// The color the cursor would pick from the spectrum or image, without picking it.
+    fn peek(
+        &self,
+        spectrum: Spectrum,
+        current_color: Hsv,
+        bounds: Rectangle,
+        cursor: Point,
+    ) -> Hsv {
+        let color = match &self.source_image {
+            Some(image) => image.sample(bounds, cursor).unwrap_or(current_color),
+            None => spectrum.fetch_hsv(current_color, bounds, cursor),
+        };
+
+        self.snap(color, None)
+    }
+
+    /// Snaps the color to the step, if there is one.
+    ///
+    /// With a `previous` color, components that moved are rounded in the direction they
+    /// moved, so small nudges, e.g. from the arrow keys, are never undone by the rounding.
+    fn snap(&self, color: Hsv, previous: Option<Hsv>) -> Hsv {
+        let Some(step) = self.step else {
+            return color;
+        };
+
+        let snap = |value: f32, from: Option<f32>, step: f32| {
+            if step <= 0.0 {
+                return value;
+            }
+
+            let steps = value / step;
+            let steps = match from {
+                Some(from) if value - from > SNAP_EPSILON => steps.ceil(),
+                Some(from) if from - value > SNAP_EPSILON => steps.floor(),
+                _ => steps.round(),
+            };
+
+            steps * step
+        };
+
+        Hsv {
+            h: snap(color.h, previous.map(|p| p.h), step.h).rem_euclid(360.0),
+            s: snap(color.s, previous.map(|p| p.s), step.s).clamp(0.0, 1.0),
+            v: snap(color.v, previous.map(|p| p.v), step.v).clamp(0.0, 1.0),
+            a: snap(color.a, previous.map(|p| p.a), step.a).clamp(0.0, 1.0),
+        }
+    }
+
+    /// The [Spectrum] that maps the cursor to a color for the given press, within the view.
+    fn active_spectrum(&self, pressed: Option<Pressed>, view: View) -> Spectrum {
+        let spectrum = match pressed {
+            Some(Pressed::Secondary | Pressed::FingerAlt(_)) => {
+                self.spectrum_alt.unwrap_or(self.spectrum)
+            }
+            _ => self.spectrum,
+        };
+
+        spectrum
+            .zoomed(view.x, view.y)
+            .simulated(self.color_blindness)
+    }
+
+    /// Returns true if the point is over the preview chip, while the color can be dragged from it.
+    fn is_over_chip(&self, bounds: Rectangle, point: Point) -> bool {
+        self.on_drag.is_some()
+            && self.preview_chip.is_some_and(|corner| {
+                chip_bounds(corner, bounds.size()).contains(point - Vector::new(bounds.x, bounds.y))
+            })
+    }
+
+    /// The color as it's drawn, i.e. as it appears with the simulated color blindness.
+    fn displayed(&self, color: Color) -> Color {
+        let color = self.spectrum.clamp_to_srgb(color);
+
+        match self.color_blindness {
+            Some(color_blindness) => color_blindness.simulate(color),
+            None => color,
+        }
+    }
+
+    /// Splits the bounds of the widget into the spectrum and the preset row, if there is one.
+    fn split_bounds(&self, bounds: Rectangle) -> (Rectangle, Option<Rectangle>) {
+        if self.presets.is_empty() {
+            return (bounds, None);
+        }
+
+        let row_height = PRESET_ROW_HEIGHT.min(bounds.height);
+
+        let spectrum = Rectangle {
+            height: (bounds.height - row_height - PRESET_SPACING).max(0.0),
+            ..bounds
+        };
+
+        let row = Rectangle {
+            y: bounds.y + bounds.height - row_height,
+            height: row_height,
+            ..bounds
+        };
+
+        (spectrum, Some(row))
+    }
+
+    /// The presets paired with the bounds of their swatch in the preset row.
+    fn preset_swatches(&self, row: Rectangle) -> impl Iterator<Item = (Hsv, Rectangle)> + '_ {
+        let count = self.presets.len() as f32;
+        let width = ((row.width - PRESET_SPACING * (count - 1.0)) / count).max(0.0);
+
+        self.presets.iter().enumerate().map(move |(i, preset)| {
+            let bounds = Rectangle {
+                x: row.x + i as f32 * (width + PRESET_SPACING),
+                width,
+                ..row
+            };
+
+            (*preset, bounds)
+        })
+    }
+
+    /// Returns the preset under the point, if any.
+    fn preset_at(&self, bounds: Rectangle, point: Point) -> Option<Hsv> {
+        let (_, row) = self.split_bounds(bounds);
+
+        self.preset_swatches(row?)
+            .find(|(_, swatch)| swatch.contains(point))
+            .map(|(preset, _)| preset)
+    }
+
+    /// Returns true if the point lands on the visible part of the spectrum.
+    fn is_over(&self, bounds: Rectangle, point: Point) -> bool {
+        bounds.contains(point)
+            && self
+                .mask
+                .as_ref()
+                .is_none_or(|mask| mask.contains(point - (bounds.position() - Point::ORIGIN)))
+    }
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for ColorPicker<'a, Message, Theme>
 where
     Theme: Catalog,
-    Renderer: geometry::Renderer + 'static,
+    Renderer: SpectrumRenderer + 'static,
 {
     fn size(&self) -> Size<Length> {
         Size::new(self.width, self.height)
@@ -115,7 +886,15 @@ where
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(State::<Renderer>::default())
+        // Start from the color and settings of the widget, so the first frame is drawn
+        // right even if it comes before any event
+        tree::State::new(State::<Renderer> {
+            current_color: self.color,
+            spectrum_base: self.spectrum_base,
+            quantization: self.quantization,
+            color_blindness: self.color_blindness,
+            ..State::default()
+        })
     }
 
     fn layout(
@@ -124,19 +903,74 @@ where
         _renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        layout::atomic(limits, self.width, self.height)
+        // Reserve room for the preset row below the spectrum
+        let row = match self.presets.is_empty() {
+            true => Size::ZERO,
+            false => Size::new(0.0, PRESET_ROW_HEIGHT + PRESET_SPACING),
+        };
+
+        let limits = limits.shrink(row);
+        let spectrum = layout::atomic(&limits, self.width, self.height).size();
+
+        // Grow up to the minimum size, as far as the limits allow
+        let spectrum = spectrum.max(self.min_size).min(limits.max());
+
+        layout::Node::new(spectrum + row)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: layout::Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        // Lets assistive technology and tests read out the picker, even while disabled
+        operation.text(self.id.as_ref(), layout.bounds(), &self.description());
+
+        if self.disabled {
+            return;
+        }
+
+        let state: &mut State<Renderer> = tree.state.downcast_mut();
+
+        // Lets the picker take part in focus navigation, e.g. with `focus_next` on Tab
+        operation.focusable(self.id.as_ref(), layout.bounds(), state);
     }
 
     fn mouse_interaction(
         &self,
-        _state: &Tree,
+        tree: &Tree,
         layout: layout::Layout<'_>,
         cursor: mouse::Cursor,
         _viewport: &Rectangle,
         _renderer: &Renderer,
     ) -> mouse::Interaction {
-        if cursor.is_over(layout.bounds()) {
+        let state: &State<Renderer> = tree.state.downcast_ref();
+
+        if state.panning.is_some() || matches!(state.pressed, Some(Pressed::Carrying)) {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let Some(cursor) = cursor.position() else {
+            return Default::default();
+        };
+
+        let (bounds, _) = self.split_bounds(layout.bounds());
+
+        if self.disabled {
+            match layout.bounds().contains(cursor) {
+                true => mouse::Interaction::NotAllowed,
+                false => Default::default(),
+            }
+        } else if self.is_over_chip(bounds, cursor) {
+            mouse::Interaction::Grab
+        } else if self.is_over(bounds, cursor) && self.dropping.is_some() {
+            mouse::Interaction::Copy
+        } else if self.is_over(bounds, cursor) {
             mouse::Interaction::Crosshair
+        } else if self.preset_at(layout.bounds(), cursor).is_some() {
+            mouse::Interaction::Pointer
         } else {
             Default::default()
         }
@@ -149,104 +983,804 @@ where
         layout: layout::Layout<'_>,
         cursor: mouse::Cursor,
         _renderer: &Renderer,
-        shell: &mut iced_core::Shell<'_, Message>,
+        shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) {
         let State {
             spectrum_cache,
             pressed,
             current_color,
+            spectrum_base,
+            quantization,
+            color_blindness,
+            last_published,
+            hovered,
+            picked_at,
             marker_cache,
+            focused,
+            modifiers,
+            fine_anchor,
+            second_finger,
+            preview,
+            view,
+            panning,
+            hover,
+            extra_markers,
+            transition,
+            last_click,
         }: &mut State<Renderer> = tree.state.downcast_mut();
 
-        let cursor_in_bounds = cursor.is_over(layout.bounds());
-        let bounds = layout.bounds();
+        let (bounds, _) = self.split_bounds(layout.bounds());
+        let cursor_in_bounds = cursor
+            .position()
+            .is_some_and(|cursor| self.is_over(bounds, cursor));
+        let cursor_preset = cursor
+            .position()
+            .and_then(|cursor| self.preset_at(layout.bounds(), cursor));
+
+        // An image or a fixed base doesn't change with the color
+        let drawn_from_color = self.spectrum_base.is_none() && self.source_image.is_none();
+
+        let previous_color = *current_color;
 
         if diff(
-            self.spectrum,
             spectrum_cache,
             marker_cache,
             current_color,
             self.color,
+            self.exact_redraw,
+            drawn_from_color.then_some(&self.spectrum),
         ) {
             shell.request_redraw();
+
+            // Only a color that wasn't just picked here came from outside the picker
+            if self.animation.is_some()
+                && pressed.is_none()
+                && *last_published != Some(self.color)
+                && self.source_image.is_none()
+            {
+                let size = bounds.size();
+                let spectrum = self.active_spectrum(None, *view);
+
+                // An interrupted transition carries on from wherever the marker got to
+                let previous = spectrum.get_marker_pos(previous_color, size);
+                let from = match transition {
+                    Some(transition) => transition.position(previous, size),
+                    None => previous,
+                };
+
+                *transition = Some(Transition {
+                    from: (from.x / size.width, from.y / size.height),
+                    started: Instant::now(),
+                    progress: 0.0,
+                });
+            }
         }
 
-        match event {
-            iced_core::Event::Mouse(mouse_event) => match mouse_event {
-                mouse::Event::ButtonReleased(mouse_button) => match (mouse_button, *pressed) {
-                    (mouse::Button::Left, Some(Pressed::Primary)) => *pressed = None,
-                    (mouse::Button::Right, Some(Pressed::Secondary)) => *pressed = None,
-                    _ => (),
-                },
-                mouse::Event::ButtonPressed(mouse_button)
-                    if cursor_in_bounds && pressed.is_none() =>
-                {
-                    let Some(cursor) = cursor.position() else {
-                        return;
-                    };
+        if let iced_core::Event::Window(window::Event::RedrawRequested(now)) = event
+            && let Some(Transition {
+                started, progress, ..
+            }) = transition
+        {
+            let duration = self.animation.unwrap_or_default().as_secs_f32();
+            *progress = now.saturating_duration_since(*started).as_secs_f32() / duration;
 
-                    let (new_pressed, on_select) = match mouse_button {
-                        mouse::Button::Left => (Pressed::Primary, Some(self.on_select.as_ref())),
-                        mouse::Button::Right => (Pressed::Secondary, self.on_select_alt.as_deref()),
-                        _ => return,
-                    };
+            // A drag takes over from the transition
+            if *progress < 1.0 && pressed.is_none() {
+                shell.request_redraw();
+            } else {
+                *transition = None;
+            }
 
-                    if let Some(on_select) = on_select {
-                        *pressed = Some(new_pressed);
+            marker_cache.clear();
+        }
 
-                        let new_color = self.spectrum.fetch_hsv(*current_color, bounds, cursor);
-                        shell.publish((on_select)(new_color))
-                    }
-                }
-                mouse::Event::CursorMoved { .. } => {
-                    if let Some(cursor) = cursor.position()
-                        && let Some(cursor_down) = pressed
-                    {
-                        let new_color = self.spectrum.fetch_hsv(*current_color, bounds, cursor);
-
-                        match cursor_down {
-                            Pressed::Primary => shell.publish((self.on_select)(new_color)),
-                            Pressed::Secondary => {
-                                if let Some(on_select_alt) = &self.on_select_alt {
-                                    shell.publish(on_select_alt(new_color))
-                                }
-                            }
-                            _ => (),
-                        };
-                    }
-                }
-                _ => (),
-            },
-            iced_core::Event::Touch(touch_event) => match touch_event {
-                touch::Event::FingerPressed { id, position } => {
-                    if bounds.contains(*position) && pressed.is_none() {
-                        *pressed = Some(Pressed::Finger(id.0));
+        if *spectrum_base != self.spectrum_base
+            || *quantization != self.quantization
+            || *color_blindness != self.color_blindness
+        {
+            *spectrum_base = self.spectrum_base;
+            *quantization = self.quantization;
+            *color_blindness = self.color_blindness;
+            spectrum_cache.clear();
+            shell.request_redraw();
+        }
 
-                        let new_color = self.spectrum.fetch_hsv(*current_color, bounds, *position);
-                        shell.publish((self.on_select)(new_color));
-                    }
+        if *extra_markers != self.extra_markers {
+            extra_markers.clone_from(&self.extra_markers);
+            marker_cache.clear();
+            shell.request_redraw();
+        }
+
+        // A drag in progress is let go of without publishing, and nothing new can be picked
+        if self.disabled {
+            if pressed.is_some() || preview.is_some() || *focused || hover.is_some() {
+                if let Some(Pressed::Carrying) = pressed
+                    && let Some(on_drag) = &self.on_drag
+                {
+                    shell.publish(on_drag(ColorDrag::Ended));
                 }
-                touch::Event::FingerMoved { id, position } => {
-                    if let Some(Pressed::Finger(finger_id)) = *pressed
-                        && id.0 == finger_id
-                    {
-                        let new_color = self.spectrum.fetch_hsv(*current_color, bounds, *position);
-                        shell.publish((self.on_select)(new_color));
-                    }
+
+                *pressed = None;
+                *preview = None;
+                *focused = false;
+                *hover = None;
+
+                marker_cache.clear();
+                shell.request_redraw();
+            }
+
+            return;
+        }
+
+        if let iced_core::Event::Mouse(
+            mouse::Event::CursorMoved { .. } | mouse::Event::CursorLeft,
+        ) = event
+        {
+            // The cursor is unavailable once it has left the window
+            let is_hovered = cursor.is_over(layout.bounds());
+
+            if is_hovered != *hovered {
+                *hovered = is_hovered;
+
+                let on_hover = match is_hovered {
+                    true => &self.on_enter,
+                    false => &self.on_leave,
+                };
+
+                if let Some(on_hover) = on_hover {
+                    shell.publish(on_hover());
                 }
-                touch::Event::FingerLifted { id, .. } => {
-                    if let Some(Pressed::Finger(finger_id)) = *pressed
-                        && id.0 == finger_id
-                    {
+            }
+        }
+
+        if let iced_core::Event::Keyboard(keyboard::Event::ModifiersChanged(new_modifiers)) = event
+        {
+            *modifiers = *new_modifiers;
+        }
+
+        // Pressing the spectrum focuses the picker, while pressing anywhere else lets go of it
+        let was_focused = *focused;
+
+        match event {
+            iced_core::Event::Mouse(mouse::Event::ButtonPressed(_)) => *focused = cursor_in_bounds,
+            iced_core::Event::Touch(touch::Event::FingerPressed { position, .. }) => {
+                *focused = self.is_over(bounds, *position);
+            }
+            _ => (),
+        }
+
+        // The focus ring is drawn outside of the caches
+        if *focused != was_focused {
+            shell.request_redraw();
+        }
+
+        let previously_picked_at = *picked_at;
+        let previous_preview = *preview;
+
+        match event {
+            iced_core::Event::Mouse(mouse_event) => match mouse_event {
+                mouse::Event::WheelScrolled { delta }
+                    if self.zoomable && modifiers.command() && cursor_in_bounds =>
+                {
+                    let Some(cursor) = cursor.position() else {
+                        return;
+                    };
+
+                    let at = (
+                        (cursor.x - bounds.x) / bounds.width,
+                        (cursor.y - bounds.y) / bounds.height,
+                    );
+                    let zoomed = view.zoom(self.spectrum, ZOOM_STEP.powf(scroll_lines(*delta)), at);
+
+                    if zoomed != *view {
+                        *view = zoomed;
+                        spectrum_cache.clear();
+                        marker_cache.clear();
+                        shell.request_redraw();
+                    }
+
+                    shell.capture_event();
+                }
+                mouse::Event::ButtonPressed(mouse::Button::Middle)
+                    if self.zoomable && cursor_in_bounds && panning.is_none() =>
+                {
+                    *panning = cursor.position();
+                    shell.capture_event();
+                }
+                mouse::Event::ButtonReleased(mouse::Button::Middle) if panning.is_some() => {
+                    *panning = None;
+                    shell.capture_event();
+                }
+                mouse::Event::ButtonReleased(_)
+                    if pressed.is_none() && cursor_in_bounds && self.dropping.is_some() =>
+                {
+                    let Some(dropped) = self.dropping else {
+                        return;
+                    };
+
+                    *last_published = None;
+                    self.publish(
+                        shell,
+                        self.on_select.as_ref(),
+                        last_published,
+                        *current_color,
+                        dropped,
+                    );
+                }
+                mouse::Event::ButtonReleased(mouse_button) => match *pressed {
+                    Some(Pressed::Carrying) if *mouse_button == self.select_button => {
+                        *pressed = None;
+
+                        if let Some(on_drag) = &self.on_drag {
+                            shell.publish(on_drag(ColorDrag::Ended));
+                        }
+                    }
+                    Some(Pressed::Primary) if *mouse_button == self.select_button => {
                         *pressed = None;
+                        self.release(
+                            shell,
+                            Some(self.on_select.as_ref()),
+                            last_published,
+                            preview,
+                            *current_color,
+                        );
+                    }
+                    Some(Pressed::Secondary) if Some(*mouse_button) == self.alt_button => {
+                        *pressed = None;
+                        self.release(
+                            shell,
+                            self.on_select_alt.as_deref(),
+                            last_published,
+                            preview,
+                            *current_color,
+                        );
+
+                        // The marker goes back to the primary spectrum
+                        if self.spectrum_alt.is_some() {
+                            marker_cache.clear();
+                            shell.request_redraw();
+                        }
+                    }
+                    _ => (),
+                },
+                mouse::Event::ButtonPressed(mouse_button)
+                    if *mouse_button == self.select_button
+                        && pressed.is_none()
+                        && cursor
+                            .position()
+                            .is_some_and(|cursor| self.is_over_chip(bounds, cursor)) =>
+                {
+                    let Some(on_drag) = &self.on_drag else {
+                        return;
+                    };
+
+                    *pressed = Some(Pressed::Carrying);
+                    shell.publish(on_drag(ColorDrag::Started(*current_color)));
+                    shell.capture_event();
+                }
+                mouse::Event::ButtonPressed(mouse_button)
+                    if *mouse_button == self.select_button
+                        && pressed.is_none()
+                        && cursor_preset.is_some() =>
+                {
+                    let Some(preset) = cursor_preset else {
+                        return;
+                    };
+
+                    *last_published = None;
+                    self.publish(
+                        shell,
+                        self.on_select.as_ref(),
+                        last_published,
+                        *current_color,
+                        preset,
+                    );
+                }
+                mouse::Event::ButtonPressed(mouse_button)
+                    if cursor_in_bounds && pressed.is_none() =>
+                {
+                    let Some(cursor) = cursor.position() else {
+                        return;
+                    };
+
+                    if *mouse_button == self.select_button {
+                        let click = mouse::Click::new(cursor, *mouse_button, *last_click);
+                        *last_click = Some(click);
+
+                        if let Some(default_color) = self.default_color
+                            && click.kind() == mouse::click::Kind::Double
+                        {
+                            *last_published = None;
+                            self.publish(
+                                shell,
+                                self.on_select.as_ref(),
+                                last_published,
+                                *current_color,
+                                default_color,
+                            );
+                            return;
+                        }
+                    }
+
+                    let (new_pressed, on_select) = if *mouse_button == self.select_button {
+                        (Pressed::Primary, Some(self.on_select.as_ref()))
+                    } else if Some(*mouse_button) == self.alt_button {
+                        (Pressed::Secondary, self.on_select_alt.as_deref())
+                    } else {
+                        return;
+                    };
+
+                    if let Some(on_select) = on_select {
+                        *pressed = Some(new_pressed);
+                        *last_published = None;
+                        *fine_anchor = None;
+                        marker_cache.clear();
+
+                        let new_color = self.fetch(
+                            self.active_spectrum(*pressed, *view),
+                            *current_color,
+                            bounds,
+                            cursor,
+                            picked_at,
+                        );
+                        self.drag(
+                            shell,
+                            on_select,
+                            last_published,
+                            preview,
+                            *current_color,
+                            new_color,
+                        );
+                        self.publish_raw(
+                            shell,
+                            self.active_spectrum(*pressed, *view),
+                            *current_color,
+                            bounds,
+                            cursor,
+                        );
+                    }
+                }
+                mouse::Event::CursorMoved { position } if panning.is_some() => {
+                    let Some(last) = panning.replace(*position) else {
+                        return;
+                    };
+
+                    // The view follows the cursor, so what's under it stays under it
+                    let panned = view.pan(
+                        (position.x - last.x) / bounds.width,
+                        (position.y - last.y) / bounds.height,
+                    );
+
+                    if panned != *view {
+                        *view = panned;
+                        spectrum_cache.clear();
+                        marker_cache.clear();
+                        shell.request_redraw();
+                    }
+
+                    shell.capture_event();
+                }
+                mouse::Event::WheelScrolled { delta }
+                    if cursor_in_bounds
+                        && pressed.is_none()
+                        && self.scroll_step != 0.0
+                        && self.source_image.is_none() =>
+                {
+                    let Some(new_color) = self
+                        .active_spectrum(None, *view)
+                        .step_component(*current_color, scroll_lines(*delta) * self.scroll_step)
+                    else {
+                        return;
+                    };
+                    let new_color = self.snap(new_color, Some(*current_color));
+
+                    // Scrolling past either end is left to other widgets, e.g. a scrollable
+                    if new_color != *current_color {
+                        shell.capture_event();
+
+                        *last_published = None;
+                        self.publish(
+                            shell,
+                            self.on_select.as_ref(),
+                            last_published,
+                            *current_color,
+                            new_color,
+                        );
+                    }
+                }
+                // Follow the event rather than the cursor, which may be unavailable once it
+                // leaves the widget for another layer; picks past the edges are clamped.
+                mouse::Event::CursorMoved { position } => {
+                    if let Some(cursor_down) = *pressed
+                        && !matches!(cursor_down, Pressed::Carrying)
+                    {
+                        let cursor = fine_adjust(*position, modifiers.shift(), fine_anchor);
+
+                        let new_color = self.fetch(
+                            self.active_spectrum(*pressed, *view),
+                            *current_color,
+                            bounds,
+                            cursor,
+                            picked_at,
+                        );
+
+                        let on_select = match cursor_down {
+                            Pressed::Primary => Some(self.on_select.as_ref()),
+                            Pressed::Secondary => self.on_select_alt.as_deref(),
+                            _ => None,
+                        };
+
+                        if let Some(on_select) = on_select {
+                            self.drag(
+                                shell,
+                                on_select,
+                                last_published,
+                                preview,
+                                *current_color,
+                                new_color,
+                            );
+                            self.publish_raw(
+                                shell,
+                                self.active_spectrum(*pressed, *view),
+                                *current_color,
+                                bounds,
+                                cursor,
+                            );
+                        }
                     }
                 }
                 _ => (),
             },
+            iced_core::Event::Touch(touch_event) => match touch_event {
+                touch::Event::FingerPressed { position, .. }
+                    if pressed.is_none()
+                        && self.preset_at(layout.bounds(), *position).is_some() =>
+                {
+                    let Some(preset) = self.preset_at(layout.bounds(), *position) else {
+                        return;
+                    };
+
+                    *last_published = None;
+                    self.publish(
+                        shell,
+                        self.on_select.as_ref(),
+                        last_published,
+                        *current_color,
+                        preset,
+                    );
+                }
+                touch::Event::FingerPressed { id, .. }
+                    if matches!(*pressed, Some(Pressed::Finger(_) | Pressed::FingerAlt(_)))
+                        && second_finger.is_none() =>
+                {
+                    *second_finger = Some(id.0);
+                }
+                touch::Event::FingerPressed { id, position }
+                    if self.is_over(bounds, *position)
+                        && pressed.is_none()
+                        && self.long_press.is_some()
+                        && self.on_select_alt.is_some() =>
+                {
+                    let Some(duration) = self.long_press else {
+                        return;
+                    };
+
+                    // Nothing is picked until the finger moves, is lifted or has been held
+                    let since = Instant::now();
+
+                    *pressed = Some(Pressed::Holding {
+                        id: id.0,
+                        at: *position,
+                        since,
+                    });
+                    shell.request_redraw_at(since + duration);
+                }
+                touch::Event::FingerPressed { id, position } => {
+                    if self.is_over(bounds, *position) && pressed.is_none() {
+                        *pressed = Some(Pressed::Finger(id.0));
+                        *last_published = None;
+                        *fine_anchor = None;
+                        *second_finger = None;
+
+                        let new_color = self.fetch(
+                            self.active_spectrum(*pressed, *view),
+                            *current_color,
+                            bounds,
+                            *position,
+                            picked_at,
+                        );
+                        self.drag(
+                            shell,
+                            self.on_select.as_ref(),
+                            last_published,
+                            preview,
+                            *current_color,
+                            new_color,
+                        );
+                        self.publish_raw(
+                            shell,
+                            self.active_spectrum(*pressed, *view),
+                            *current_color,
+                            bounds,
+                            *position,
+                        );
+                    }
+                }
+                touch::Event::FingerMoved { id, position } => {
+                    // A finger moving before it's been held for long enough drags as usual
+                    if let Some(Pressed::Holding {
+                        id: holding, at, ..
+                    }) = *pressed
+                        && id.0 == holding
+                        && position.distance(at) > LONG_PRESS_SLOP
+                    {
+                        *pressed = Some(Pressed::Finger(holding));
+                        *last_published = None;
+                    }
+
+                    let on_select = match *pressed {
+                        Some(Pressed::Finger(finger_id)) if id.0 == finger_id => {
+                            Some(self.on_select.as_ref())
+                        }
+                        Some(Pressed::FingerAlt(finger_id)) if id.0 == finger_id => {
+                            self.on_select_alt.as_deref()
+                        }
+                        _ => None,
+                    };
+
+                    if let Some(on_select) = on_select {
+                        let position = fine_adjust(*position, second_finger.is_some(), fine_anchor);
+
+                        let new_color = self.fetch(
+                            self.active_spectrum(*pressed, *view),
+                            *current_color,
+                            bounds,
+                            position,
+                            picked_at,
+                        );
+                        self.drag(
+                            shell,
+                            on_select,
+                            last_published,
+                            preview,
+                            *current_color,
+                            new_color,
+                        );
+                        self.publish_raw(
+                            shell,
+                            self.active_spectrum(*pressed, *view),
+                            *current_color,
+                            bounds,
+                            position,
+                        );
+                    }
+                }
+                touch::Event::FingerLifted { id, .. } => match *pressed {
+                    _ if *second_finger == Some(id.0) => *second_finger = None,
+                    Some(Pressed::Finger(finger_id)) if id.0 == finger_id => {
+                        *pressed = None;
+                        self.release(
+                            shell,
+                            Some(self.on_select.as_ref()),
+                            last_published,
+                            preview,
+                            *current_color,
+                        );
+                    }
+                    // A tap, picking where the finger was pressed
+                    Some(Pressed::Holding {
+                        id: holding, at, ..
+                    }) if id.0 == holding => {
+                        *pressed = None;
+                        *last_published = None;
+
+                        let new_color = self.fetch(
+                            self.active_spectrum(None, *view),
+                            *current_color,
+                            bounds,
+                            at,
+                            picked_at,
+                        );
+                        self.publish(
+                            shell,
+                            self.on_select.as_ref(),
+                            last_published,
+                            *current_color,
+                            new_color,
+                        );
+                        self.publish_raw(
+                            shell,
+                            self.active_spectrum(None, *view),
+                            *current_color,
+                            bounds,
+                            at,
+                        );
+                        self.release(shell, None, last_published, preview, *current_color);
+                    }
+                    Some(Pressed::FingerAlt(finger_id)) if id.0 == finger_id => {
+                        *pressed = None;
+                        self.release(
+                            shell,
+                            self.on_select_alt.as_deref(),
+                            last_published,
+                            preview,
+                            *current_color,
+                        );
+
+                        // The marker goes back to the primary spectrum
+                        if self.spectrum_alt.is_some() {
+                            marker_cache.clear();
+                            shell.request_redraw();
+                        }
+                    }
+                    _ => (),
+                },
+                touch::Event::FingerLost { id, .. } => {
+                    if *second_finger == Some(id.0) {
+                        *second_finger = None;
+                    }
+
+                    if let Some(Pressed::Holding { id: holding, .. }) = *pressed
+                        && id.0 == holding
+                    {
+                        *pressed = None;
+                    }
+                }
+            },
+            iced_core::Event::Window(window::Event::RedrawRequested(now)) => {
+                if let Some(Pressed::Holding { id, at, since }) = *pressed
+                    && let Some(duration) = self.long_press
+                {
+                    // Other redraws may come first, so keep asking for one when it's due
+                    if *now < since + duration {
+                        shell.request_redraw_at(since + duration);
+                        return;
+                    }
+
+                    *pressed = Some(Pressed::FingerAlt(id));
+                    *last_published = None;
+                    marker_cache.clear();
+
+                    if let Some(on_select_alt) = self.on_select_alt.as_deref() {
+                        let new_color = self.fetch(
+                            self.active_spectrum(*pressed, *view),
+                            *current_color,
+                            bounds,
+                            at,
+                            picked_at,
+                        );
+                        self.drag(
+                            shell,
+                            on_select_alt,
+                            last_published,
+                            preview,
+                            *current_color,
+                            new_color,
+                        );
+                        self.publish_raw(
+                            shell,
+                            self.active_spectrum(*pressed, *view),
+                            *current_color,
+                            bounds,
+                            at,
+                        );
+                    }
+                }
+            }
+            iced_core::Event::Keyboard(keyboard::Event::KeyPressed {
+                key, physical_key, ..
+            }) if *focused
+                && modifiers.command()
+                && matches!(key.to_latin(*physical_key), Some('c' | 'v')) =>
+            {
+                match key.to_latin(*physical_key) {
+                    Some('c') => shell.write_clipboard(
+                        clipboard::Kind::Standard,
+                        current_color.to_hex(current_color.a < 1.0),
+                    ),
+                    // Anything that isn't a color, e.g. some other text, is left alone
+                    _ => {
+                        let Some(new_color) = shell
+                            .read_clipboard(clipboard::Kind::Standard)
+                            .and_then(|contents| css::parse(&contents).ok())
+                        else {
+                            return;
+                        };
+
+                        if pressed.is_none() {
+                            *last_published = None;
+                            self.publish(
+                                shell,
+                                self.on_select.as_ref(),
+                                last_published,
+                                *current_color,
+                                new_color,
+                            );
+                        }
+                    }
+                }
+
+                shell.capture_event();
+            }
+            iced_core::Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                ..
+            }) if *focused && pressed.is_none() && self.source_image.is_none() => {
+                let (dx, dy) = match key {
+                    key::Named::ArrowLeft => (-KEY_STEP, 0.0),
+                    key::Named::ArrowRight => (KEY_STEP, 0.0),
+                    key::Named::ArrowUp => (0.0, -KEY_STEP),
+                    key::Named::ArrowDown => (0.0, KEY_STEP),
+                    _ => return,
+                };
+
+                let new_color = self
+                    .active_spectrum(None, *view)
+                    .step(*current_color, dx, dy);
+                let new_color = self.snap(new_color, Some(*current_color));
+
+                // Keys along an axis the spectrum doesn't have are left to other widgets
+                if new_color != *current_color {
+                    shell.capture_event();
+
+                    *last_published = None;
+                    self.publish(
+                        shell,
+                        self.on_select.as_ref(),
+                        last_published,
+                        *current_color,
+                        new_color,
+                    );
+                }
+            }
 
             _ => (),
         }
+
+        // The marker follows the pick, rather than the color, on an image
+        if self.source_image.is_some() && *picked_at != previously_picked_at {
+            marker_cache.clear();
+            shell.request_redraw();
+        }
+
+        if *preview != previous_preview {
+            marker_cache.clear();
+            shell.request_redraw();
+        }
+
+        // Only a cursor that isn't dragging or panning explores the spectrum
+        if let iced_core::Event::Mouse(_) = event {
+            let new_hover = cursor
+                .position()
+                .filter(|_| cursor_in_bounds && pressed.is_none() && panning.is_none())
+                .map(|cursor| {
+                    let color = self.peek(
+                        self.active_spectrum(None, *view),
+                        *current_color,
+                        bounds,
+                        cursor,
+                    );
+
+                    (cursor - bounds.position(), color)
+                });
+
+            if *hover != new_hover {
+                let previous_hover = std::mem::replace(hover, new_hover);
+
+                if let Some(on_hover) = &self.on_hover
+                    && let Some((_, color)) = new_hover
+                    && previous_hover.is_none_or(|(_, previous)| previous != color)
+                {
+                    shell.publish(on_hover(color));
+                }
+
+                if self.hover_readout.is_some() {
+                    marker_cache.clear();
+                    shell.request_redraw();
+                }
+            }
+        }
     }
 
     fn draw(
@@ -263,28 +1797,211 @@ where
             spectrum_cache,
             marker_cache,
             current_color,
+            spectrum_base,
+            pressed,
+            picked_at,
+            focused,
+            preview,
+            view,
+            hover,
+            transition,
             ..
         }: &State<Renderer> = tree.state.downcast_ref();
 
-        let Style { marker_shape } = theme.style(&self.class);
+        let style = theme.style(&self.class);
+        let Style {
+            checkerboard,
+            border,
+            focus_ring,
+            disabled,
+            ticks,
+            ..
+        } = style;
 
-        let bounds = layout.bounds();
-        let size = layout.bounds().size();
+        let (bounds, preset_row) = self.split_bounds(layout.bounds());
+        let size = bounds.size();
 
         renderer.with_layer(bounds, |renderer| {
+            // Backdrop, so there's never a flash of nothing before the spectrum is
+            // produced, and no gaps where its blocks don't reach the edges.
+            if self.mask.is_none() {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: border::rounded(border.radius),
+                        ..Default::default()
+                    },
+                    self.displayed(Color::from(*current_color)),
+                );
+            }
+
+            let base = spectrum_base.unwrap_or(*current_color);
+            let primary = self.active_spectrum(None, *view);
+            let translucent = self.source_image.is_some() || primary.is_translucent(base);
+
+            // Masks, rounded corners, images and checkerboards are only drawn as geometry
+            let shaded = self.mask.is_none()
+                && !translucent
+                && border.radius == Radius::default()
+                && primary.is_saturation_value()
+                && self
+                    .shader
+                    .is_some_and(|draw| draw(renderer, bounds, base.h));
+
             renderer.with_translation(bounds.position() - Point::ORIGIN, |renderer| {
-                let spectrum = spectrum_cache.draw(renderer, size, |frame| {
-                    self.spectrum.render_spectrum(frame, current_color)
+                let spectrum = (!shaded).then(|| {
+                    spectrum_cache.draw(renderer, size, |frame| {
+                        // Rounded corners are cut from the spectrum, unless it has a shape of its own
+                        let rounded = (self.mask.is_none() && border.radius != Radius::default())
+                            .then(|| {
+                                Mask::new(&Path::rounded_rectangle(
+                                    Point::ORIGIN,
+                                    size,
+                                    border.radius,
+                                ))
+                            });
+                        let mask = self.mask.as_ref().or(rounded.as_ref());
+
+                        if translucent {
+                            draw_checkerboard(frame, checkerboard, mask);
+                        }
+
+                        match &self.source_image {
+                            #[cfg(feature = "image")]
+                            Some(image) => {
+                                frame.draw_image(Rectangle::with_size(size), image.handle())
+                            }
+                            _ => primary.render_masked(frame, &base, self.quantization, mask),
+                        }
+
+                        if let Some(ticks) = ticks
+                            && self.source_image.is_none()
+                        {
+                            draw_ticks(frame, &primary, base, ticks);
+                        }
+                    })
                 });
 
                 let marker = marker_cache.draw(renderer, size, |frame| {
-                    marker(self.spectrum, *current_color, size).draw(frame, marker_shape);
+                    // Follow a drag that hasn't been published yet
+                    let color = preview.unwrap_or(*current_color);
+                    let mut marker = marker(self.active_spectrum(*pressed, *view), color, size);
+                    marker.color = self.displayed(marker.color);
+                    marker.outline = style.marker_contrast.against(marker.color);
+
+                    if self.source_image.is_none() {
+                        let spectrum = self.active_spectrum(*pressed, *view);
+
+                        for extra in &self.extra_markers {
+                            extra_marker(
+                                frame,
+                                spectrum.get_marker_pos(*extra, size),
+                                self.displayed(Color::from(*extra)),
+                            );
+                        }
+                    }
+
+                    // A color may appear anywhere in an image, so mark where it was picked
+                    if self.source_image.is_some() {
+                        let (x, y) = picked_at.unwrap_or((0.5, 0.5));
+                        marker.position = Point::new(x * size.width, y * size.height);
+                    } else if let Some(transition) = transition
+                        && pressed.is_none()
+                    {
+                        marker.position = transition.position(marker.position, size);
+                    }
+
+                    // Beneath the marker, so the lines don't cover it
+                    if let Some(crosshair) = style.crosshair
+                        && marker.track.is_none()
+                    {
+                        marker.draw_crosshair(frame, crosshair);
+                    }
+
+                    match &self.marker_with {
+                        Some(draw) => draw(frame, marker.position, color),
+                        None => marker.draw(frame, &style),
+                    }
+
+                    if let Some(corner) = self.preview_chip {
+                        preview_chip(frame, corner, self.displayed(Color::from(color)));
+                    }
+
+                    if let Some(readout) = self.hover_readout
+                        && let Some((offset, color)) = *hover
+                    {
+                        hover_readout(frame, readout, Point::ORIGIN + offset, color);
+                    }
                 });
 
-                renderer.draw_geometry(spectrum);
-                renderer.draw_geometry(marker);
+                if let Some(spectrum) = spectrum {
+                    renderer.draw_geometry(spectrum);
+                }
+
+                // A layer of its own, so the border covers the cut corners of the spectrum
+                // while staying below the marker
+                renderer.with_layer(Rectangle::with_size(size), |renderer| {
+                    if border.width > 0.0 {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: Rectangle::with_size(size),
+                                border,
+                                ..Default::default()
+                            },
+                            Color::TRANSPARENT,
+                        );
+                    }
+
+                    renderer.draw_geometry(marker);
+                });
             });
+
+            if *focused {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: focus_ring,
+                        ..Default::default()
+                    },
+                    Color::TRANSPARENT,
+                );
+            }
         });
+
+        if let Some(row) = preset_row {
+            for (preset, swatch) in self.preset_swatches(row) {
+                let color = Color::from(preset);
+
+                // Highlight the preset matching the current color
+                let border = match quantize(preset) == quantize(*current_color) {
+                    true => border::color(outline(color)).width(2.0),
+                    false => Default::default(),
+                };
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: swatch,
+                        border,
+                        ..Default::default()
+                    },
+                    color,
+                );
+            }
+        }
+
+        // A layer of its own to cover the spectrum, which is drawn as geometry
+        if self.disabled {
+            renderer.with_layer(layout.bounds(), |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: layout.bounds(),
+                        border: border::rounded(border.radius),
+                        ..Default::default()
+                    },
+                    disabled,
+                );
+            });
+        }
     }
 }
 
@@ -292,18 +2009,91 @@ impl<'a, Message, Theme, Renderer> From<ColorPicker<'a, Message, Theme>>
     for Element<'a, Message, Theme, Renderer>
 where
     Theme: Catalog + 'a,
-    Renderer: geometry::Renderer + 'static,
+    Renderer: SpectrumRenderer + 'static,
 {
     fn from(value: ColorPicker<'a, Message, Theme>) -> Self {
         Element::new(value)
     }
 }
 
+/// The part of each axis of the spectrum shown, as positions along them on `[0, 1]`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct View {
+    x: (f32, f32),
+    y: (f32, f32),
+}
+
+impl Default for View {
+    fn default() -> Self {
+        Self {
+            x: (0.0, 1.0),
+            y: (0.0, 1.0),
+        }
+    }
+}
+
+impl View {
+    /// Zooms the axes of the spectrum in by the factor, or out below 1, keeping the point at
+    /// the fractions of the bounds in place.
+    fn zoom(self, spectrum: Spectrum, factor: f32, (x, y): (f32, f32)) -> Self {
+        let zoom = |(start, end): (f32, f32), at: f32| {
+            let width = ((end - start) / factor).clamp(1.0 / MAX_ZOOM, 1.0);
+            let anchor = start + at.clamp(0.0, 1.0) * (end - start);
+            let start = (anchor - at * width).clamp(0.0, 1.0 - width);
+
+            (start, start + width)
+        };
+
+        Self {
+            x: match spectrum.x_axis() {
+                Some(_) => zoom(self.x, x),
+                None => self.x,
+            },
+            y: match spectrum.y_axis() {
+                Some(_) => zoom(self.y, y),
+                None => self.y,
+            },
+        }
+    }
+
+    /// Moves the view against a drag by fractions of the bounds, as far as the axes reach.
+    fn pan(self, dx: f32, dy: f32) -> Self {
+        let pan = |(start, end): (f32, f32), delta: f32| {
+            let width = end - start;
+            let start = (start - delta * width).clamp(0.0, 1.0 - width);
+
+            (start, start + width)
+        };
+
+        Self {
+            x: pan(self.x, dx),
+            y: pan(self.y, dy),
+        }
+    }
+}
+
+/// What a part of a widget is being dragged with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Pointer {
+    Mouse,
+    Finger(u64),
+}
+
 #[derive(Debug, Clone, Copy)]
 enum Pressed {
     Primary,
     Secondary,
     Finger(u64),
+    /// A finger held still since it was pressed, see [ColorPicker::long_press].
+    Holding {
+        id: u64,
+        at: Point,
+        since: Instant,
+    },
+    /// A finger that was held for long enough, picking with `on_select_alt`.
+    FingerAlt(u64),
+    /// The color being dragged from the preview chip, see [ColorPicker::on_drag].
+    Carrying,
 }
 
 struct State<Renderer: geometry::Renderer> {
@@ -311,6 +2101,54 @@ struct State<Renderer: geometry::Renderer> {
     marker_cache: geometry::Cache<Renderer>,
     pressed: Option<Pressed>,
     current_color: Hsv,
+    spectrum_base: Option<Hsv>,
+    quantization: NonZeroUsize,
+    color_blindness: Option<ColorBlindness>,
+    /// The last color published during the current press, used to compute deltas.
+    last_published: Option<Hsv>,
+    hovered: bool,
+    /// Whether arrow keys adjust the color, Ctrl+C copies it as hex and Ctrl+V pastes one.
+    focused: bool,
+    modifiers: keyboard::Modifiers,
+    /// Where the cursor was, and where it picked from, when fine adjusting started during a drag.
+    fine_anchor: Option<(Point, Point)>,
+    /// A second finger down while another drags, fine adjusting like Shift does.
+    second_finger: Option<u64>,
+    /// The color picked during a drag that hasn't been published yet, when committing on release.
+    preview: Option<Hsv>,
+    /// Where the color was last picked, as fractions of the bounds.
+    picked_at: Option<(f32, f32)>,
+    view: View,
+    /// Where the cursor was while panning the view with the middle button.
+    panning: Option<Point>,
+    /// Where the cursor is relative to the spectrum, and the color under it, while hovering.
+    hover: Option<(Vector, Hsv)>,
+    extra_markers: Vec<Hsv>,
+    /// The marker sliding over to a color set from outside the picker.
+    transition: Option<Transition>,
+    /// The last click on the spectrum with the select button, to tell when it's double-clicked.
+    last_click: Option<mouse::Click>,
+}
+
+/// A marker sliding over to its position, see [ColorPicker::animate_marker].
+#[derive(Debug, Clone, Copy)]
+struct Transition {
+    /// Where the marker started from, as fractions of the bounds.
+    from: (f32, f32),
+    started: Instant,
+    /// How far along the marker is, from 0 to 1.
+    progress: f32,
+}
+
+impl Transition {
+    /// Where the marker is on its way to the position.
+    fn position(&self, to: Point, size: Size) -> Point {
+        // Ease out, so the marker settles into place
+        let t = 1.0 - (1.0 - self.progress).powi(3);
+        let from = Point::new(self.from.0 * size.width, self.from.1 * size.height);
+
+        from + (to - from) * t
+    }
 }
 
 impl<Renderer: geometry::Renderer> Default for State<Renderer> {
@@ -320,26 +2158,459 @@ impl<Renderer: geometry::Renderer> Default for State<Renderer> {
             marker_cache: Default::default(),
             pressed: Default::default(),
             current_color: Default::default(),
+            spectrum_base: Default::default(),
+            quantization: spectrums::QUANTIZATION,
+            color_blindness: None,
+            last_published: Default::default(),
+            hovered: Default::default(),
+            focused: Default::default(),
+            modifiers: Default::default(),
+            fine_anchor: Default::default(),
+            second_finger: Default::default(),
+            preview: Default::default(),
+            picked_at: Default::default(),
+            view: Default::default(),
+            panning: Default::default(),
+            hover: Default::default(),
+            extra_markers: Default::default(),
+            transition: Default::default(),
+            last_click: Default::default(),
+        }
+    }
+}
+
+impl<Renderer: geometry::Renderer> operation::Focusable for State<Renderer> {
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn focus(&mut self) {
+        self.focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.focused = false;
+    }
+}
+
+/// The lines scrolled, counting precise scrolls, e.g. on a touchpad, in pixels per line.
+fn scroll_lines(delta: mouse::ScrollDelta) -> f32 {
+    match delta {
+        mouse::ScrollDelta::Lines { y, .. } => y,
+        mouse::ScrollDelta::Pixels { y, .. } => y / PIXELS_PER_LINE,
+    }
+}
+
+/// Scales the movement of the cursor down while fine adjusting, e.g. with Shift held,
+/// relative to where it was when that started, so small spectra can be picked from precisely.
+fn fine_adjust(cursor: Point, fine: bool, anchor: &mut Option<(Point, Point)>) -> Point {
+    if !fine {
+        *anchor = None;
+        return cursor;
+    }
+
+    let (start, picked) = *anchor.get_or_insert((cursor, cursor));
+
+    picked + (cursor - start) * (1.0 / FINE_ADJUST)
+}
+
+/// A corner of the [ColorPicker].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Corner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+/// A color dragged from a picker onto other widgets, see [ColorPicker::on_drag].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ColorDrag {
+    /// A drag of the color started, so it can be handed to the widgets it can be dropped on.
+    Started(Hsv),
+    /// The drag ended, whether it was dropped on anything or let go of elsewhere.
+    Ended,
+}
+
+/// How the color under the cursor is written out, see [ColorPicker::show_hover_readout].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Readout {
+    /// `#rrggbb`, with the alpha appended if the color is translucent.
+    Hex,
+    /// The hue in degrees, followed by the saturation and value in percent.
+    Hsv,
+}
+
+impl Readout {
+    fn format(self, color: Hsv) -> String {
+        match self {
+            Readout::Hex => color.to_hex(color.a < 1.0),
+            Readout::Hsv => format!(
+                "{:.0}° {:.0}% {:.0}%",
+                color.h,
+                color.s * 100.0,
+                color.v * 100.0
+            ),
         }
     }
 }
 
+/// The frame a custom marker is drawn into, see [ColorPicker::marker_with].
+pub trait MarkerFrame {
+    /// The size of the frame, which is that of the spectrum.
+    fn size(&self) -> Size;
+
+    /// Fills the path, e.g. with a [Color].
+    fn fill(&mut self, path: &Path, fill: Fill);
+
+    /// Draws the outline of the path.
+    fn stroke(&mut self, path: &Path, stroke: Stroke<'_>);
+}
+
+impl<Renderer: geometry::Renderer> MarkerFrame for Frame<Renderer> {
+    fn size(&self) -> Size {
+        Frame::size(self)
+    }
+
+    fn fill(&mut self, path: &Path, fill: Fill) {
+        Frame::fill(self, path, fill);
+    }
+
+    fn stroke(&mut self, path: &Path, stroke: Stroke<'_>) {
+        Frame::stroke(self, path, stroke);
+    }
+}
+
+/// Draws one of the extra markers, which is smaller and round to set it apart from the
+/// marker of the picker.
+fn extra_marker<Renderer: geometry::Renderer>(
+    frame: &mut Frame<Renderer>,
+    position: Point,
+    color: Color,
+) {
+    const RADIUS: f32 = 4.0;
+    const BORDER_WIDTH: f32 = 1.5;
+
+    let circle = Path::circle(position, RADIUS);
+
+    frame.fill(&circle, color);
+    frame.stroke(
+        &circle,
+        Stroke::default()
+            .with_color(outline(color))
+            .with_width(BORDER_WIDTH),
+    );
+}
+
+/// The bounds of the preview chip in a corner of a spectrum of the size, border included.
+fn chip_bounds(corner: Corner, size: Size) -> Rectangle {
+    const SIZE: f32 = 20.0;
+    const MARGIN: f32 = 4.0;
+
+    let x = match corner {
+        Corner::TopLeft | Corner::BottomLeft => MARGIN,
+        Corner::TopRight | Corner::BottomRight => size.width - MARGIN - SIZE,
+    };
+    let y = match corner {
+        Corner::TopLeft | Corner::TopRight => MARGIN,
+        Corner::BottomLeft | Corner::BottomRight => size.height - MARGIN - SIZE,
+    };
+
+    Rectangle::new(Point::new(x, y), Size::new(SIZE, SIZE))
+}
+
+/// Draws a rounded chip filled with the color in a corner of the frame.
+fn preview_chip<Renderer: geometry::Renderer>(
+    frame: &mut Frame<Renderer>,
+    corner: Corner,
+    color: Color,
+) {
+    const BORDER_WIDTH: f32 = 2.0;
+    const RADIUS: f32 = 4.0;
+
+    let outer = chip_bounds(corner, frame.size());
+    let inner = outer.shrink(BORDER_WIDTH);
+
+    frame.fill(
+        &Path::rounded_rectangle(
+            outer.position(),
+            outer.size(),
+            border::radius(RADIUS + BORDER_WIDTH),
+        ),
+        outline(color),
+    );
+    frame.fill(
+        &Path::rounded_rectangle(inner.position(), inner.size(), border::radius(RADIUS)),
+        color,
+    );
+}
+
+/// Draws a label filled with the color next to the cursor, written out as the readout.
+///
+/// The label is kept within the frame, flipping to the other side of the cursor near the edges.
+fn hover_readout<Renderer: geometry::Renderer>(
+    frame: &mut Frame<Renderer>,
+    readout: Readout,
+    cursor: Point,
+    color: Hsv,
+) {
+    const TEXT_SIZE: f32 = 12.0;
+    /// The width of a character of the monospace font, relative to its size.
+    const CHAR_WIDTH: f32 = 0.6;
+    const PADDING: f32 = 4.0;
+    const OFFSET: f32 = 12.0;
+    const RADIUS: f32 = 4.0;
+
+    let content = readout.format(color);
+    let size = Size::new(
+        content.chars().count() as f32 * TEXT_SIZE * CHAR_WIDTH + PADDING * 2.0,
+        TEXT_SIZE + PADDING * 2.0,
+    );
+
+    let x = match cursor.x + OFFSET + size.width > frame.width() {
+        true => cursor.x - OFFSET - size.width,
+        false => cursor.x + OFFSET,
+    };
+    let y = match cursor.y + OFFSET + size.height > frame.height() {
+        true => cursor.y - OFFSET - size.height,
+        false => cursor.y + OFFSET,
+    };
+    let position = Point::new(x.max(0.0), y.max(0.0));
+
+    // Opaque, so the text stays legible over the spectrum
+    let fill = Color {
+        a: 1.0,
+        ..Color::from(color)
+    };
+
+    let label = Path::rounded_rectangle(position, size, border::radius(RADIUS));
+    frame.fill(&label, fill);
+    frame.stroke(
+        &label,
+        Stroke::default().with_color(outline(fill)).with_width(1.0),
+    );
+    frame.fill_text(geometry::Text {
+        content,
+        position: Point::new(position.x + PADDING, position.y + PADDING),
+        color: outline(fill),
+        size: TEXT_SIZE.into(),
+        font: Font::MONOSPACE,
+        ..Default::default()
+    });
+}
+
 #[derive(Clone, Copy)]
 struct Marker {
     position: Point,
     color: Color,
     outline: Color,
+    /// The slider the marker moves along, if it only moves along one axis.
+    track: Option<Track>,
 }
 
-impl Marker {
-    fn draw<Renderer: geometry::Renderer>(&self, frame: &mut Frame<Renderer>, shape: MarkerShape) {
-        let Self {
+/// The edges of a slider, which some marker shapes point into from either side.
+#[derive(Debug, Clone, Copy)]
+enum Track {
+    Horizontal { top: f32, bottom: f32 },
+    Vertical { left: f32, right: f32 },
+}
+
+/// Draws tick marks along the far edge of a slider, evenly dividing it, and labels its ends
+/// with their values. Spectra without exactly one axis are left alone.
+fn draw_ticks<Renderer: geometry::Renderer>(
+    frame: &mut Frame<Renderer>,
+    spectrum: &Spectrum,
+    color: Hsv,
+    ticks: Ticks,
+) {
+    let vertical = match (spectrum.x_axis(), spectrum.y_axis()) {
+        (Some(_), None) => false,
+        (None, Some(_)) => true,
+        _ => return,
+    };
+
+    let size = frame.size();
+    let stroke = Stroke::default()
+        .with_color(ticks.color)
+        .with_width(ticks.width);
+
+    let divisions = ticks.divisions.max(1);
+    for step in 0..=divisions {
+        let t = step as f32 / divisions as f32;
+        let (from, to) = match vertical {
+            true => (
+                Point::new(size.width - ticks.length, t * size.height),
+                Point::new(size.width, t * size.height),
+            ),
+            false => (
+                Point::new(t * size.width, size.height - ticks.length),
+                Point::new(t * size.width, size.height),
+            ),
+        };
+
+        frame.stroke(&Path::line(from, to), stroke);
+    }
+
+    let Some(label_size) = ticks.label_size else {
+        return;
+    };
+
+    // Inset from the ends, so the labels aren't cut off by rounded corners
+    let inset = label_size / 2.;
+    let ends = match vertical {
+        true => [
+            (
+                Point::new(size.width / 2., inset),
+                text::Alignment::Center,
+                Vertical::Top,
+            ),
+            (
+                Point::new(size.width / 2., size.height - inset),
+                text::Alignment::Center,
+                Vertical::Bottom,
+            ),
+        ],
+        false => [
+            (
+                Point::new(inset, size.height / 2.),
+                text::Alignment::Left,
+                Vertical::Center,
+            ),
+            (
+                Point::new(size.width - inset, size.height / 2.),
+                text::Alignment::Right,
+                Vertical::Center,
+            ),
+        ],
+    };
+
+    for ((position, align_x, align_y), percent) in ends.into_iter().zip([0., 1.]) {
+        let Some((component, value)) = spectrum.axis_value(color, percent) else {
+            return;
+        };
+
+        frame.fill_text(geometry::Text {
+            content: component_value(component, value),
             position,
-            color,
-            outline,
-        } = *self;
+            color: ticks.color,
+            size: label_size.into(),
+            align_x,
+            align_y,
+            ..Default::default()
+        });
+    }
+}
+
+/// The name of a component, as read out by assistive technology.
+fn component_name(component: HsvComponent) -> &'static str {
+    match component {
+        HsvComponent::Hue => "hue",
+        HsvComponent::Saturation => "saturation",
+        HsvComponent::Value => "value",
+        HsvComponent::Alpha => "opacity",
+        HsvComponent::Red => "red",
+        HsvComponent::Green => "green",
+        HsvComponent::Blue => "blue",
+        HsvComponent::Temperature => "temperature",
+    }
+}
+
+/// The value of a component as written on labels, in degrees, Kelvin or percent.
+fn component_value(component: HsvComponent, value: f32) -> String {
+    match component {
+        HsvComponent::Hue => format!("{value:.0}°"),
+        HsvComponent::Temperature => format!("{value:.0}K"),
+        _ => format!("{:.0}%", value * 100.),
+    }
+}
+
+/// Fills the frame with a checkerboard, skipping any square that falls outside of the mask.
+fn draw_checkerboard<Renderer: geometry::Renderer>(
+    frame: &mut Frame<Renderer>,
+    Checkerboard { size, light, dark }: Checkerboard,
+    mask: Option<&Mask>,
+) {
+    let size = size.max(1.0);
+    let cols = (frame.width() / size).ceil() as usize;
+    let rows = (frame.height() / size).ceil() as usize;
+
+    for col in 0..cols {
+        for row in 0..rows {
+            let position = Point::new(col as f32 * size, row as f32 * size);
+
+            let center = Point::new(position.x + size / 2.0, position.y + size / 2.0);
+            if mask.is_some_and(|mask| !mask.contains(center)) {
+                continue;
+            }
+
+            let color = match (col + row) % 2 == 0 {
+                true => light,
+                false => dark,
+            };
+
+            frame.fill_rectangle(position, Size::new(size, size), color);
+        }
+    }
+}
+
+/// A quarter of a circle, starting at the angle and going clockwise.
+fn quarter_circle(center: Point, radius: f32, start_angle: f32) -> Path {
+    // Arcs always begin a new sub-path, so the curve is approximated with lines
+    // to keep the slice joined to the center.
+    const SEGMENTS: usize = 8;
+
+    Path::new(|builder| {
+        builder.move_to(center);
+
+        for i in 0..=SEGMENTS {
+            let angle = start_angle + FRAC_PI_2 * i as f32 / SEGMENTS as f32;
+            builder.line_to(Point::new(
+                center.x + radius * angle.cos(),
+                center.y + radius * angle.sin(),
+            ));
+        }
+
+        builder.close();
+    })
+}
 
-        match shape {
+impl Marker {
+    /// Draws a horizontal and a vertical line through the marker, across the whole frame.
+    fn draw_crosshair<Renderer: geometry::Renderer>(
+        &self,
+        frame: &mut Frame<Renderer>,
+        crosshair: Crosshair,
+    ) {
+        let Point { x, y } = self.position;
+        let stroke = Stroke::default()
+            .with_color(crosshair.color.unwrap_or(self.outline))
+            .with_width(crosshair.width);
+
+        frame.stroke(
+            &Path::line(Point::new(0.0, y), Point::new(frame.width(), y)),
+            stroke,
+        );
+        frame.stroke(
+            &Path::line(Point::new(x, 0.0), Point::new(x, frame.height())),
+            stroke,
+        );
+    }
+
+    /// Draws the marker with the shape of the style, and any colors it overrides.
+    fn draw<Renderer: geometry::Renderer>(&self, frame: &mut Frame<Renderer>, style: &Style) {
+        let Style {
+            marker_shape,
+            marker_fill,
+            marker_outline,
+            checkerboard,
+            ..
+        } = *style;
+
+        let position = self.position;
+        let color = marker_fill.unwrap_or(self.color);
+        let outline = marker_outline.unwrap_or(self.outline);
+
+        match marker_shape {
             MarkerShape::Square { size, border_width } => {
                 let size = size.max(0.0);
                 let border_width = border_width.max(0.0);
@@ -353,11 +2624,18 @@ impl Marker {
                     outline,
                 );
 
-                frame.fill_rectangle(
-                    Point::new(position.x - (size / 2.0), position.y - (size / 2.0)),
-                    Size::new(size, size),
-                    color,
-                );
+                let top_left = Point::new(position.x - (size / 2.0), position.y - (size / 2.0));
+
+                // Show translucent colors over a checkerboard of four squares
+                if color.a < 1.0 {
+                    let quarter = Size::new(size / 2.0, size / 2.0);
+
+                    frame.fill_rectangle(top_left, Size::new(size, size), checkerboard.light);
+                    frame.fill_rectangle(top_left, quarter, checkerboard.dark);
+                    frame.fill_rectangle(position, quarter, checkerboard.dark);
+                }
+
+                frame.fill_rectangle(top_left, Size::new(size, size), color);
             }
             MarkerShape::Circle {
                 radius,
@@ -367,8 +2645,120 @@ impl Marker {
                 let border_width = border_width.max(0.0);
 
                 frame.fill(&Path::circle(position, radius + border_width), outline);
+
+                // Show translucent colors over a checkerboard of four quarters
+                if color.a < 1.0 {
+                    frame.fill(&Path::circle(position, radius), checkerboard.light);
+                    frame.fill(&quarter_circle(position, radius, PI), checkerboard.dark);
+                    frame.fill(&quarter_circle(position, radius, 0.0), checkerboard.dark);
+                }
+
                 frame.fill(&Path::circle(position, radius), color);
             }
+            MarkerShape::Arrow { size, border_width } => {
+                let half = size.max(0.0) / 2.0;
+                let color = Color { a: 1.0, ..color };
+                let stroke = Stroke::default()
+                    .with_color(outline)
+                    .with_width(border_width.max(0.0));
+
+                for [tip, left, right] in self.pointers(half) {
+                    let arrow = Path::new(|builder| {
+                        builder.move_to(left);
+                        builder.line_to(tip);
+                        builder.line_to(right);
+                        builder.close();
+                    });
+
+                    frame.fill(&arrow, color);
+                    frame.stroke(&arrow, stroke);
+                }
+            }
+            MarkerShape::Chevron { size, width } => {
+                let stroke = Stroke::default()
+                    .with_color(outline)
+                    .with_width(width.max(0.0))
+                    .with_line_cap(LineCap::Round)
+                    .with_line_join(LineJoin::Round);
+
+                for [tip, left, right] in self.pointers(size.max(0.0) / 2.0) {
+                    let chevron = Path::new(|builder| {
+                        builder.move_to(left);
+                        builder.line_to(tip);
+                        builder.line_to(right);
+                    });
+
+                    frame.stroke(&chevron, stroke);
+                }
+            }
+            MarkerShape::Ring { radius, width } => {
+                let stroke = Stroke::default()
+                    .with_color(outline)
+                    .with_width(width.max(0.0));
+
+                frame.stroke(&Path::circle(position, radius.max(0.0)), stroke);
+            }
+            MarkerShape::Crosshair { size, width } => {
+                let half = size.max(0.0) / 2.0;
+                let gap = half / 3.0;
+                let stroke = Stroke::default()
+                    .with_color(outline)
+                    .with_width(width.max(0.0));
+
+                let crosshair = Path::new(|builder| {
+                    for direction in [
+                        Vector::new(1.0, 0.0),
+                        Vector::new(-1.0, 0.0),
+                        Vector::new(0.0, 1.0),
+                        Vector::new(0.0, -1.0),
+                    ] {
+                        builder.move_to(position + direction * gap);
+                        builder.line_to(position + direction * half);
+                    }
+                });
+
+                frame.stroke(&crosshair, stroke);
+            }
+        }
+    }
+
+    /// The tip and base corners of two triangles pointing at the marker from either edge
+    /// of its track, as tall as `half` their width.
+    ///
+    /// A marker without a track is pointed at from just above and below.
+    fn pointers(&self, half: f32) -> [[Point; 3]; 2] {
+        let Point { x, y } = self.position;
+
+        let track = self.track.unwrap_or(Track::Horizontal {
+            top: y - half,
+            bottom: y + half,
+        });
+
+        match track {
+            Track::Horizontal { top, bottom } => [
+                [
+                    Point::new(x, top + half),
+                    Point::new(x - half, top),
+                    Point::new(x + half, top),
+                ],
+                [
+                    Point::new(x, bottom - half),
+                    Point::new(x - half, bottom),
+                    Point::new(x + half, bottom),
+                ],
+            ],
+            Track::Vertical { left, right } => [
+                [
+                    Point::new(left + half, y),
+                    Point::new(left, y - half),
+                    Point::new(left, y + half),
+                ],
+                [
+                    Point::new(right - half, y),
+                    Point::new(right, y - half),
+                    Point::new(right, y + half),
+                ],
+            ],
         }
     }
 }
@@ -380,36 +2770,344 @@ fn marker(spectrum: Spectrum, current_color: Hsv, bounds: Size) -> Marker {
 
     let position = spectrum.get_marker_pos(current_color, bounds);
 
-    let outline = match color.relative_luminance() > 0.5 {
-        true => Color::BLACK,
-        false => Color::WHITE,
+    let track = match (spectrum.x_axis(), spectrum.y_axis()) {
+        (Some(_), None) => Some(Track::Horizontal {
+            top: 0.0,
+            bottom: bounds.height,
+        }),
+        (None, Some(_)) => Some(Track::Vertical {
+            left: 0.0,
+            right: bounds.width,
+        }),
+        _ => None,
     };
 
     Marker {
         position,
         color,
-        outline,
+        outline: outline(color),
+        track,
+    }
+}
+
+/// An outline color that stands out against the given color.
+fn outline(color: Color) -> Color {
+    match color.relative_luminance() > 0.5 {
+        true => Color::BLACK,
+        false => Color::WHITE,
     }
 }
 
-/// Determines if the colour changed for a specific spectrum
+/// Determines if the colour changed, clearing the caches if so.
+///
+/// Any change requires a redraw, alpha included, as the marker is filled with the full color.
+/// The spectrum is only cleared when it's drawn from the color, and a component that isn't
+/// bound to an axis changed (e.g. the hue of a saturation/value square), see
+/// [Spectrum::requires_redraw]. This keeps pickers that share the same [Hsv] in sync,
+/// whichever one it came from.
+///
+/// The new color is always kept, but unless `exact` is set, changes below the precision of
+/// [quantize] neither clear the caches nor ask for a redraw.
 fn diff<Renderer>(
-    spectrum: Spectrum,
     canvas_cache: &geometry::Cache<Renderer>,
     cursor_cache: &geometry::Cache<Renderer>,
     current_color: &mut Hsv,
     new_color: Hsv,
+    exact: bool,
+    spectrum: Option<&Spectrum>,
 ) -> bool
 where
     Renderer: geometry::Renderer,
 {
-    let redraw = spectrum.requires_redraw(current_color, &new_color);
+    let visible = match exact {
+        true => new_color != *current_color,
+        false => quantize(new_color) != quantize(*current_color),
+    };
+
+    if visible {
+        // Moving along the axes only moves the marker
+        if spectrum.is_some_and(|spectrum| spectrum.requires_redraw(current_color, &new_color)) {
+            canvas_cache.clear();
+        }
 
-    if new_color != *current_color {
-        *current_color = new_color;
-        canvas_cache.clear();
         cursor_cache.clear();
     }
 
-    redraw
+    // Small steps, e.g. from the keyboard, build on the exact color rather than getting stuck
+    *current_color = new_color;
+
+    visible
+}
+
+/// The components of a color at 8 bits of precision, with hue in tenths of a degree so
+/// the marker doesn't jump along wide hue strips.
+fn quantize(Hsv { h, s, v, a }: Hsv) -> [u16; 4] {
+    let [s, v, a] = [s, v, a].map(|component| (component.clamp(0.0, 1.0) * 255.0).round() as u16);
+
+    [(h.clamp(0.0, 360.0) * 10.0).round() as u16, s, v, a]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns true if drawing the cache had to run, i.e. it was cleared since the last draw.
+    fn redrawn(cache: &geometry::Cache<()>) -> bool {
+        let mut redrawn = false;
+        cache.draw(&(), Size::new(1.0, 1.0), |_| redrawn = true);
+        redrawn
+    }
+
+    /// A spectrum and marker cache, both drawn already.
+    fn caches() -> (geometry::Cache<()>, geometry::Cache<()>) {
+        let caches = (geometry::Cache::new(), geometry::Cache::new());
+        redrawn(&caches.0);
+        redrawn(&caches.1);
+        caches
+    }
+
+    #[test]
+    fn diff_ignores_changes_below_8_bits() {
+        let (spectrum_cache, marker_cache) = caches();
+        let spectrum = Spectrum::saturation_value();
+
+        let mut current = hsv(120.0, 0.5, 0.5);
+        let new = hsv(120.01, 0.5005, 0.5);
+
+        assert!(!diff(
+            &spectrum_cache,
+            &marker_cache,
+            &mut current,
+            new,
+            false,
+            Some(&spectrum)
+        ));
+        assert!(!redrawn(&spectrum_cache));
+        assert!(!redrawn(&marker_cache));
+        assert_eq!(current, new);
+    }
+
+    #[test]
+    fn diff_exact_redraws_on_any_change() {
+        let (spectrum_cache, marker_cache) = caches();
+        let spectrum = Spectrum::saturation_value();
+
+        let mut current = hsv(120.0, 0.5, 0.5);
+        let new = hsv(120.01, 0.5, 0.5);
+
+        assert!(diff(
+            &spectrum_cache,
+            &marker_cache,
+            &mut current,
+            new,
+            true,
+            Some(&spectrum)
+        ));
+        assert!(redrawn(&spectrum_cache));
+        assert!(redrawn(&marker_cache));
+    }
+
+    #[test]
+    fn diff_small_steps_accumulate() {
+        let (spectrum_cache, marker_cache) = caches();
+        let spectrum = Spectrum::hue_horizontal();
+
+        // Steps well under a tenth of a degree, each one made from the stored color
+        let mut current = hsv(0.0, 1.0, 1.0);
+        let mut redraws = 0;
+        for _ in 0..100 {
+            let new = spectrum.step(current, 0.0001, 0.0);
+
+            if diff(
+                &spectrum_cache,
+                &marker_cache,
+                &mut current,
+                new,
+                false,
+                Some(&spectrum),
+            ) {
+                redraws += 1;
+            }
+        }
+
+        assert!((current.h - 3.6).abs() < 0.01);
+        assert!(redraws > 0);
+    }
+
+    #[test]
+    fn diff_redraws_alpha_only_changes() {
+        let (spectrum_cache, marker_cache) = caches();
+        let spectrum = Spectrum::new_horizontal(HsvComponent::Alpha);
+
+        let mut current = hsv(120.0, 0.5, 0.5);
+        let new = Hsv { a: 0.5, ..current };
+
+        assert!(diff(
+            &spectrum_cache,
+            &marker_cache,
+            &mut current,
+            new,
+            false,
+            Some(&spectrum)
+        ));
+        assert!(redrawn(&marker_cache));
+        // Alpha is bound to the axis, so only the marker moves
+        assert!(!redrawn(&spectrum_cache));
+    }
+
+    #[test]
+    fn diff_redraws_kept_alpha() {
+        let (spectrum_cache, marker_cache) = caches();
+        let spectrum = Spectrum::saturation_value().keep_alpha(true);
+
+        let mut current = hsv(120.0, 0.5, 0.5);
+        let new = Hsv { a: 0.5, ..current };
+
+        assert!(diff(
+            &spectrum_cache,
+            &marker_cache,
+            &mut current,
+            new,
+            false,
+            Some(&spectrum)
+        ));
+        assert!(redrawn(&spectrum_cache));
+    }
+
+    #[test]
+    fn fetch_keeps_the_color_in_zero_size_bounds() {
+        let color = hsv(120.0, 0.5, 0.5);
+        let picker: ColorPicker<'_, Hsv, iced_core::Theme> =
+            color_picker(color, |color: Hsv| color);
+
+        let mut picked_at = None;
+        let picked = picker.fetch(
+            picker.spectrum,
+            color,
+            Rectangle::default(),
+            Point::ORIGIN,
+            &mut picked_at,
+        );
+
+        assert_eq!(picked, color);
+        assert_eq!(picked_at, None);
+    }
+
+    /// A renderer keeping the quads it fills with a solid color, and drawing nothing else.
+    #[derive(Default)]
+    struct Quads(Vec<(Rectangle, Color)>);
+
+    impl iced_core::Renderer for Quads {
+        fn start_layer(&mut self, _bounds: Rectangle) {}
+
+        fn end_layer(&mut self) {}
+
+        fn start_transformation(&mut self, _transformation: iced_core::Transformation) {}
+
+        fn end_transformation(&mut self) {}
+
+        fn fill_quad(
+            &mut self,
+            quad: renderer::Quad,
+            background: impl Into<iced_core::Background>,
+        ) {
+            if let iced_core::Background::Color(color) = background.into() {
+                self.0.push((quad.bounds, color));
+            }
+        }
+
+        fn reset(&mut self, _new_bounds: Rectangle) {}
+
+        fn allocate_image(
+            &mut self,
+            handle: &iced_core::image::Handle,
+            callback: impl FnOnce(Result<iced_core::image::Allocation, iced_core::image::Error>)
+            + Send
+            + 'static,
+        ) {
+            ().allocate_image(handle, callback);
+        }
+    }
+
+    impl geometry::Renderer for Quads {
+        type Geometry = ();
+        type Frame = ();
+
+        fn new_frame(&self, _bounds: Rectangle) -> Self::Frame {}
+
+        fn draw_geometry(&mut self, _geometry: Self::Geometry) {}
+    }
+
+    #[test]
+    fn first_frame_has_a_backdrop() {
+        let color = hsv(120.0, 0.5, 0.5);
+        let bounds = Rectangle::with_size(Size::new(100.0, 100.0));
+
+        let picker: ColorPicker<'_, Hsv, iced_core::Theme> =
+            color_picker(color, |color: Hsv| color);
+        let widget: &dyn Widget<Hsv, iced_core::Theme, Quads> = &picker;
+
+        // Drawn straight away, before any event could have produced the spectrum
+        let tree = Tree::new(widget);
+        let node = layout::Node::new(bounds.size());
+        let mut renderer = Quads::default();
+
+        widget.draw(
+            &tree,
+            &mut renderer,
+            &iced_core::Theme::Light,
+            &renderer::Style {
+                text_color: Color::BLACK,
+            },
+            layout::Layout::new(&node),
+            mouse::Cursor::Unavailable,
+            &bounds,
+        );
+
+        assert_eq!(renderer.0.first(), Some(&(bounds, Color::from(color))));
+    }
+
+    #[test]
+    fn description_reads_out_the_axes_and_color() {
+        let color = hsv(120.0, 0.5, 0.5);
+
+        let area: ColorPicker<'_, Hsv, iced_core::Theme> =
+            color_picker(color, |color: Hsv| color).spectrum(Spectrum::saturation_value());
+        assert_eq!(
+            area.description(),
+            "color area, saturation from 0% to 100% by value from 100% to 0%: \
+             #408040, hsv(120.0, 50%, 50%)"
+        );
+
+        let slider: ColorPicker<'_, Hsv, iced_core::Theme> =
+            color_picker(color, |color: Hsv| color).spectrum(Spectrum::hue_horizontal());
+        assert_eq!(
+            slider.description(),
+            "color slider, hue from 0° to 360°: #408040, hsv(120.0, 50%, 50%)"
+        );
+    }
+
+    #[test]
+    fn quantize_keeps_tenths_of_a_degree() {
+        assert_ne!(
+            quantize(hsv(200.0, 1.0, 1.0)),
+            quantize(hsv(200.1, 1.0, 1.0))
+        );
+        assert_eq!(
+            quantize(hsv(200.0, 1.0, 1.0)),
+            quantize(hsv(200.01, 1.0, 1.0))
+        );
+    }
+
+    #[test]
+    fn snapping_wraps_the_hue_around() {
+        let picker: ColorPicker<'_, Hsv, iced_core::Theme> =
+            color_picker(hsv(0.0, 1.0, 1.0), |color: Hsv| color).step(hsv(15.0, 0.0, 0.0));
+
+        assert_eq!(picker.snap(hsv(358.0, 1.0, 1.0), None).h, 0.0);
+        assert_eq!(picker.snap(hsv(352.0, 1.0, 1.0), None).h, 345.0);
+        // Nudged up from 355°, the hue is rounded up past 360° to 0°
+        let previous = Some(hsv(355.0, 1.0, 1.0));
+        assert_eq!(picker.snap(hsv(356.0, 1.0, 1.0), previous).h, 0.0);
+    }
 }
@@ -0,0 +1,143 @@
+use super::Hsv;
+
+use iced_core::Color;
+
+/// Cyan, Magenta, Yellow, Key (black)
+///
+/// This is the naive conversion from sRGB, without any ink or paper profile.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cmyk {
+    /// The Cyan component.
+    pub c: f32,
+    /// The Magenta component.
+    pub m: f32,
+    /// The Yellow component.
+    pub y: f32,
+    /// The Key (black) component.
+    pub k: f32,
+    /// The alpha component.
+    pub a: f32,
+}
+
+impl Default for Cmyk {
+    fn default() -> Self {
+        Self {
+            c: Default::default(),
+            m: Default::default(),
+            y: Default::default(),
+            k: 1.0,
+            a: 1.0,
+        }
+    }
+}
+
+impl From<Color> for Cmyk {
+    // https://en.wikipedia.org/wiki/CMYK_color_model#Conversion
+    fn from(Color { r, g, b, a }: Color) -> Self {
+        let k = 1.0 - r.max(g.max(b));
+
+        // Black has no color of its own
+        if k >= 1.0 {
+            return Self {
+                c: 0.0,
+                m: 0.0,
+                y: 0.0,
+                k: 1.0,
+                a,
+            };
+        }
+
+        Self {
+            c: (1.0 - r - k) / (1.0 - k),
+            m: (1.0 - g - k) / (1.0 - k),
+            y: (1.0 - b - k) / (1.0 - k),
+            k,
+            a,
+        }
+    }
+}
+
+impl From<Cmyk> for Color {
+    fn from(Cmyk { c, m, y, k, a }: Cmyk) -> Self {
+        let [r, g, b] = [c, m, y].map(|ink| ((1.0 - ink) * (1.0 - k)).clamp(0.0, 1.0));
+
+        Color::from_rgba(r, g, b, a)
+    }
+}
+
+impl From<Hsv> for Cmyk {
+    fn from(hsv: Hsv) -> Self {
+        Cmyk::from(Color::from(hsv))
+    }
+}
+
+impl From<Cmyk> for Hsv {
+    fn from(cmyk: Cmyk) -> Self {
+        Hsv::from(Color::from(cmyk))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cmyk(c: f32, m: f32, y: f32, k: f32) -> Cmyk {
+        Cmyk { c, m, y, k, a: 1.0 }
+    }
+
+    #[test]
+    fn reference_values() {
+        assert_eq!(Cmyk::from(Color::WHITE), cmyk(0.0, 0.0, 0.0, 0.0));
+        assert_eq!(
+            Cmyk::from(Color::from_rgb(1.0, 0.0, 0.0)),
+            cmyk(0.0, 1.0, 1.0, 0.0)
+        );
+        assert_eq!(
+            Cmyk::from(Color::from_rgb(0.5, 0.25, 0.0)),
+            cmyk(0.0, 0.5, 1.0, 0.5)
+        );
+    }
+
+    #[test]
+    fn black() {
+        // The inks divide by 1 - k, which is zero for black
+        assert_eq!(Cmyk::from(Color::BLACK), Cmyk::default());
+        assert_eq!(
+            Cmyk::from(Color::from_rgba(1e-9, 0.0, 0.0, 0.5)),
+            Cmyk {
+                a: 0.5,
+                ..Cmyk::default()
+            }
+        );
+
+        // Whatever the inks, full black is black
+        assert_eq!(Color::from(cmyk(0.3, 0.6, 0.9, 1.0)), Color::BLACK);
+    }
+
+    #[test]
+    fn round_trip() {
+        for rgb in [
+            [1.0, 0.5, 0.0],
+            [0.2, 0.4, 0.6],
+            [0.01, 0.02, 0.03],
+            [0.5, 0.5, 0.5],
+        ] {
+            let color = Color::from_rgba(rgb[0], rgb[1], rgb[2], 0.75);
+            let back = Color::from(Cmyk::from(color));
+
+            for (channel, expected) in [back.r, back.g, back.b].into_iter().zip(rgb) {
+                assert!((channel - expected).abs() < 1e-6, "{back:?} from {rgb:?}");
+            }
+            assert_eq!(back.a, 0.75);
+        }
+    }
+
+    #[test]
+    fn out_of_range_inks_are_clamped() {
+        assert_eq!(
+            Color::from(cmyk(-0.5, 1.5, 0.0, 0.0)),
+            Color::from_rgb(1.0, 0.0, 1.0)
+        );
+    }
+}
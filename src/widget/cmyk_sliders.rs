@@ -0,0 +1,407 @@
+//! Four sliders to pick a color by its [Cmyk] components.
+
+use super::style::{Catalog, Style, StyleFn};
+use super::{Cmyk, Hsv, Marker, Pointer, Track, quantize};
+
+use iced_core::widget::{Tree, Widget, tree};
+use iced_core::{Color, Element, Length, Point, Rectangle, Shell, Size, layout, mouse, touch};
+use iced_graphics::geometry::{self, Frame};
+
+/// Height of each slider.
+const ROW_HEIGHT: f32 = 20.0;
+/// Space between each slider.
+const ROW_SPACING: f32 = 4.0;
+/// Size of the blocks the sliders are drawn with.
+const QUANTIZATION: f32 = 2.0;
+
+/// Creates new [CmykSliders] with the current [Hsv] (or [Color]) value, and a closure to produce a message when a color is picked.
+pub fn cmyk_sliders<'a, Message, Theme, FromHsv>(
+    color: impl Into<Hsv>,
+    on_select: impl Fn(FromHsv) -> Message + 'a,
+) -> CmykSliders<'a, Message, Theme>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    FromHsv: From<Hsv> + 'a,
+{
+    CmykSliders::new(color, move |color| on_select(color.into()))
+}
+
+/// A widget with a slider for each of the cyan, magenta, yellow and key components of a color.
+///
+/// Each slider shows the colors it can reach with the other components held in place.
+/// The components are kept while dragging, so moving one slider never changes another,
+/// even though many [Cmyk] values map to the same [Hsv].
+pub struct CmykSliders<'a, Message, Theme>
+where
+    Message: 'a,
+    Theme: Catalog,
+{
+    color: Hsv,
+    width: Length,
+    height: Length,
+    on_select: Box<dyn Fn(Hsv) -> Message + 'a>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme> CmykSliders<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    pub fn new(color: impl Into<Hsv>, on_select: impl Fn(Hsv) -> Message + 'a) -> Self {
+        Self {
+            color: color.into(),
+            width: Length::Fill,
+            height: Length::Fixed(ROW_HEIGHT * 4.0 + ROW_SPACING * 3.0),
+            on_select: Box::new(on_select),
+            class: Theme::default(),
+        }
+    }
+
+    /// Set the width of the [CmykSliders].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Set the height of the [CmykSliders], shared evenly between the sliders.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Set the [Style] of the [CmykSliders].
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = Theme::Class::from(Box::new(style));
+        self
+    }
+
+    /// Set the style class of the [CmykSliders].
+    pub fn class(mut self, class: Theme::Class<'a>) -> Self {
+        self.class = class;
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for CmykSliders<'a, Message, Theme>
+where
+    Theme: Catalog,
+    Renderer: geometry::Renderer + 'static,
+{
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer>::default())
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.width, self.height)
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        match cursor.position() {
+            Some(cursor) if channel_at(layout.bounds(), cursor).is_some() => {
+                mouse::Interaction::Pointer
+            }
+            _ => Default::default(),
+        }
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &iced_core::Event,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let State {
+            track_cache,
+            marker_cache,
+            pressed,
+            current_color,
+            cmyk,
+        }: &mut State<Renderer> = tree.state.downcast_mut();
+
+        let bounds = layout.bounds();
+
+        if quantize(self.color) != quantize(*current_color) {
+            *current_color = self.color;
+
+            // Keep the components while they still describe the color
+            if Hsv::from(*cmyk).to_rgba8() != self.color.to_rgba8() {
+                *cmyk = Cmyk::from(self.color);
+            }
+
+            track_cache.clear();
+            marker_cache.clear();
+            shell.request_redraw();
+        }
+
+        let (pointer, position) = match event {
+            iced_core::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position() else {
+                    return;
+                };
+
+                if pressed.is_none() {
+                    *pressed =
+                        channel_at(bounds, position).map(|channel| (channel, Pointer::Mouse));
+                }
+
+                (Pointer::Mouse, position)
+            }
+            // The cursor may be unavailable over another layer, but a drag should follow it
+            iced_core::Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                (Pointer::Mouse, *position)
+            }
+            iced_core::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                if let Some((_, Pointer::Mouse)) = pressed {
+                    *pressed = None;
+                }
+
+                return;
+            }
+            iced_core::Event::Touch(touch::Event::FingerPressed { id, position }) => {
+                if pressed.is_none() {
+                    *pressed = channel_at(bounds, *position)
+                        .map(|channel| (channel, Pointer::Finger(id.0)));
+                }
+
+                (Pointer::Finger(id.0), *position)
+            }
+            iced_core::Event::Touch(touch::Event::FingerMoved { id, position }) => {
+                (Pointer::Finger(id.0), *position)
+            }
+            iced_core::Event::Touch(
+                touch::Event::FingerLifted { id, .. } | touch::Event::FingerLost { id, .. },
+            ) => {
+                if let Some((_, Pointer::Finger(finger_id))) = pressed
+                    && id.0 == *finger_id
+                {
+                    *pressed = None;
+                }
+
+                return;
+            }
+            _ => return,
+        };
+
+        // Keep moving the slider that was pressed, even once the cursor leaves it
+        if let Some((channel, pressed_by)) = *pressed
+            && pressed_by == pointer
+        {
+            let row = rows(bounds)[channel as usize];
+            let percent = match row.width > 0.0 {
+                true => ((position.x - row.x) / row.width).clamp(0.0, 1.0),
+                false => 0.0,
+            };
+
+            channel.set(cmyk, percent);
+            cmyk.a = current_color.a;
+
+            // The components may change without changing the color, e.g. at full black
+            track_cache.clear();
+            marker_cache.clear();
+            shell.request_redraw();
+
+            let new_color = Hsv::from_color_with_previous(Color::from(*cmyk), *current_color);
+            shell.publish((self.on_select)(new_color));
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &iced_core::renderer::Style,
+        layout: iced_core::Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &iced_core::Rectangle,
+    ) {
+        let State {
+            track_cache,
+            marker_cache,
+            current_color,
+            cmyk,
+            ..
+        }: &State<Renderer> = tree.state.downcast_ref();
+
+        let style = theme.style(&self.class);
+
+        let bounds = layout.bounds();
+        let size = bounds.size();
+        let rows = rows(Rectangle::with_size(size));
+
+        renderer.with_layer(bounds, |renderer| {
+            renderer.with_translation(bounds.position() - Point::ORIGIN, |renderer| {
+                let tracks = track_cache.draw(renderer, size, |frame| {
+                    for (channel, row) in Channel::ALL.into_iter().zip(rows) {
+                        draw_track(frame, row, channel, *cmyk);
+                    }
+                });
+
+                let markers = marker_cache.draw(renderer, size, |frame| {
+                    let color = Color::from(*current_color);
+
+                    for (channel, row) in Channel::ALL.into_iter().zip(rows) {
+                        let marker = Marker {
+                            position: Point::new(
+                                row.x + channel.get(*cmyk) * row.width,
+                                row.center_y(),
+                            ),
+                            color,
+                            outline: style.marker_contrast.against(color),
+                            track: Some(Track::Horizontal {
+                                top: row.y,
+                                bottom: row.y + row.height,
+                            }),
+                        };
+
+                        marker.draw(frame, &style);
+                    }
+                });
+
+                renderer.draw_geometry(tracks);
+                renderer.draw_geometry(markers);
+            });
+        });
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<CmykSliders<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog + 'a,
+    Renderer: geometry::Renderer + 'static,
+{
+    fn from(value: CmykSliders<'a, Message, Theme>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// A component of [Cmyk], in the order of the sliders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Channel {
+    Cyan,
+    Magenta,
+    Yellow,
+    Key,
+}
+
+impl Channel {
+    const ALL: [Channel; 4] = [
+        Channel::Cyan,
+        Channel::Magenta,
+        Channel::Yellow,
+        Channel::Key,
+    ];
+
+    fn get(self, cmyk: Cmyk) -> f32 {
+        match self {
+            Channel::Cyan => cmyk.c,
+            Channel::Magenta => cmyk.m,
+            Channel::Yellow => cmyk.y,
+            Channel::Key => cmyk.k,
+        }
+    }
+
+    fn set(self, cmyk: &mut Cmyk, value: f32) {
+        match self {
+            Channel::Cyan => cmyk.c = value,
+            Channel::Magenta => cmyk.m = value,
+            Channel::Yellow => cmyk.y = value,
+            Channel::Key => cmyk.k = value,
+        }
+    }
+}
+
+/// Splits the bounds into the row of each slider.
+fn rows(bounds: Rectangle) -> [Rectangle; 4] {
+    let height = ((bounds.height - ROW_SPACING * 3.0) / 4.0).max(0.0);
+
+    [0.0, 1.0, 2.0, 3.0].map(|i| Rectangle {
+        y: bounds.y + i * (height + ROW_SPACING),
+        height,
+        ..bounds
+    })
+}
+
+/// Returns the slider under the point, if any.
+fn channel_at(bounds: Rectangle, point: Point) -> Option<Channel> {
+    Channel::ALL
+        .into_iter()
+        .zip(rows(bounds))
+        .find(|(_, row)| row.contains(point))
+        .map(|(channel, _)| channel)
+}
+
+/// Draws the colors reached by moving the channel from 0 to 1, holding the others in place.
+fn draw_track<Renderer: geometry::Renderer>(
+    frame: &mut Frame<Renderer>,
+    row: Rectangle,
+    channel: Channel,
+    cmyk: Cmyk,
+) {
+    let blocks = (row.width / QUANTIZATION).ceil().max(0.0) as usize;
+
+    for block in 0..blocks {
+        let x = block as f32 * QUANTIZATION;
+
+        let mut color = Cmyk { a: 1.0, ..cmyk };
+        channel.set(&mut color, ((x + QUANTIZATION / 2.0) / row.width).min(1.0));
+
+        frame.fill_rectangle(
+            Point::new(row.x + x, row.y),
+            Size::new(QUANTIZATION.min(row.width - x), row.height),
+            Color::from(color),
+        );
+    }
+}
+
+struct State<Renderer: geometry::Renderer> {
+    track_cache: geometry::Cache<Renderer>,
+    marker_cache: geometry::Cache<Renderer>,
+    pressed: Option<(Channel, Pointer)>,
+    current_color: Hsv,
+    /// The components being edited, which may differ from those of `current_color`
+    /// while describing the same color.
+    cmyk: Cmyk,
+}
+
+impl<Renderer: geometry::Renderer> Default for State<Renderer> {
+    fn default() -> Self {
+        Self {
+            track_cache: Default::default(),
+            marker_cache: Default::default(),
+            pressed: Default::default(),
+            current_color: Default::default(),
+            cmyk: Default::default(),
+        }
+    }
+}
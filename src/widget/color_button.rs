@@ -0,0 +1,432 @@
+//! A swatch that opens a picker in a popup when clicked.
+
+use super::style::{Catalog, popup};
+use super::swatch::draw_swatch;
+use super::{Hsv, SpectrumRenderer, color_picker_panel};
+
+use iced_core::widget::{Operation, Tree, Widget, tree};
+use iced_core::{
+    Border, Element, Event, Length, Padding, Point, Rectangle, Shell, Size, Vector, keyboard,
+    layout, mouse, overlay, renderer, text, touch,
+};
+use iced_graphics::geometry;
+use iced_widget::text_input;
+
+/// Space between the button and its popup.
+const POPUP_GAP: f32 = 4.0;
+/// Space between the edges of the popup and its content.
+const POPUP_PADDING: f32 = 8.0;
+
+/// Creates a new [ColorButton] with the current [Hsv] (or [Color](iced_core::Color)) value, and a closure to produce a message when a color is picked.
+///
+/// The popup holds a [ColorPickerPanel](super::ColorPickerPanel); use [ColorButton::new] for any other content.
+pub fn color_button<'a, Message, Theme, Renderer, FromHsv>(
+    color: impl Into<Hsv>,
+    on_select: impl Fn(FromHsv) -> Message + 'a,
+) -> ColorButton<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + popup::Catalog + text_input::Catalog + 'a,
+    Renderer: SpectrumRenderer + text::Renderer + 'static,
+    FromHsv: From<Hsv> + 'a,
+{
+    let color = color.into();
+
+    ColorButton::new(color, color_picker_panel(color, on_select))
+}
+
+/// A small swatch of the color, which opens a popup below it when clicked.
+///
+/// The popup is closed by clicking anywhere outside of it, by clicking the button again,
+/// or by pressing Escape.
+pub struct ColorButton<'a, Message, Theme, Renderer>
+where
+    Theme: popup::Catalog,
+{
+    color: Hsv,
+    width: Length,
+    height: Length,
+    popup: Element<'a, Message, Theme, Renderer>,
+    class: <Theme as popup::Catalog>::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> ColorButton<'a, Message, Theme, Renderer>
+where
+    Theme: popup::Catalog,
+{
+    /// Creates a [ColorButton] showing the color, which opens a popup with the given content.
+    pub fn new(
+        color: impl Into<Hsv>,
+        popup: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            color: color.into(),
+            width: Length::Fixed(32.0),
+            height: Length::Fixed(24.0),
+            popup: popup.into(),
+            class: Theme::default(),
+        }
+    }
+
+    /// Set the width of the [ColorButton].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Set the height of the [ColorButton].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Set the [popup::Style] of the [ColorButton].
+    pub fn style(mut self, style: impl Fn(&Theme) -> popup::Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<popup::StyleFn<'a, Theme>>,
+    {
+        self.class = Theme::Class::from(Box::new(style));
+        self
+    }
+
+    /// Set the style class of the [ColorButton].
+    pub fn class(mut self, class: Theme::Class<'a>) -> Self {
+        self.class = class;
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ColorButton<'a, Message, Theme, Renderer>
+where
+    Theme: popup::Catalog,
+    Renderer: geometry::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.popup)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.popup));
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.width, self.height)
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        match cursor.is_over(layout.bounds()) {
+            true => mouse::Interaction::Pointer,
+            false => Default::default(),
+        }
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let State { is_open }: &mut State = tree.state.downcast_mut();
+
+        let pressed_at = match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => cursor.position(),
+            Event::Touch(touch::Event::FingerPressed { position, .. }) => Some(*position),
+            _ => None,
+        };
+
+        if pressed_at.is_some_and(|position| layout.bounds().contains(position)) {
+            *is_open = !*is_open;
+
+            shell.capture_event();
+            // The runtime only looks for overlays when laying out
+            shell.invalidate_layout();
+            shell.request_redraw();
+        }
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: layout::Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let popup::Style {
+            border,
+            checkerboard,
+            ..
+        } = theme.style(&self.class);
+
+        // The swatch is square, so only the color of the border is kept
+        let border = Border {
+            radius: 0.into(),
+            ..border
+        };
+
+        draw_swatch(
+            renderer,
+            layout.bounds(),
+            self.color,
+            checkerboard,
+            Some(border),
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: layout::Layout<'b>,
+        _renderer: &Renderer,
+        _viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let Tree {
+            state, children, ..
+        } = tree;
+
+        let state: &mut State = state.downcast_mut();
+
+        if !state.is_open {
+            return None;
+        }
+
+        Some(overlay::Element::new(Box::new(Popup {
+            content: &mut self.popup,
+            tree: &mut children[0],
+            state,
+            anchor: layout.bounds() + translation,
+            class: &self.class,
+        })))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ColorButton<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: popup::Catalog + 'a,
+    Renderer: geometry::Renderer + 'a,
+{
+    fn from(value: ColorButton<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    is_open: bool,
+}
+
+/// The content of an open [ColorButton], below the button or above it if there's no room.
+struct Popup<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: popup::Catalog,
+{
+    content: &'b mut Element<'a, Message, Theme, Renderer>,
+    tree: &'b mut Tree,
+    state: &'b mut State,
+    /// The bounds of the button.
+    anchor: Rectangle,
+    class: &'b <Theme as popup::Catalog>::Class<'a>,
+}
+
+impl<Message, Theme, Renderer> Popup<'_, '_, Message, Theme, Renderer>
+where
+    Theme: popup::Catalog,
+{
+    fn close(&mut self, shell: &mut Shell<'_, Message>) {
+        self.state.is_open = false;
+
+        shell.invalidate_layout();
+        shell.request_redraw();
+    }
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Popup<'_, '_, Message, Theme, Renderer>
+where
+    Theme: popup::Catalog,
+    Renderer: geometry::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let padding = Padding::new(POPUP_PADDING);
+        let limits = layout::Limits::new(Size::ZERO, bounds).shrink(padding);
+
+        let content = self
+            .content
+            .as_widget_mut()
+            .layout(self.tree, renderer, &limits)
+            .move_to(Point::new(POPUP_PADDING, POPUP_PADDING));
+
+        let size = content.size().expand(padding);
+
+        let below = self.anchor.y + self.anchor.height + POPUP_GAP;
+        let y = match below + size.height <= bounds.height {
+            true => below,
+            false => (self.anchor.y - POPUP_GAP - size.height).max(0.0),
+        };
+        let x = self.anchor.x.min(bounds.width - size.width).max(0.0);
+
+        layout::Node::with_children(size, vec![content]).move_to(Point::new(x, y))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let popup::Style {
+            background, border, ..
+        } = theme.style(self.class);
+
+        let bounds = layout.bounds();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border,
+                ..Default::default()
+            },
+            background,
+        );
+
+        if let Some(content) = layout.children().next() {
+            self.content
+                .as_widget()
+                .draw(self.tree, renderer, theme, style, content, cursor, &bounds);
+        }
+    }
+
+    fn operate(
+        &mut self,
+        layout: layout::Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if let Some(content) = layout.children().next() {
+            self.content
+                .as_widget_mut()
+                .operate(self.tree, content, renderer, operation);
+        }
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let bounds = layout.bounds();
+
+        let pressed_at = match event {
+            Event::Mouse(mouse::Event::ButtonPressed(_)) => cursor.position(),
+            Event::Touch(touch::Event::FingerPressed { position, .. }) => Some(*position),
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Escape),
+                ..
+            }) => {
+                self.close(shell);
+                shell.capture_event();
+
+                return;
+            }
+            _ => None,
+        };
+
+        // Presses on the button are left to it, so it toggles the popup rather than reopening it
+        if let Some(position) = pressed_at
+            && !bounds.contains(position)
+            && !self.anchor.contains(position)
+        {
+            self.close(shell);
+
+            return;
+        }
+
+        if let Some(content) = layout.children().next() {
+            self.content
+                .as_widget_mut()
+                .update(self.tree, event, content, cursor, renderer, shell, &bounds);
+        }
+
+        // Keep presses on the popup from reaching the widgets below it
+        if pressed_at.is_some() {
+            shell.capture_event();
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+
+        let interaction = layout.children().next().map(|content| {
+            self.content
+                .as_widget()
+                .mouse_interaction(self.tree, content, cursor, &bounds, renderer)
+        });
+
+        match interaction {
+            Some(interaction) if interaction != mouse::Interaction::None => interaction,
+            // Hide the widgets below the popup from the cursor
+            _ if cursor.is_over(bounds) => mouse::Interaction::Idle,
+            _ => mouse::Interaction::None,
+        }
+    }
+
+    fn overlay<'c>(
+        &'c mut self,
+        layout: layout::Layout<'c>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'c, Message, Theme, Renderer>> {
+        let content = layout.children().next()?;
+
+        self.content.as_widget_mut().overlay(
+            self.tree,
+            content,
+            renderer,
+            &layout.bounds(),
+            Vector::ZERO,
+        )
+    }
+}
@@ -0,0 +1,572 @@
+//! A modal dialog to pick a color, which is only kept once submitted.
+
+use super::style::{Catalog, popup};
+use super::{ColorPickerPanel, Hsv, SpectrumRenderer};
+
+use iced_core::widget::{Operation, Tree, Widget, tree};
+use iced_core::{
+    Color, Element, Event, Length, Padding, Point, Rectangle, Shell, Size, Vector, keyboard,
+    layout, mouse, overlay, renderer, text, touch,
+};
+use iced_graphics::geometry;
+use iced_widget::{Column, Row, button, text_input};
+
+/// Space between the edges of the dialog and its content.
+const DIALOG_PADDING: f32 = 12.0;
+/// Space between the panel and the buttons.
+const DIALOG_SPACING: f32 = 8.0;
+/// Dims the content behind the dialog.
+const BACKDROP: Color = Color::from_rgba(0.0, 0.0, 0.0, 0.5);
+
+/// Creates a new [ColorDialog] over the `underlay`, shown while `is_open` is true.
+///
+/// Picking a color only changes the dialog; `on_submit` is produced with the picked color
+/// once OK is pressed, and `on_cancel` once Cancel or Escape is pressed.
+pub fn color_dialog<'a, Message, Theme, Renderer, FromHsv>(
+    is_open: bool,
+    color: impl Into<Hsv>,
+    underlay: impl Into<Element<'a, Message, Theme, Renderer>>,
+    on_cancel: Message,
+    on_submit: impl Fn(FromHsv) -> Message + 'a,
+) -> ColorDialog<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + popup::Catalog + 'a,
+    FromHsv: From<Hsv> + 'a,
+{
+    ColorDialog::new(is_open, color, underlay, on_cancel, move |color| {
+        on_submit(color.into())
+    })
+}
+
+/// A [ColorPickerPanel] with OK and Cancel buttons, shown in a modal above other content.
+///
+/// The dialog keeps its own copy of the color while open, so canceling reverts to the
+/// color it was opened with. Closing the dialog is left to the application, by setting
+/// `is_open` to false in response to either message.
+pub struct ColorDialog<'a, Message, Theme, Renderer>
+where
+    Theme: popup::Catalog,
+{
+    is_open: bool,
+    color: Hsv,
+    underlay: Element<'a, Message, Theme, Renderer>,
+    on_cancel: Message,
+    on_submit: Box<dyn Fn(Hsv) -> Message + 'a>,
+    submit_label: String,
+    cancel_label: String,
+    class: <Theme as popup::Catalog>::Class<'a>,
+    /// Built around the color being picked once the dialog is shown.
+    dialog: Option<Element<'a, Action, Theme, Renderer>>,
+}
+
+impl<'a, Message, Theme, Renderer> ColorDialog<'a, Message, Theme, Renderer>
+where
+    Theme: popup::Catalog,
+{
+    pub fn new(
+        is_open: bool,
+        color: impl Into<Hsv>,
+        underlay: impl Into<Element<'a, Message, Theme, Renderer>>,
+        on_cancel: Message,
+        on_submit: impl Fn(Hsv) -> Message + 'a,
+    ) -> Self {
+        Self {
+            is_open,
+            color: color.into(),
+            underlay: underlay.into(),
+            on_cancel,
+            on_submit: Box::new(on_submit),
+            submit_label: String::from("OK"),
+            cancel_label: String::from("Cancel"),
+            class: Theme::default(),
+            dialog: None,
+        }
+    }
+
+    /// Set the label of the button that submits the color.
+    pub fn submit_label(mut self, label: impl Into<String>) -> Self {
+        self.submit_label = label.into();
+        self
+    }
+
+    /// Set the label of the button that cancels the dialog.
+    pub fn cancel_label(mut self, label: impl Into<String>) -> Self {
+        self.cancel_label = label.into();
+        self
+    }
+
+    /// Set the [popup::Style] of the [ColorDialog].
+    pub fn style(mut self, style: impl Fn(&Theme) -> popup::Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<popup::StyleFn<'a, Theme>>,
+    {
+        self.class = Theme::Class::from(Box::new(style));
+        self
+    }
+
+    /// Set the style class of the [ColorDialog].
+    pub fn class(mut self, class: Theme::Class<'a>) -> Self {
+        self.class = class;
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ColorDialog<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog
+        + popup::Catalog
+        + text_input::Catalog
+        + button::Catalog
+        + iced_widget::text::Catalog
+        + 'a,
+    Renderer: SpectrumRenderer + text::Renderer + 'static,
+{
+    fn size(&self) -> Size<Length> {
+        self.underlay.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.underlay.as_widget().size_hint()
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            color: self.color,
+            is_open: self.is_open,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        // The dialog is only built, and diffed, while it's shown
+        vec![Tree::new(&self.underlay), Tree::empty()]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state: &mut State = tree.state.downcast_mut();
+
+        // Start from the given color every time the dialog is opened
+        if self.is_open && !state.is_open {
+            state.color = self.color;
+        }
+
+        state.is_open = self.is_open;
+
+        tree.children[0].diff(&self.underlay);
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.underlay
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: layout::Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.underlay
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.underlay.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            shell,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.underlay.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.underlay.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: layout::Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let Tree {
+            state, children, ..
+        } = tree;
+
+        let state: &mut State = state.downcast_mut();
+        let [underlay, dialog_tree] = children.as_mut_slice() else {
+            return None;
+        };
+
+        if !self.is_open {
+            return self.underlay.as_widget_mut().overlay(
+                underlay,
+                layout,
+                renderer,
+                viewport,
+                translation,
+            );
+        }
+
+        // Built once per view, as some of its widgets are only ready once laid out; the
+        // widgets are invalidated whenever a color is picked to build it again
+        if self.dialog.is_none() {
+            let dialog =
+                self.dialog
+                    .insert(dialog(state.color, &self.submit_label, &self.cancel_label));
+
+            dialog_tree.diff(&*dialog);
+        }
+
+        let dialog = self.dialog.as_mut()?;
+
+        Some(overlay::Element::new(Box::new(Modal {
+            content: dialog,
+            tree: dialog_tree,
+            state,
+            on_cancel: &self.on_cancel,
+            on_submit: self.on_submit.as_ref(),
+            class: &self.class,
+        })))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ColorDialog<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog
+        + popup::Catalog
+        + text_input::Catalog
+        + button::Catalog
+        + iced_widget::text::Catalog
+        + 'a,
+    Renderer: SpectrumRenderer + text::Renderer + 'static,
+{
+    fn from(value: ColorDialog<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Messages of the dialog.
+#[derive(Debug, Clone)]
+enum Action {
+    Pick(Hsv),
+    Submit,
+    Cancel,
+}
+
+struct State {
+    /// The color being picked while the dialog is open.
+    color: Hsv,
+    /// Whether the dialog was open when last diffed, to notice it being opened.
+    is_open: bool,
+}
+
+/// The panel, with the buttons below it.
+fn dialog<'a, Theme, Renderer>(
+    color: Hsv,
+    submit_label: &str,
+    cancel_label: &str,
+) -> Element<'a, Action, Theme, Renderer>
+where
+    Theme: Catalog
+        + popup::Catalog
+        + text_input::Catalog
+        + button::Catalog
+        + iced_widget::text::Catalog
+        + 'a,
+    Renderer: SpectrumRenderer + text::Renderer + 'static,
+{
+    let label = |label: &str| {
+        iced_widget::text(label.to_owned())
+            .width(Length::Fill)
+            .center()
+    };
+
+    let buttons = Row::with_children([
+        button(label(cancel_label))
+            .on_press(Action::Cancel)
+            .width(Length::Fill)
+            .into(),
+        button(label(submit_label))
+            .on_press(Action::Submit)
+            .width(Length::Fill)
+            .into(),
+    ])
+    .spacing(DIALOG_SPACING);
+
+    Column::with_children([
+        ColorPickerPanel::new(color, Action::Pick).into(),
+        buttons.into(),
+    ])
+    .spacing(DIALOG_SPACING)
+    .into()
+}
+
+/// The dialog, centered over a backdrop covering the window.
+struct Modal<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: popup::Catalog,
+{
+    content: &'b mut Element<'a, Action, Theme, Renderer>,
+    tree: &'b mut Tree,
+    state: &'b mut State,
+    on_cancel: &'b Message,
+    on_submit: &'b dyn Fn(Hsv) -> Message,
+    class: &'b <Theme as popup::Catalog>::Class<'a>,
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Modal<'_, '_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: popup::Catalog,
+    Renderer: geometry::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let padding = Padding::new(DIALOG_PADDING);
+        let limits = layout::Limits::new(Size::ZERO, bounds).shrink(padding);
+
+        let content = self
+            .content
+            .as_widget_mut()
+            .layout(self.tree, renderer, &limits)
+            .move_to(Point::new(DIALOG_PADDING, DIALOG_PADDING));
+
+        let size = content.size().expand(padding);
+        let position = Point::new(
+            ((bounds.width - size.width) / 2.0).max(0.0),
+            ((bounds.height - size.height) / 2.0).max(0.0),
+        );
+
+        let dialog = layout::Node::with_children(size, vec![content]).move_to(position);
+
+        layout::Node::with_children(bounds, vec![dialog])
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let popup::Style {
+            background, border, ..
+        } = theme.style(self.class);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: layout.bounds(),
+                ..Default::default()
+            },
+            BACKDROP,
+        );
+
+        let Some(dialog) = layout.children().next() else {
+            return;
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: dialog.bounds(),
+                border,
+                ..Default::default()
+            },
+            background,
+        );
+
+        if let Some(content) = dialog.children().next() {
+            self.content.as_widget().draw(
+                self.tree,
+                renderer,
+                theme,
+                style,
+                content,
+                cursor,
+                &layout.bounds(),
+            );
+        }
+    }
+
+    fn operate(
+        &mut self,
+        layout: layout::Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if let Some(content) = layout
+            .children()
+            .next()
+            .and_then(|dialog| dialog.children().next())
+        {
+            self.content
+                .as_widget_mut()
+                .operate(self.tree, content, renderer, operation);
+        }
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let Some(content) = layout
+            .children()
+            .next()
+            .and_then(|dialog| dialog.children().next())
+        else {
+            return;
+        };
+
+        let mut actions = Vec::new();
+        let mut local_shell = Shell::new(&mut actions);
+
+        self.content.as_widget_mut().update(
+            self.tree,
+            event,
+            content,
+            cursor,
+            renderer,
+            &mut local_shell,
+            &layout.bounds(),
+        );
+
+        if local_shell.is_event_captured() {
+            shell.capture_event();
+        }
+
+        if local_shell.is_layout_invalid() {
+            shell.invalidate_layout();
+        }
+
+        if local_shell.are_widgets_invalid() {
+            shell.invalidate_widgets();
+        }
+
+        shell.request_redraw_at(local_shell.redraw_request());
+        shell.request_input_method(local_shell.input_method());
+
+        if let Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(keyboard::key::Named::Escape),
+            ..
+        }) = event
+            && !shell.is_event_captured()
+        {
+            actions.push(Action::Cancel);
+            shell.capture_event();
+        }
+
+        for action in actions {
+            match action {
+                Action::Pick(color) => {
+                    self.state.color = color;
+                    shell.invalidate_widgets();
+                }
+                Action::Submit => shell.publish((self.on_submit)(self.state.color)),
+                Action::Cancel => shell.publish(self.on_cancel.clone()),
+            }
+        }
+
+        // Keep the widgets below the backdrop from being used while the dialog is open
+        if let Event::Mouse(mouse::Event::ButtonPressed(_) | mouse::Event::WheelScrolled { .. })
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            shell.capture_event();
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let interaction = layout
+            .children()
+            .next()
+            .and_then(|dialog| dialog.children().next())
+            .map(|content| {
+                self.content.as_widget().mouse_interaction(
+                    self.tree,
+                    content,
+                    cursor,
+                    &layout.bounds(),
+                    renderer,
+                )
+            });
+
+        match interaction {
+            Some(interaction) if interaction != mouse::Interaction::None => interaction,
+            // Hide the widgets below the backdrop from the cursor
+            _ => mouse::Interaction::Idle,
+        }
+    }
+}
@@ -0,0 +1,347 @@
+//! A text input to edit a color as text, e.g. as a hex code or a single component.
+
+use super::Hsv;
+
+use iced_core::widget::{Id, Operation, Tree, Widget, tree};
+use iced_core::{Element, Length, Padding, Pixels, Rectangle, Shell, Size, layout, mouse, text};
+use iced_widget::text_input::{self, TextInput};
+
+/// Turns the text and the current color into the new color, if the text is valid.
+type ParseFn<'a> = Box<dyn Fn(&str, Hsv) -> Option<Hsv> + 'a>;
+
+/// A text input showing a color as text, produced by `format`.
+///
+/// A message is only produced once `parse` accepts the text, so half typed values never
+/// reach the application. Invalid text is reverted to the current color when the input is
+/// submitted or loses focus.
+pub struct ColorField<'a, Message, Theme, Renderer>
+where
+    Theme: text_input::Catalog,
+    Renderer: text::Renderer,
+{
+    color: Hsv,
+    format: Box<dyn Fn(Hsv) -> String + 'a>,
+    parse: ParseFn<'a>,
+    on_select: Box<dyn Fn(Hsv) -> Message + 'a>,
+    placeholder: String,
+    width: Length,
+    padding: Padding,
+    size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    id: Option<Id>,
+    class: Option<Theme::Class<'a>>,
+    /// Built around the text being edited, once the tree is known.
+    text_input: Option<TextInput<'a, Edit, Theme, Renderer>>,
+}
+
+impl<'a, Message, Theme, Renderer> ColorField<'a, Message, Theme, Renderer>
+where
+    Theme: text_input::Catalog,
+    Renderer: text::Renderer,
+{
+    /// Creates a [ColorField] showing the color as given by `format`.
+    ///
+    /// `parse` receives the text and the current color, and returns the new color if the
+    /// text is valid.
+    pub fn new(
+        color: impl Into<Hsv>,
+        format: impl Fn(Hsv) -> String + 'a,
+        parse: impl Fn(&str, Hsv) -> Option<Hsv> + 'a,
+        on_select: impl Fn(Hsv) -> Message + 'a,
+    ) -> Self {
+        Self {
+            color: color.into(),
+            format: Box::new(format),
+            parse: Box::new(parse),
+            on_select: Box::new(on_select),
+            placeholder: String::new(),
+            width: Length::Fill,
+            padding: Padding::new(5.0),
+            size: None,
+            font: None,
+            id: None,
+            class: None,
+            text_input: None,
+        }
+    }
+
+    /// Set the placeholder shown while the [ColorField] is empty.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Set the width of the [ColorField].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Set the padding of the [ColorField].
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Set the text size of the [ColorField].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Set the font of the [ColorField].
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Set the [Id] of the [ColorField], to focus it with an operation.
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Set the style of the [ColorField].
+    pub fn style(
+        mut self,
+        style: impl Fn(&Theme, text_input::Status) -> text_input::Style + 'a,
+    ) -> Self
+    where
+        Theme::Class<'a>: From<text_input::StyleFn<'a, Theme>>,
+    {
+        self.class = Some(Theme::Class::from(Box::new(style)));
+        self
+    }
+
+    /// Set the style class of the [ColorField].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = Some(class.into());
+        self
+    }
+
+    /// Replaces invalid text by the current color.
+    fn revert(&self, state: &mut State, shell: &mut Shell<'_, Message>) {
+        if (self.parse)(&state.text, state.color).is_none() {
+            state.text = (self.format)(state.color);
+            shell.invalidate_widgets();
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ColorField<'a, Message, Theme, Renderer>
+where
+    Theme: text_input::Catalog,
+    Renderer: text::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            text: (self.format)(self.color),
+            color: self.color,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        let text_input = TextInput::<Edit, Theme, Renderer>::new("", "");
+
+        vec![Tree::new(&text_input as &dyn Widget<_, _, _>)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state: &mut State = tree.state.downcast_mut();
+
+        // Follow the color when it was changed elsewhere, keeping the text as typed otherwise
+        if (self.format)(state.color) != (self.format)(self.color) {
+            state.text = (self.format)(self.color);
+        }
+
+        state.color = self.color;
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let State { text, .. }: &State = tree.state.downcast_ref();
+
+        // A text input can't change its value, so it's built once the text is known, and
+        // the widgets are invalidated whenever the text changes to build a new one
+        let text_input = self.text_input.get_or_insert_with(|| {
+            let mut text_input = TextInput::new(&self.placeholder, text)
+                .on_input(Edit::Input)
+                .on_submit(Edit::Submit)
+                .width(self.width)
+                .padding(self.padding)
+                .class(self.class.take().unwrap_or_else(Theme::default));
+
+            if let Some(size) = self.size {
+                text_input = text_input.size(size);
+            }
+
+            if let Some(font) = self.font {
+                text_input = text_input.font(font);
+            }
+
+            if let Some(id) = self.id.clone() {
+                text_input = text_input.id(id);
+            }
+
+            text_input
+        });
+
+        Widget::<Edit, Theme, Renderer>::layout(text_input, &mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: layout::Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if let Some(text_input) = &mut self.text_input {
+            text_input.operate(&mut tree.children[0], layout, renderer, operation);
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        match &self.text_input {
+            Some(text_input) => {
+                text_input.mouse_interaction(&tree.children[0], layout, cursor, viewport, renderer)
+            }
+            None => Default::default(),
+        }
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &iced_core::Event,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let Some(text_input) = &mut self.text_input else {
+            return;
+        };
+
+        let was_focused = is_focused::<Renderer>(&tree.children[0]);
+
+        let mut edits = Vec::new();
+        let mut local_shell = Shell::new(&mut edits);
+
+        text_input.update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            &mut local_shell,
+            viewport,
+        );
+
+        if local_shell.is_event_captured() {
+            shell.capture_event();
+        }
+
+        shell.request_redraw_at(local_shell.redraw_request());
+        shell.request_input_method(local_shell.input_method());
+
+        let is_focused = is_focused::<Renderer>(&tree.children[0]);
+        let state: &mut State = tree.state.downcast_mut();
+
+        for edit in edits {
+            match edit {
+                Edit::Input(text) => {
+                    if let Some(color) = (self.parse)(&text, state.color) {
+                        state.color = color;
+                        shell.publish((self.on_select)(color));
+                    }
+
+                    state.text = text;
+                    shell.invalidate_widgets();
+                }
+                Edit::Submit => self.revert(state, shell),
+            }
+        }
+
+        if was_focused && !is_focused {
+            self.revert(state, shell);
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &iced_core::renderer::Style,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        if let Some(text_input) = &self.text_input {
+            Widget::<Edit, Theme, Renderer>::draw(
+                text_input,
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                layout,
+                cursor,
+                viewport,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ColorField<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: text_input::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(value: ColorField<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Messages of the inner text input.
+#[derive(Debug, Clone)]
+enum Edit {
+    Input(String),
+    Submit,
+}
+
+struct State {
+    /// The text being edited, which may not be valid.
+    text: String,
+    /// The color last given to, or picked by, the [ColorField].
+    color: Hsv,
+}
+
+fn is_focused<Renderer: text::Renderer>(tree: &Tree) -> bool {
+    tree.state
+        .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+        .is_focused()
+}
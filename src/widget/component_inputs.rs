@@ -0,0 +1,187 @@
+//! Numeric fields to type the hue, saturation, value, red, green and blue of a color.
+
+use super::{ColorField, Hsv};
+
+use std::rc::Rc;
+
+use iced_core::{Color, Element, Length, Pixels, alignment, text};
+use iced_widget::{Column, Row, text_input};
+
+/// Creates new [ComponentInputs] with the current [Hsv] (or [Color]) value, and a closure to produce a message when a valid value is typed.
+pub fn component_inputs<'a, Message, FromHsv>(
+    color: impl Into<Hsv>,
+    on_select: impl Fn(FromHsv) -> Message + 'a,
+) -> ComponentInputs<'a, Message>
+where
+    Message: 'a,
+    FromHsv: From<Hsv> + 'a,
+{
+    ComponentInputs::new(color, move |color| on_select(color.into()))
+}
+
+/// A labeled field for each component of a color, with a row for `H S V` and one for `R G B`.
+///
+/// Hue is typed in degrees, saturation and value as percentages, and red, green and blue on `[0, 255]`.
+/// Each field only produces a message once its text is a valid number in range.
+pub struct ComponentInputs<'a, Message> {
+    color: Hsv,
+    on_select: Rc<dyn Fn(Hsv) -> Message + 'a>,
+    width: Length,
+    spacing: f32,
+    size: Option<Pixels>,
+}
+
+impl<'a, Message> ComponentInputs<'a, Message> {
+    pub fn new(color: impl Into<Hsv>, on_select: impl Fn(Hsv) -> Message + 'a) -> Self {
+        Self {
+            color: color.into(),
+            on_select: Rc::new(on_select),
+            width: Length::Fill,
+            spacing: 4.0,
+            size: None,
+        }
+    }
+
+    /// Set the width of the [ComponentInputs], shared evenly between the fields of a row.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Set the space between the fields and their labels.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Set the text size of the fields and their labels.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ComponentInputs<'a, Message>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: text_input::Catalog + iced_widget::text::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(inputs: ComponentInputs<'a, Message>) -> Self {
+        let field = |component: Component| -> Element<'a, Message, Theme, Renderer> {
+            let on_select = inputs.on_select.clone();
+
+            let mut label = iced_widget::text(component.label());
+            let mut field = ColorField::new(
+                inputs.color,
+                move |color| component.format(color),
+                move |text, color| component.parse(text, color),
+                move |color| on_select(color),
+            );
+
+            if let Some(size) = inputs.size {
+                label = label.size(size);
+                field = field.size(size);
+            }
+
+            Row::with_children([label.into(), field.into()])
+                .spacing(inputs.spacing)
+                .align_y(alignment::Vertical::Center)
+                .into()
+        };
+
+        let row = |components: [Component; 3]| {
+            Row::with_children(components.map(field))
+                .spacing(inputs.spacing)
+                .into()
+        };
+
+        Column::with_children([row(Component::HSV), row(Component::RGB)])
+            .spacing(inputs.spacing)
+            .width(inputs.width)
+            .into()
+    }
+}
+
+/// The component edited by a field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Component {
+    Hue,
+    Saturation,
+    Value,
+    Red,
+    Green,
+    Blue,
+}
+
+impl Component {
+    const HSV: [Component; 3] = [Component::Hue, Component::Saturation, Component::Value];
+    const RGB: [Component; 3] = [Component::Red, Component::Green, Component::Blue];
+
+    fn label(self) -> &'static str {
+        match self {
+            Component::Hue => "H",
+            Component::Saturation => "S",
+            Component::Value => "V",
+            Component::Red => "R",
+            Component::Green => "G",
+            Component::Blue => "B",
+        }
+    }
+
+    fn format(self, color: Hsv) -> String {
+        let [r, g, b] = color.to_rgb8();
+
+        let value = match self {
+            Component::Hue => color.h.round() as u32 % 360,
+            Component::Saturation => (color.s * 100.0).round() as u32,
+            Component::Value => (color.v * 100.0).round() as u32,
+            Component::Red => r as u32,
+            Component::Green => g as u32,
+            Component::Blue => b as u32,
+        };
+
+        value.to_string()
+    }
+
+    /// Sets the component of the color to the typed value, if it's a number in range.
+    fn parse(self, text: &str, color: Hsv) -> Option<Hsv> {
+        let value: f32 = text.trim().parse().ok()?;
+
+        let max = match self {
+            Component::Hue => 360.0,
+            Component::Saturation | Component::Value => 100.0,
+            Component::Red | Component::Green | Component::Blue => 255.0,
+        };
+
+        if !(0.0..=max).contains(&value) {
+            return None;
+        }
+
+        let channel = |set: fn(&mut Color, f32)| {
+            let mut rgb = Color::from(color);
+            set(&mut rgb, value / 255.0);
+
+            Hsv::from_color_with_previous(rgb, color)
+        };
+
+        Some(match self {
+            Component::Hue => Hsv {
+                h: value.rem_euclid(360.0),
+                ..color
+            },
+            Component::Saturation => Hsv {
+                s: value / 100.0,
+                ..color
+            },
+            Component::Value => Hsv {
+                v: value / 100.0,
+                ..color
+            },
+            Component::Red => channel(|color, value| color.r = value),
+            Component::Green => channel(|color, value| color.g = value),
+            Component::Blue => channel(|color, value| color.b = value),
+        })
+    }
+}
@@ -0,0 +1,209 @@
+//! Sample text in two colors on each other, with the levels of WCAG their contrast meets.
+
+use super::Hsv;
+use super::hsv::TextSize;
+
+use iced_core::{Color, Element, Length, Pixels, alignment, border, text};
+use iced_widget::{Column, Row, container};
+
+/// The background of a badge for a level that's met.
+const PASS: Color = Color::from_rgb(0.18, 0.49, 0.2);
+/// The background of a badge for a level that isn't met.
+const FAIL: Color = Color::from_rgb(0.78, 0.16, 0.16);
+
+/// Creates a new [ContrastChecker] of the foreground and background [Hsv] (or [Color]) values.
+pub fn contrast_checker(foreground: impl Into<Hsv>, background: impl Into<Hsv>) -> ContrastChecker {
+    ContrastChecker::new(foreground, background)
+}
+
+/// Sample text in the foreground color on the background, and the other way around, above
+/// the contrast ratio and a badge for each level of WCAG, showing whether it's met.
+///
+/// Pair it with two pickers, e.g. for the text and background colors of a theme.
+pub struct ContrastChecker {
+    foreground: Hsv,
+    background: Hsv,
+    sample: String,
+    width: Length,
+    spacing: f32,
+    size: Option<Pixels>,
+}
+
+impl ContrastChecker {
+    pub fn new(foreground: impl Into<Hsv>, background: impl Into<Hsv>) -> Self {
+        Self {
+            foreground: foreground.into(),
+            background: background.into(),
+            sample: String::from("The quick brown fox"),
+            width: Length::Fill,
+            spacing: 4.0,
+            size: None,
+        }
+    }
+
+    /// Set the sample text drawn in both combinations of the colors.
+    pub fn sample(mut self, sample: impl Into<String>) -> Self {
+        self.sample = sample.into();
+        self
+    }
+
+    /// Set the width of the [ContrastChecker].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Set the space between the samples and the badges.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Set the text size of the samples and the badges.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ContrastChecker> for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: container::Catalog + iced_widget::text::Catalog + 'a,
+    <Theme as container::Catalog>::Class<'a>: From<container::StyleFn<'a, Theme>>,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(checker: ContrastChecker) -> Self {
+        let ContrastChecker {
+            foreground,
+            background,
+            sample,
+            width,
+            spacing,
+            size,
+        } = checker;
+
+        let label = |content: String| {
+            let label = iced_widget::text(content);
+
+            match size {
+                Some(size) => label.size(size),
+                None => label,
+            }
+        };
+
+        let sample = |text_color: Hsv, background: Hsv| -> Element<'a, Message, Theme, Renderer> {
+            container(label(sample.clone()))
+                .padding(spacing * 2.0)
+                .width(Length::Fill)
+                .style(move |_| {
+                    container::background(Color::from(background)).color(Color::from(text_color))
+                })
+                .into()
+        };
+
+        let badge = |name: &'static str, passes: bool| -> Element<'a, Message, Theme, Renderer> {
+            let background = match passes {
+                true => PASS,
+                false => FAIL,
+            };
+
+            container(label(name.to_string()))
+                .padding([spacing / 2.0, spacing * 1.5])
+                .style(move |_| {
+                    container::background(background)
+                        .color(Color::WHITE)
+                        .border(border::rounded(spacing))
+                })
+                .into()
+        };
+
+        let ratio = foreground.contrast_ratio(&background);
+        let levels = [
+            (
+                "AA",
+                foreground.meets_wcag_aa(&background, TextSize::Normal),
+            ),
+            (
+                "AAA",
+                foreground.meets_wcag_aaa(&background, TextSize::Normal),
+            ),
+            (
+                "AA large",
+                foreground.meets_wcag_aa(&background, TextSize::Large),
+            ),
+            (
+                "AAA large",
+                foreground.meets_wcag_aaa(&background, TextSize::Large),
+            ),
+        ];
+
+        let badges = Row::with_children(
+            std::iter::once(label(format!("{ratio:.2}:1")).into())
+                .chain(levels.map(|(name, passes)| badge(name, passes))),
+        )
+        .spacing(spacing)
+        .align_y(alignment::Vertical::Center);
+
+        Column::with_children([
+            sample(foreground, background),
+            sample(background, foreground),
+            badges.into(),
+        ])
+        .spacing(spacing)
+        .width(width)
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gray(v: u8) -> Hsv {
+        Hsv::from_rgb8([v; 3])
+    }
+
+    #[test]
+    fn contrast_ratio() {
+        let white = gray(255);
+
+        assert!((gray(0).contrast_ratio(&white) - 21.0).abs() < 1e-3);
+        assert!((white.contrast_ratio(&white) - 1.0).abs() < 1e-3);
+        // Either way around
+        assert_eq!(
+            gray(0x76).contrast_ratio(&white),
+            white.contrast_ratio(&gray(0x76))
+        );
+    }
+
+    #[test]
+    fn aa_boundary_of_normal_text() {
+        let white = gray(255);
+
+        // 4.54:1 and 4.48:1
+        assert!(gray(0x76).meets_wcag_aa(&white, TextSize::Normal));
+        assert!(!gray(0x77).meets_wcag_aa(&white, TextSize::Normal));
+    }
+
+    #[test]
+    fn aa_boundary_of_large_text() {
+        let white = gray(255);
+
+        // 3.03:1 and 2.99:1
+        assert!(gray(0x94).meets_wcag_aa(&white, TextSize::Large));
+        assert!(!gray(0x95).meets_wcag_aa(&white, TextSize::Large));
+        // Large text passes with less contrast than normal text needs
+        assert!(!gray(0x94).meets_wcag_aa(&white, TextSize::Normal));
+    }
+
+    #[test]
+    fn aaa_boundaries() {
+        let white = gray(255);
+
+        assert!(gray(0x76).meets_wcag_aaa(&white, TextSize::Large));
+        assert!(!gray(0x77).meets_wcag_aaa(&white, TextSize::Large));
+        assert!(!gray(0x76).meets_wcag_aaa(&white, TextSize::Normal));
+        assert!(gray(0).meets_wcag_aaa(&white, TextSize::Normal));
+    }
+}
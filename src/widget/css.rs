@@ -0,0 +1,230 @@
+//! Parsing colors written as in CSS, e.g. pasted from a stylesheet.
+
+use super::hsv::ParseError;
+use super::{Hsl, Hsv, Hwb, named};
+
+use iced_core::Color;
+
+/// Parses a CSS color: a hex code, a named color, or one of the `rgb()`, `rgba()`,
+/// `hsl()`, `hsla()` and `hwb()` functions.
+///
+/// Both the comma separated syntax, e.g. `rgb(255, 128, 0)`, and the space separated
+/// syntax, e.g. `rgb(255 128 0 / 50%)`, are accepted, but not a mix of the two. Saturation,
+/// lightness, whiteness and blackness are percentages. Components out of range are clamped.
+pub fn parse(css: &str) -> Result<Hsv, ParseError> {
+    let css = css.trim().to_ascii_lowercase();
+
+    if css.starts_with('#') {
+        return Hsv::from_hex(&css);
+    }
+
+    if let Some((name, args)) = css.split_once('(') {
+        let args = args.strip_suffix(')').ok_or(ParseError::InvalidCss)?;
+
+        return parse_function(name.trim_end(), args).ok_or(ParseError::InvalidCss);
+    }
+
+    if css == "transparent" {
+        return Ok(Hsv::from(Color::TRANSPARENT));
+    }
+
+    named::by_name(&css)
+        .map(Hsv::from)
+        .ok_or(ParseError::InvalidCss)
+}
+
+/// Parses the arguments of a color function, which are either all separated by commas, or
+/// by spaces with an optional `/` before the alpha.
+fn parse_function(name: &str, args: &str) -> Option<Hsv> {
+    let args: Vec<&str> = match args.contains(',') {
+        true => args
+            .split(',')
+            .map(str::trim)
+            .map(single)
+            .collect::<Option<_>>()?,
+        false => {
+            let (components, alpha) = match args.split_once('/') {
+                Some((components, alpha)) => (components, Some(single(alpha.trim())?)),
+                None => (args, None),
+            };
+
+            components.split_whitespace().chain(alpha).collect()
+        }
+    };
+
+    let (first, second, third, alpha) = match args.as_slice() {
+        [first, second, third] => (*first, *second, *third, None),
+        [first, second, third, alpha] => (*first, *second, *third, Some(*alpha)),
+        _ => return None,
+    };
+
+    let a = match alpha {
+        Some(alpha) => number_or_percentage(alpha, 1.0)?.clamp(0.0, 1.0),
+        None => 1.0,
+    };
+
+    let color = match name {
+        "rgb" | "rgba" => {
+            let channel =
+                |arg: &str| Some(number_or_percentage(arg, 255.0)?.clamp(0.0, 255.0) / 255.0);
+
+            Hsv::from_rgba([channel(first)?, channel(second)?, channel(third)?, a])
+        }
+        "hsl" | "hsla" => Hsv::from(Hsl {
+            h: hue(first)?,
+            s: percentage(second)?,
+            l: percentage(third)?,
+            a,
+        }),
+        "hwb" => Hsv::from(Hwb {
+            h: hue(first)?,
+            w: percentage(second)?,
+            b: percentage(third)?,
+            a,
+        }),
+        _ => return None,
+    };
+
+    Some(color)
+}
+
+/// Returns the argument if it's a single token, without any whitespace or `/` in it.
+fn single(arg: &str) -> Option<&str> {
+    (!arg.is_empty() && !arg.contains(|c: char| c.is_whitespace() || c == '/')).then_some(arg)
+}
+
+/// Parses a number, or a percentage of `full`. `none` is zero.
+fn number_or_percentage(arg: &str, full: f32) -> Option<f32> {
+    match arg.strip_suffix('%') {
+        Some(percentage) => Some(number(percentage)? / 100.0 * full),
+        None => number(arg),
+    }
+}
+
+/// Parses a percentage as a fraction, where the `%` is required. `none` is zero.
+fn percentage(arg: &str) -> Option<f32> {
+    let percentage = match arg {
+        "none" => 0.0,
+        _ => number(arg.strip_suffix('%')?)?,
+    };
+
+    Some((percentage / 100.0).clamp(0.0, 1.0))
+}
+
+/// Parses an angle in degrees, where the unit is optional, or in radians, gradians or turns.
+fn hue(arg: &str) -> Option<f32> {
+    let degrees = if let Some(degrees) = arg.strip_suffix("deg") {
+        number(degrees)?
+    } else if let Some(gradians) = arg.strip_suffix("grad") {
+        number(gradians)? * 0.9
+    } else if let Some(radians) = arg.strip_suffix("rad") {
+        number(radians)?.to_degrees()
+    } else if let Some(turns) = arg.strip_suffix("turn") {
+        number(turns)? * 360.0
+    } else {
+        number(arg)?
+    };
+
+    Some(degrees.rem_euclid(360.0))
+}
+
+/// Parses a finite number, where `none` is zero.
+fn number(arg: &str) -> Option<f32> {
+    match arg {
+        "none" => Some(0.0),
+        _ => arg.parse().ok().filter(|number: &f32| number.is_finite()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rgba8(css: &str) -> [u8; 4] {
+        parse(css).unwrap().to_rgba8()
+    }
+
+    #[test]
+    fn hex_and_named() {
+        assert_eq!(rgba8("#ff8000"), [255, 128, 0, 255]);
+        assert_eq!(rgba8("#F80"), [255, 136, 0, 255]);
+        assert_eq!(rgba8("#ff800080"), [255, 128, 0, 128]);
+        assert_eq!(rgba8("RebeccaPurple"), [102, 51, 153, 255]);
+        assert_eq!(rgba8(" white "), [255, 255, 255, 255]);
+        assert_eq!(rgba8("transparent")[3], 0);
+    }
+
+    #[test]
+    fn rgb() {
+        assert_eq!(rgba8("rgb(255, 128, 0)"), [255, 128, 0, 255]);
+        assert_eq!(rgba8("rgba(255, 128, 0, 0.5)"), [255, 128, 0, 128]);
+        assert_eq!(rgba8("rgb(255 128 0)"), [255, 128, 0, 255]);
+        assert_eq!(rgba8("rgb(255 128 0 / 50%)"), [255, 128, 0, 128]);
+        assert_eq!(rgba8("rgb(255 128 0/0.5)"), [255, 128, 0, 128]);
+        assert_eq!(rgba8("rgb(100%, 50%, 0%)"), [255, 128, 0, 255]);
+        assert_eq!(rgba8("RGB(none 128 0)"), [0, 128, 0, 255]);
+        // Out of range components are clamped
+        assert_eq!(rgba8("rgb(300, -20, 0, 2)"), [255, 0, 0, 255]);
+    }
+
+    #[test]
+    fn hsl() {
+        assert_eq!(rgba8("hsl(120, 100%, 50%)"), [0, 255, 0, 255]);
+        assert_eq!(rgba8("hsla(120, 100%, 50%, 0.5)"), [0, 255, 0, 128]);
+        assert_eq!(rgba8("hsl(120 100% 50%)"), [0, 255, 0, 255]);
+        assert_eq!(rgba8("hsl(120 100% 50% / 50%)"), [0, 255, 0, 128]);
+        assert_eq!(rgba8("hsl(120 none 50%)"), [128, 128, 128, 255]);
+    }
+
+    #[test]
+    fn hue_units() {
+        for css in [
+            "hsl(120 100% 50%)",
+            "hsl(120deg 100% 50%)",
+            "hsl(133.3333grad 100% 50%)",
+            "hsl(2.0943951rad 100% 50%)",
+            "hsl(0.3333333turn 100% 50%)",
+            "hsl(-240 100% 50%)",
+        ] {
+            assert_eq!(rgba8(css), [0, 255, 0, 255], "{css}");
+        }
+    }
+
+    #[test]
+    fn hwb() {
+        assert_eq!(rgba8("hwb(0 0% 0%)"), [255, 0, 0, 255]);
+        assert_eq!(rgba8("hwb(0 100% 0%)"), [255, 255, 255, 255]);
+        assert_eq!(rgba8("hwb(0, 0%, 100%, 0.5)"), [0, 0, 0, 128]);
+    }
+
+    #[test]
+    fn rejects() {
+        for css in [
+            // Not finite
+            "rgb(nan% 0% 0%)",
+            "rgb(inf 0 0)",
+            "hsl(0 inf% 50%)",
+            "hsl(nan 100% 50%)",
+            // Mixed or repeated separators
+            "rgb(1,2 3)",
+            "rgb(1/2/3/4)",
+            "rgb(1 2 / 3 / 4)",
+            "rgb(1, 2, 3 / 0.5)",
+            "rgb(1 2 3 /)",
+            "rgb(1, 2, , 3)",
+            // Missing units
+            "hsl(120, 50, 50)",
+            "hsl(120 50% 50)",
+            "hwb(0 0 0)",
+            // Wrong number of arguments
+            "rgb(1 2)",
+            "rgb(1 2 3 4 5)",
+            // Not a color
+            "rgb(1 2 3",
+            "lab(50 0 0)",
+            "notacolor",
+        ] {
+            assert_eq!(parse(css), Err(ParseError::InvalidCss), "{css}");
+        }
+    }
+}
@@ -0,0 +1,214 @@
+use super::Hsv;
+use super::hsv::OutOfGamut;
+use super::srgb::{from_linear, in_gamut, to_linear};
+
+use iced_core::Color;
+
+/// A color in [Display P3](https://www.color.org/chardata/rgb/DisplayP3.xalter), the wide
+/// gamut of recent Apple displays, with the primaries of DCI-P3 and the transfer function of sRGB.
+///
+/// Its gamut holds all of sRGB and then some, mostly more saturated reds and greens. See
+/// [Gamut::DisplayP3](super::Gamut::DisplayP3) to pick colors in it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DisplayP3 {
+    /// The red channel, on `[0, 1]`.
+    pub r: f32,
+    /// The green channel, on `[0, 1]`.
+    pub g: f32,
+    /// The blue channel, on `[0, 1]`.
+    pub b: f32,
+    /// The alpha component.
+    pub a: f32,
+}
+
+impl Default for DisplayP3 {
+    fn default() -> Self {
+        Self {
+            r: Default::default(),
+            g: Default::default(),
+            b: Default::default(),
+            a: 1.0,
+        }
+    }
+}
+
+impl DisplayP3 {
+    /// The color with the channels of the [Hsv], as picked by a spectrum in
+    /// [Gamut::DisplayP3](super::Gamut::DisplayP3).
+    ///
+    /// Unlike `DisplayP3::from`, this doesn't convert from sRGB.
+    pub fn from_p3_hsv(hsv: Hsv) -> Self {
+        let Color { r, g, b, a } = Color::from(hsv);
+
+        Self { r, g, b, a }
+    }
+
+    /// The [Hsv] of the channels of the color, as given to a spectrum in
+    /// [Gamut::DisplayP3](super::Gamut::DisplayP3).
+    ///
+    /// Unlike `Hsv::from`, this doesn't convert to sRGB.
+    pub fn to_p3_hsv(self) -> Hsv {
+        let DisplayP3 { r, g, b, a } = self;
+
+        Hsv::from(Color { r, g, b, a })
+    }
+
+    /// Converts to a [Color] in sRGB without clamping.
+    ///
+    /// Unlike `Color::from`, this fails if the color falls outside of the sRGB gamut.
+    pub fn try_to_color(self) -> Result<Color, OutOfGamut> {
+        let rgb = self.to_linear_srgb();
+
+        match in_gamut(rgb) {
+            true => {
+                let [r, g, b] = rgb.map(|c| from_linear(c.clamp(0.0, 1.0)));
+                Ok(Color::from_rgba(r, g, b, self.a))
+            }
+            false => Err(OutOfGamut {
+                clamped: Color::from(self),
+            }),
+        }
+    }
+
+    /// Formats the color as a CSS `color(display-p3 ...)`, with the alpha if translucent.
+    pub fn to_css(self) -> String {
+        let DisplayP3 { r, g, b, a } = self;
+
+        match a < 1.0 {
+            true => format!("color(display-p3 {r:.4} {g:.4} {b:.4} / {a:.3})"),
+            false => format!("color(display-p3 {r:.4} {g:.4} {b:.4})"),
+        }
+    }
+
+    // https://www.w3.org/TR/css-color-4/#color-conversion-code
+    fn to_linear_srgb(self) -> [f32; 3] {
+        let [r, g, b] = [self.r, self.g, self.b].map(to_linear);
+
+        [
+            1.224_940_2 * r - 0.224_940_18 * g,
+            -0.042_056_955 * r + 1.042_056_9 * g,
+            -0.019_637_555 * r - 0.078_636_05 * g + 1.098_273_6 * b,
+        ]
+    }
+}
+
+impl From<Color> for DisplayP3 {
+    // https://www.w3.org/TR/css-color-4/#color-conversion-code
+    fn from(color: Color) -> Self {
+        let [r, g, b] = [color.r, color.g, color.b].map(to_linear);
+
+        let [r, g, b] = [
+            0.822_462_1 * r + 0.177_538 * g,
+            0.033_194_2 * r + 0.966_805_8 * g,
+            0.017_082_63 * r + 0.072_397_44 * g + 0.910_519_9 * b,
+        ]
+        .map(|c| from_linear(c.clamp(0.0, 1.0)));
+
+        Self {
+            r,
+            g,
+            b,
+            a: color.a,
+        }
+    }
+}
+
+impl From<DisplayP3> for Color {
+    /// Colors outside of the sRGB gamut have each channel clamped.
+    fn from(p3: DisplayP3) -> Self {
+        let [r, g, b] = p3.to_linear_srgb().map(|c| from_linear(c.clamp(0.0, 1.0)));
+
+        Color::from_rgba(r, g, b, p3.a)
+    }
+}
+
+impl From<Hsv> for DisplayP3 {
+    fn from(hsv: Hsv) -> Self {
+        DisplayP3::from(Color::from(hsv))
+    }
+}
+
+impl From<DisplayP3> for Hsv {
+    fn from(p3: DisplayP3) -> Self {
+        Hsv::from(Color::from(p3))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn p3(r: f32, g: f32, b: f32) -> DisplayP3 {
+        DisplayP3 { r, g, b, a: 1.0 }
+    }
+
+    fn assert_close(p3: DisplayP3, [r, g, b]: [f32; 3]) {
+        assert!(
+            (p3.r - r).abs() < 1e-3 && (p3.g - g).abs() < 1e-3 && (p3.b - b).abs() < 1e-3,
+            "{p3:?} isn't close to {:?}",
+            [r, g, b]
+        );
+    }
+
+    #[test]
+    fn reference_values() {
+        // The sRGB primaries, as CSS converts them
+        assert_close(
+            DisplayP3::from(Color::from_rgb(1.0, 0.0, 0.0)),
+            [0.9175, 0.2003, 0.1386],
+        );
+        assert_close(
+            DisplayP3::from(Color::from_rgb(0.0, 1.0, 0.0)),
+            [0.4584, 0.9853, 0.2983],
+        );
+        assert_close(
+            DisplayP3::from(Color::from_rgb(0.0, 0.0, 1.0)),
+            [0.0, 0.0, 0.9596],
+        );
+        assert_close(DisplayP3::from(Color::WHITE), [1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn matrices_are_inverses() {
+        for color in [
+            p3(0.9175, 0.2003, 0.1386),
+            p3(0.3, 0.6, 0.9),
+            p3(0.5, 0.5, 0.5),
+        ] {
+            let back = DisplayP3::from(color.try_to_color().unwrap());
+
+            assert_close(back, [color.r, color.g, color.b]);
+        }
+    }
+
+    #[test]
+    fn gamut_boundary() {
+        // sRGB red is on the edge of the sRGB gamut within P3
+        assert!(p3(0.9175, 0.2003, 0.1386).try_to_color().is_ok());
+        assert!(p3(0.94, 0.2003, 0.1386).try_to_color().is_err());
+        assert!(p3(0.9175, 0.18, 0.1386).try_to_color().is_err());
+
+        // The P3 primaries lie outside of sRGB, and are clamped into it
+        let OutOfGamut { clamped } = p3(1.0, 0.0, 0.0).try_to_color().unwrap_err();
+        assert_eq!(clamped, Color::from(p3(1.0, 0.0, 0.0)));
+        assert!(clamped.r > 0.999 && clamped.g == 0.0 && clamped.b == 0.0);
+        assert!(p3(0.0, 1.0, 0.0).try_to_color().is_err());
+    }
+
+    #[test]
+    fn css() {
+        assert_eq!(
+            p3(1.0, 0.5, 0.0).to_css(),
+            "color(display-p3 1.0000 0.5000 0.0000)"
+        );
+        assert_eq!(
+            DisplayP3 {
+                a: 0.5,
+                ..p3(1.0, 0.5, 0.0)
+            }
+            .to_css(),
+            "color(display-p3 1.0000 0.5000 0.0000 / 0.500)"
+        );
+    }
+}
@@ -0,0 +1,272 @@
+//! Pick colors from content of the application, e.g. the canvas of an image editor.
+
+use super::{Hsv, Pointer, SourceImage};
+
+use iced_core::widget::{Operation, Tree, Widget, tree};
+use iced_core::{
+    Element, Event, Length, Point, Rectangle, Shell, Size, Vector, layout, mouse, overlay,
+    renderer, touch,
+};
+
+/// Creates a new [Eyedropper] over the `content`, which picks from the image while `is_active` is true.
+pub fn eyedropper<'a, Message, Theme, Renderer, FromHsv>(
+    is_active: bool,
+    image: SourceImage,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    on_select: impl Fn(FromHsv) -> Message + 'a,
+) -> Eyedropper<'a, Message, Theme, Renderer>
+where
+    FromHsv: From<Hsv> + 'a,
+{
+    Eyedropper::new(is_active, image, content, move |color| {
+        on_select(color.into())
+    })
+}
+
+/// Wraps content of the application, e.g. a canvas, to pick colors from it.
+///
+/// Colors are sampled from a [SourceImage] of what the content shows, stretched over its
+/// bounds. While active, pressing or dragging over the content picks the color under the
+/// cursor instead of interacting with the content.
+pub struct Eyedropper<'a, Message, Theme, Renderer> {
+    is_active: bool,
+    image: SourceImage,
+    content: Element<'a, Message, Theme, Renderer>,
+    on_select: Box<dyn Fn(Hsv) -> Message + 'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Eyedropper<'a, Message, Theme, Renderer> {
+    pub fn new(
+        is_active: bool,
+        image: SourceImage,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        on_select: impl Fn(Hsv) -> Message + 'a,
+    ) -> Self {
+        Self {
+            is_active,
+            image,
+            content: content.into(),
+            on_select: Box::new(on_select),
+        }
+    }
+
+    /// Publishes the color under the point, if there is one.
+    fn pick(&self, bounds: Rectangle, point: Point, shell: &mut Shell<'_, Message>) {
+        if let Some(color) = self.image.sample(bounds, point) {
+            shell.publish((self.on_select)(color));
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Eyedropper<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: layout::Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let State { pressed }: &mut State = tree.state.downcast_mut();
+        let bounds = layout.bounds();
+
+        if !self.is_active {
+            *pressed = None;
+
+            self.content.as_widget_mut().update(
+                &mut tree.children[0],
+                event,
+                layout,
+                cursor,
+                renderer,
+                shell,
+                viewport,
+            );
+
+            return;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if let Some(position) = cursor.position_over(bounds) {
+                    *pressed = Some(Pointer::Mouse);
+
+                    self.pick(bounds, position, shell);
+                    shell.capture_event();
+                }
+            }
+            // The cursor may be unavailable over another layer, but a drag should follow it
+            Event::Mouse(mouse::Event::CursorMoved { position })
+                if *pressed == Some(Pointer::Mouse) =>
+            {
+                self.pick(bounds, *position, shell);
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if *pressed == Some(Pointer::Mouse) =>
+            {
+                *pressed = None;
+                shell.capture_event();
+            }
+            Event::Touch(touch::Event::FingerPressed { id, position })
+                if pressed.is_none() && bounds.contains(*position) =>
+            {
+                *pressed = Some(Pointer::Finger(id.0));
+
+                self.pick(bounds, *position, shell);
+                shell.capture_event();
+            }
+            Event::Touch(touch::Event::FingerMoved { id, position })
+                if *pressed == Some(Pointer::Finger(id.0)) =>
+            {
+                self.pick(bounds, *position, shell);
+                shell.capture_event();
+            }
+            Event::Touch(
+                touch::Event::FingerLifted { id, .. } | touch::Event::FingerLost { id, .. },
+            ) if *pressed == Some(Pointer::Finger(id.0)) => {
+                *pressed = None;
+                shell.capture_event();
+            }
+            // Anything else, e.g. scrolling or the keyboard, still reaches the content
+            _ => self.content.as_widget_mut().update(
+                &mut tree.children[0],
+                event,
+                layout,
+                cursor,
+                renderer,
+                shell,
+                viewport,
+            ),
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.is_active && cursor.is_over(layout.bounds()) {
+            return mouse::Interaction::Crosshair;
+        }
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: layout::Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Eyedropper<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(value: Eyedropper<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    pressed: Option<Pointer>,
+}
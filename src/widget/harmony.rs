@@ -0,0 +1,62 @@
+//! Color harmonies: schemes of colors spread around the hue circle from a base color.
+
+use super::Hsv;
+
+/// A scheme of colors whose hues are set apart from that of a base color, keeping its
+/// saturation, value and alpha.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Harmony {
+    /// The base and the hue opposite of it.
+    #[default]
+    Complementary,
+    /// The base and its neighbours, 30° to either side.
+    Analogous,
+    /// Three hues evenly spread around the circle.
+    Triadic,
+    /// The base and the hues to either side of its complement.
+    SplitComplementary,
+    /// Two pairs of complements, 60° apart.
+    Tetradic,
+    /// Four hues evenly spread around the circle.
+    Square,
+}
+
+impl Harmony {
+    pub const ALL: [Harmony; 6] = [
+        Harmony::Complementary,
+        Harmony::Analogous,
+        Harmony::Triadic,
+        Harmony::SplitComplementary,
+        Harmony::Tetradic,
+        Harmony::Square,
+    ];
+
+    /// The hue of each color of the scheme relative to the base, in degrees, starting
+    /// with the base itself.
+    pub fn offsets(self) -> &'static [f32] {
+        match self {
+            Harmony::Complementary => &[0.0, 180.0],
+            Harmony::Analogous => &[0.0, 330.0, 30.0],
+            Harmony::Triadic => &[0.0, 120.0, 240.0],
+            Harmony::SplitComplementary => &[0.0, 150.0, 210.0],
+            Harmony::Tetradic => &[0.0, 60.0, 180.0, 240.0],
+            Harmony::Square => &[0.0, 90.0, 180.0, 270.0],
+        }
+    }
+
+    /// The colors of the scheme, starting with the base.
+    pub fn colors(self, base: Hsv) -> Vec<Hsv> {
+        self.offsets()
+            .iter()
+            .map(|offset| Hsv {
+                h: rotate(base.h, *offset),
+                ..base
+            })
+            .collect()
+    }
+}
+
+/// Turns the hue by the angle in degrees, keeping it on `[0, 360)`.
+pub(crate) fn rotate(hue: f32, angle: f32) -> f32 {
+    (hue + angle).rem_euclid(360.0).min(360f32.next_down())
+}
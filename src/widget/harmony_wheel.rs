@@ -0,0 +1,465 @@
+//! A hue wheel showing a color along with its harmony, with a marker for each color.
+
+use super::harmony::{Harmony, rotate};
+use super::style::{Catalog, Style, StyleFn};
+use super::{Hsv, Marker, Pointer, hsv, outline, quantize};
+
+use iced_core::widget::{Tree, Widget, tree};
+use iced_core::{Color, Element, Length, Point, Rectangle, Shell, Size, layout, mouse, touch};
+use iced_graphics::geometry::{self, Frame, Path, Stroke};
+
+/// How close a press has to be to a marker to grab it.
+const GRAB_RADIUS: f32 = 10.0;
+/// Size of the blocks the wheel is drawn with.
+const QUANTIZATION: f32 = 2.0;
+
+/// Creates a new [HarmonyWheel] with the base [Hsv] (or [Color]) value, the [Harmony]
+/// around it, and a closure to produce a message with the whole scheme when it's moved.
+pub fn harmony_wheel<'a, Message, Theme>(
+    color: impl Into<Hsv>,
+    harmony: Harmony,
+    on_select: impl Fn(Vec<Hsv>) -> Message + 'a,
+) -> HarmonyWheel<'a, Message, Theme>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+{
+    HarmonyWheel::new(color, harmony, on_select)
+}
+
+/// A wheel with hue around it and saturation growing outwards, with a marker for the base
+/// color and each of its companions in the [Harmony].
+///
+/// Dragging any marker rotates the whole scheme and sets the saturation of every color,
+/// publishing all of them, starting with the base. Pressing the wheel away from the
+/// markers moves the base there. The wheel is drawn at the value of the base.
+pub struct HarmonyWheel<'a, Message, Theme>
+where
+    Message: 'a,
+    Theme: Catalog,
+{
+    color: Hsv,
+    harmony: Harmony,
+    width: Length,
+    height: Length,
+    on_select: Box<dyn Fn(Vec<Hsv>) -> Message + 'a>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme> HarmonyWheel<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    pub fn new(
+        color: impl Into<Hsv>,
+        harmony: Harmony,
+        on_select: impl Fn(Vec<Hsv>) -> Message + 'a,
+    ) -> Self {
+        Self {
+            color: color.into(),
+            harmony,
+            width: Length::Fill,
+            height: Length::Fill,
+            on_select: Box::new(on_select),
+            class: Theme::default(),
+        }
+    }
+
+    /// Set both the width and height of the [HarmonyWheel].
+    ///
+    /// The wheel is always round, and centered within the bounds.
+    pub fn size(mut self, size: impl Into<Length>) -> Self {
+        let size = size.into();
+        self.width = size;
+        self.height = size;
+        self
+    }
+
+    /// Set the width of the [HarmonyWheel].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Set the height of the [HarmonyWheel].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Set the [Style] of the [HarmonyWheel].
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = Theme::Class::from(Box::new(style));
+        self
+    }
+
+    /// Set the style class of the [HarmonyWheel].
+    pub fn class(mut self, class: Theme::Class<'a>) -> Self {
+        self.class = class;
+        self
+    }
+
+    /// Returns the index of the marker nearest to the point, if it's close enough to grab.
+    fn marker_at(&self, current_color: Hsv, bounds: Rectangle, point: Point) -> Option<usize> {
+        let wheel = Wheel::new(bounds.size());
+        let point = point - (bounds.position() - Point::ORIGIN);
+
+        self.harmony
+            .colors(current_color)
+            .into_iter()
+            .map(|color| wheel.position(color).distance(point))
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .filter(|(_, distance)| *distance <= GRAB_RADIUS)
+            .map(|(index, _)| index)
+    }
+
+    /// Returns the marker under the point, or the base if the point is elsewhere on the wheel.
+    fn grab(&self, current_color: Hsv, bounds: Rectangle, point: Point) -> Option<usize> {
+        let wheel = Wheel::new(bounds.size());
+        let on_wheel =
+            (point - (bounds.position() - Point::ORIGIN)).distance(wheel.center) <= wheel.radius;
+
+        self.marker_at(current_color, bounds, point)
+            .or(on_wheel.then_some(0))
+    }
+
+    /// Moves the marker at the index to the point, turning the rest of the scheme along with it.
+    fn pick(&self, index: usize, current_color: Hsv, bounds: Rectangle, point: Point) -> Hsv {
+        let wheel = Wheel::new(bounds.size());
+        let (hue, s) = wheel.pick(point - (bounds.position() - Point::ORIGIN));
+        let offset = self.harmony.offsets().get(index).copied().unwrap_or(0.0);
+
+        Hsv {
+            h: rotate(hue, -offset),
+            s,
+            ..current_color
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for HarmonyWheel<'a, Message, Theme>
+where
+    Theme: Catalog,
+    Renderer: geometry::Renderer + 'static,
+{
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer>::default())
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.width, self.height)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let State {
+            current_color,
+            pressed,
+            ..
+        }: &State<Renderer> = tree.state.downcast_ref();
+
+        if let Some((_, Pointer::Mouse)) = pressed {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let Some(cursor) = cursor.position() else {
+            return Default::default();
+        };
+
+        if self
+            .marker_at(*current_color, layout.bounds(), cursor)
+            .is_some()
+        {
+            mouse::Interaction::Grab
+        } else if self.grab(*current_color, layout.bounds(), cursor).is_some() {
+            mouse::Interaction::Crosshair
+        } else {
+            Default::default()
+        }
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &iced_core::Event,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let State {
+            wheel_cache,
+            marker_cache,
+            pressed,
+            current_color,
+            harmony,
+        }: &mut State<Renderer> = tree.state.downcast_mut();
+
+        let bounds = layout.bounds();
+
+        if quantize(self.color) != quantize(*current_color) || self.harmony != *harmony {
+            // The wheel only depends on the value, the markers on the whole scheme
+            if quantize(self.color)[2] != quantize(*current_color)[2] {
+                wheel_cache.clear();
+            }
+
+            *current_color = self.color;
+            *harmony = self.harmony;
+            marker_cache.clear();
+            shell.request_redraw();
+        }
+
+        let (pointer, position) = match event {
+            iced_core::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position() else {
+                    return;
+                };
+
+                if pressed.is_none() {
+                    *pressed = self
+                        .grab(*current_color, bounds, position)
+                        .map(|index| (index, Pointer::Mouse));
+                }
+
+                (Pointer::Mouse, position)
+            }
+            // The cursor may be unavailable over another layer, but a drag should follow it
+            iced_core::Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                (Pointer::Mouse, *position)
+            }
+            iced_core::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                if let Some((_, Pointer::Mouse)) = pressed {
+                    *pressed = None;
+                }
+
+                return;
+            }
+            iced_core::Event::Touch(touch::Event::FingerPressed { id, position }) => {
+                if pressed.is_none() {
+                    *pressed = self
+                        .grab(*current_color, bounds, *position)
+                        .map(|index| (index, Pointer::Finger(id.0)));
+                }
+
+                (Pointer::Finger(id.0), *position)
+            }
+            iced_core::Event::Touch(touch::Event::FingerMoved { id, position }) => {
+                (Pointer::Finger(id.0), *position)
+            }
+            iced_core::Event::Touch(
+                touch::Event::FingerLifted { id, .. } | touch::Event::FingerLost { id, .. },
+            ) => {
+                if let Some((_, Pointer::Finger(finger_id))) = pressed
+                    && id.0 == *finger_id
+                {
+                    *pressed = None;
+                }
+
+                return;
+            }
+            _ => return,
+        };
+
+        // Keep moving the marker that was grabbed, even once the cursor leaves the wheel
+        if let Some((index, pressed_by)) = *pressed
+            && pressed_by == pointer
+        {
+            let base = self.pick(index, *current_color, bounds, position);
+            shell.publish((self.on_select)(self.harmony.colors(base)));
+            shell.capture_event();
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &iced_core::renderer::Style,
+        layout: iced_core::Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &iced_core::Rectangle,
+    ) {
+        let State {
+            wheel_cache,
+            marker_cache,
+            current_color,
+            ..
+        }: &State<Renderer> = tree.state.downcast_ref();
+
+        let style = theme.style(&self.class);
+
+        let bounds = layout.bounds();
+        let size = bounds.size();
+        let wheel = Wheel::new(size);
+
+        renderer.with_layer(bounds, |renderer| {
+            renderer.with_translation(bounds.position() - Point::ORIGIN, |renderer| {
+                let disk = wheel_cache.draw(renderer, size, |frame| {
+                    wheel.draw(frame, current_color.v);
+                });
+
+                let markers = marker_cache.draw(renderer, size, |frame| {
+                    let colors = self.harmony.colors(*current_color);
+                    let spoke = outline(Color::from(hsv(0.0, 0.0, current_color.v)));
+
+                    for color in &colors {
+                        frame.stroke(
+                            &Path::line(wheel.center, wheel.position(*color)),
+                            Stroke::default().with_color(spoke).with_width(1.0),
+                        );
+                    }
+
+                    // The base is drawn last, on top of any companion it overlaps
+                    for color in colors.iter().rev() {
+                        let fill = Color::from(*color);
+
+                        Marker {
+                            position: wheel.position(*color),
+                            color: fill,
+                            outline: style.marker_contrast.against(fill),
+                            track: None,
+                        }
+                        .draw(frame, &style);
+                    }
+
+                    // Set the base apart from its companions
+                    frame.stroke(
+                        &Path::circle(wheel.position(*current_color), GRAB_RADIUS),
+                        Stroke::default().with_color(spoke).with_width(2.0),
+                    );
+                });
+
+                renderer.draw_geometry(disk);
+                renderer.draw_geometry(markers);
+            });
+        });
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<HarmonyWheel<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog + 'a,
+    Renderer: geometry::Renderer + 'static,
+{
+    fn from(value: HarmonyWheel<'a, Message, Theme>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// The layout of the wheel within the bounds, relative to the top left.
+#[derive(Debug, Clone, Copy)]
+struct Wheel {
+    center: Point,
+    radius: f32,
+}
+
+impl Wheel {
+    fn new(bounds: Size) -> Self {
+        Self {
+            center: Point::new(bounds.width / 2.0, bounds.height / 2.0),
+            radius: (bounds.width.min(bounds.height) / 2.0).max(0.0),
+        }
+    }
+
+    /// Where the color sits on the wheel.
+    ///
+    /// Hue goes counter-clockwise from the right, and saturation from the center outwards.
+    fn position(&self, color: Hsv) -> Point {
+        let angle = color.h.to_radians();
+        let distance = color.s.clamp(0.0, 1.0) * self.radius;
+
+        Point::new(
+            self.center.x + distance * angle.cos(),
+            self.center.y - distance * angle.sin(),
+        )
+    }
+
+    /// The hue and saturation at the point, clamped onto the wheel.
+    fn pick(&self, point: Point) -> (f32, f32) {
+        let angle = (self.center.y - point.y).atan2(point.x - self.center.x);
+        let saturation = match self.radius > 0.0 {
+            true => (point.distance(self.center) / self.radius).clamp(0.0, 1.0),
+            false => 0.0,
+        };
+
+        (rotate(angle.to_degrees(), 0.0), saturation)
+    }
+
+    fn draw<Renderer: geometry::Renderer>(&self, frame: &mut Frame<Renderer>, value: f32) {
+        let cells = (self.radius * 2.0 / QUANTIZATION).ceil().max(0.0) as usize;
+        let origin = Point::new(self.center.x - self.radius, self.center.y - self.radius);
+
+        for col in 0..cells {
+            for row in 0..cells {
+                let position = Point::new(
+                    origin.x + col as f32 * QUANTIZATION,
+                    origin.y + row as f32 * QUANTIZATION,
+                );
+                let center = Point::new(
+                    position.x + QUANTIZATION / 2.0,
+                    position.y + QUANTIZATION / 2.0,
+                );
+
+                if center.distance(self.center) > self.radius {
+                    continue;
+                }
+
+                let (hue, saturation) = self.pick(center);
+
+                frame.fill_rectangle(
+                    position,
+                    Size::new(QUANTIZATION, QUANTIZATION),
+                    Color::from(hsv(hue, saturation, value)),
+                );
+            }
+        }
+    }
+}
+
+struct State<Renderer: geometry::Renderer> {
+    wheel_cache: geometry::Cache<Renderer>,
+    marker_cache: geometry::Cache<Renderer>,
+    /// The marker being dragged, by its index in the scheme.
+    pressed: Option<(usize, Pointer)>,
+    current_color: Hsv,
+    harmony: Harmony,
+}
+
+impl<Renderer: geometry::Renderer> Default for State<Renderer> {
+    fn default() -> Self {
+        Self {
+            wheel_cache: Default::default(),
+            marker_cache: Default::default(),
+            pressed: Default::default(),
+            current_color: Default::default(),
+            harmony: Default::default(),
+        }
+    }
+}
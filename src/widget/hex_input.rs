@@ -0,0 +1,38 @@
+//! A text input to edit a color as a hex code.
+
+use super::{ColorField, Hsv};
+
+use iced_core::{Color, text};
+use iced_widget::text_input;
+
+/// A [ColorField] showing a color as `#rrggbb`, or `#rrggbbaa` when it isn't opaque.
+pub type HexInput<'a, Message, Theme, Renderer> = ColorField<'a, Message, Theme, Renderer>;
+
+/// Creates a new [HexInput] with the current [Hsv] (or [Color]) value, and a closure to produce a message when a valid color is typed.
+///
+/// Any hex code [Hsv::from_hex] accepts can be typed, e.g. `#rgb` or `#rrggbbaa`.
+pub fn hex_input<'a, Message, Theme, Renderer, FromHsv>(
+    color: impl Into<Hsv>,
+    on_select: impl Fn(FromHsv) -> Message + 'a,
+) -> HexInput<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: text_input::Catalog + 'a,
+    Renderer: text::Renderer,
+    FromHsv: From<Hsv> + 'a,
+{
+    ColorField::new(color, format, parse, move |color| on_select(color.into()))
+        .placeholder("#rrggbb")
+}
+
+/// Formats the color as `#rrggbb`, with the alpha appended when it isn't opaque.
+fn format(color: Hsv) -> String {
+    color.to_hex(color.to_rgba8()[3] != u8::MAX)
+}
+
+/// Parses a hex code, keeping the hue of `previous` for grays.
+fn parse(text: &str, previous: Hsv) -> Option<Hsv> {
+    let color = Hsv::from_hex(text).ok()?;
+
+    Some(Hsv::from_color_with_previous(Color::from(color), previous))
+}
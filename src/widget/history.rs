@@ -0,0 +1,219 @@
+//! An undo history of colors, which folds the many updates of a drag into a single step.
+
+use iced_core::time::{Duration, Instant};
+
+use std::collections::VecDeque;
+
+/// The steps kept by default, see [History::capacity].
+const CAPACITY: usize = 100;
+/// How close together pushes have to be to join the same step by default, see [History::coalesce].
+const COALESCE: Duration = Duration::from_millis(500);
+
+/// An undo history, e.g. of the [Hsv](crate::Hsv) being edited.
+///
+/// A picker publishes a color for every move of a drag, which would take as many undos to
+/// get back out of. Pushes that come in quick succession are coalesced into a single step
+/// instead, and [History::commit] ends a step right away, e.g. once a drag is released.
+///
+/// ```
+/// use iced_color_picker::{History, Hsv, hsv};
+///
+/// let mut history = History::new(Hsv::default());
+///
+/// // A drag, publishing a color for every move and committing it on release
+/// history.push(hsv(10.0, 0.5, 0.5));
+/// history.push(hsv(20.0, 0.5, 0.5));
+/// history.commit();
+///
+/// history.push(hsv(200.0, 1.0, 1.0));
+///
+/// assert_eq!(history.undo(), Some(&hsv(20.0, 0.5, 0.5)));
+/// assert_eq!(history.undo(), Some(&Hsv::default()));
+/// assert_eq!(history.redo(), Some(&hsv(20.0, 0.5, 0.5)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct History<T> {
+    current: T,
+    /// Earlier values, oldest first.
+    undo: VecDeque<T>,
+    /// Undone values, the next one to redo last.
+    redo: Vec<T>,
+    capacity: usize,
+    coalesce: Duration,
+    /// When the step of the current value was last pushed to, while it can still be.
+    last_push: Option<Instant>,
+}
+
+impl<T: Clone + PartialEq> History<T> {
+    /// Creates a [History] starting at the value, with nothing to undo.
+    pub fn new(initial: T) -> Self {
+        Self {
+            current: initial,
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+            capacity: CAPACITY,
+            coalesce: COALESCE,
+            last_push: None,
+        }
+    }
+
+    /// Set how many steps can be undone, dropping the oldest past that. Defaults to `100`.
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self.trim();
+        self
+    }
+
+    /// Set how soon after the last push another has to come to join its step.
+    ///
+    /// Defaults to half a second, and a duration of zero gives every push a step of its own.
+    pub fn coalesce(mut self, coalesce: Duration) -> Self {
+        self.coalesce = coalesce;
+        self
+    }
+
+    /// The value as of the latest push, undo or redo.
+    pub fn current(&self) -> &T {
+        &self.current
+    }
+
+    /// Records a new value, joining the current step if it was pushed to a moment ago.
+    ///
+    /// Anything undone can no longer be redone. Pushing the current value does nothing.
+    pub fn push(&mut self, value: T) {
+        self.push_at(value, Instant::now());
+    }
+
+    /// Records a new value as if pushed at the given time, see [History::push].
+    pub fn push_at(&mut self, value: T, now: Instant) {
+        if value == self.current {
+            return;
+        }
+
+        let coalesced = self
+            .last_push
+            .is_some_and(|last| now.saturating_duration_since(last) < self.coalesce);
+
+        if coalesced {
+            self.current = value;
+        } else {
+            self.undo
+                .push_back(std::mem::replace(&mut self.current, value));
+            self.trim();
+        }
+
+        self.redo.clear();
+        self.last_push = Some(now);
+    }
+
+    /// Ends the current step, so the next push starts a step of its own however soon it comes.
+    pub fn commit(&mut self) {
+        self.last_push = None;
+    }
+
+    /// Goes back a step, returning the value there, if there is one.
+    pub fn undo(&mut self) -> Option<&T> {
+        let previous = self.undo.pop_back()?;
+
+        self.redo
+            .push(std::mem::replace(&mut self.current, previous));
+        self.last_push = None;
+
+        Some(&self.current)
+    }
+
+    /// Goes forward a step that was undone, returning the value there, if there is one.
+    pub fn redo(&mut self) -> Option<&T> {
+        let next = self.redo.pop()?;
+
+        self.undo
+            .push_back(std::mem::replace(&mut self.current, next));
+        self.trim();
+        self.last_push = None;
+
+        Some(&self.current)
+    }
+
+    /// Returns true if there's a step to go back to.
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Returns true if there's an undone step to go forward to.
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Drops the oldest steps past the capacity.
+    fn trim(&mut self) {
+        while self.undo.len() > self.capacity {
+            self.undo.pop_front();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drag_is_coalesced_into_one_step() {
+        let start = Instant::now();
+        let mut history = History::new(0);
+
+        // Moves of a drag, each well within the coalescing window of the last
+        for (i, value) in (1..=10).enumerate() {
+            history.push_at(value, start + Duration::from_millis(100) * i as u32);
+        }
+        assert_eq!(history.current(), &10);
+
+        // A push long after the drag starts a step of its own
+        history.push_at(20, start + Duration::from_secs(10));
+
+        assert_eq!(history.undo(), Some(&10));
+        assert_eq!(history.undo(), Some(&0));
+        assert!(!history.can_undo());
+    }
+
+    #[test]
+    fn commit_ends_the_step() {
+        let start = Instant::now();
+        let mut history = History::new(0);
+
+        history.push_at(1, start);
+        history.commit();
+        history.push_at(2, start);
+
+        assert_eq!(history.undo(), Some(&1));
+    }
+
+    #[test]
+    fn push_clears_redo() {
+        let mut history = History::new(0).coalesce(Duration::ZERO);
+
+        history.push(1);
+        history.push(2);
+        assert_eq!(history.undo(), Some(&1));
+        assert!(history.can_redo());
+
+        history.push(3);
+        assert!(!history.can_redo());
+        assert_eq!(history.redo(), None);
+        assert_eq!(history.undo(), Some(&1));
+    }
+
+    #[test]
+    fn oldest_steps_are_evicted_at_capacity() {
+        let mut history = History::new(0).capacity(3).coalesce(Duration::ZERO);
+
+        for value in 1..=5 {
+            history.push(value);
+        }
+
+        assert_eq!(history.undo(), Some(&4));
+        assert_eq!(history.undo(), Some(&3));
+        assert_eq!(history.undo(), Some(&2));
+        assert_eq!(history.undo(), None);
+        assert_eq!(history.current(), &2);
+    }
+}
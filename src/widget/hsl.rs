@@ -0,0 +1,64 @@
+use super::Hsv;
+
+use iced_core::Color;
+
+/// Hue, Saturation, Lightness
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hsl {
+    /// The Hue component.
+    pub h: f32,
+    /// The Saturation component.
+    pub s: f32,
+    /// The Lightness component.
+    pub l: f32,
+    /// The alpha component.
+    pub a: f32,
+}
+
+impl Default for Hsl {
+    fn default() -> Self {
+        Self {
+            h: Default::default(),
+            s: Default::default(),
+            l: Default::default(),
+            a: 1.0,
+        }
+    }
+}
+
+impl From<Hsv> for Hsl {
+    // https://en.wikipedia.org/wiki/HSL_and_HSV#HSV_to_HSL
+    fn from(Hsv { h, s, v, a }: Hsv) -> Self {
+        let l = v * (1.0 - s / 2.0);
+        let s = if l <= 0.0 || l >= 1.0 {
+            0.0
+        } else {
+            (v - l) / l.min(1.0 - l)
+        };
+
+        Self { h, s, l, a }
+    }
+}
+
+impl From<Hsl> for Hsv {
+    // https://en.wikipedia.org/wiki/HSL_and_HSV#HSL_to_HSV
+    fn from(Hsl { h, s, l, a }: Hsl) -> Self {
+        let v = l + s * l.min(1.0 - l);
+        let s = if v <= 0.0 { 0.0 } else { 2.0 * (1.0 - l / v) };
+
+        Self { h, s, v, a }
+    }
+}
+
+impl From<Hsl> for Color {
+    fn from(hsl: Hsl) -> Self {
+        Color::from(Hsv::from(hsl))
+    }
+}
+
+impl From<Color> for Hsl {
+    fn from(color: Color) -> Self {
+        Hsl::from(Hsv::from(color))
+    }
+}
@@ -1,9 +1,12 @@
 // nicked from: https://github.com/iced-rs/iced_aw/blob/main/src/core/color.rs
 
+use super::{Cmyk, Oklab};
+
 use iced_core::Color;
 
 /// Hue, Saturation, Value (Brightness)
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Hsv {
     /// The Hue component.
     pub h: f32,
@@ -15,6 +18,29 @@ pub struct Hsv {
     pub a: f32,
 }
 
+/// The size of text, which sets the contrast it needs under WCAG, see [Hsv::meets_wcag_aa].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextSize {
+    #[default]
+    Normal,
+    /// At least 18 points, or 14 points when bold.
+    Large,
+}
+
+/// The signed difference between two [Hsv] colors.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HsvDelta {
+    /// The change in hue, taking the shortest way around the color wheel.
+    pub dh: f32,
+    /// The change in saturation.
+    pub ds: f32,
+    /// The change in value.
+    pub dv: f32,
+    /// The change in alpha.
+    pub da: f32,
+}
+
 impl Default for Hsv {
     fn default() -> Self {
         Self {
@@ -41,39 +67,77 @@ pub fn hsva(hue: f32, saturation: f32, value: f32, alpha: f32) -> Hsv {
 
 impl From<Hsv> for Color {
     fn from(hsv: Hsv) -> Self {
-        // https://en.wikipedia.org/wiki/HSL_and_HSV#Color_conversion_formulae
-        let h = (hsv.h / 60.0).floor();
-        let f = (hsv.h / 60.0) - h;
-
-        let p = hsv.v * (1.0 - hsv.s);
-        let q = hsv.v * (1.0 - hsv.s * f);
-        let t = hsv.v * (1.0 - hsv.s * (1.0 - f));
-
-        let h = h as u8;
-        let (red, green, blue) = match h {
-            1 => (q, hsv.v, p),
-            2 => (p, hsv.v, t),
-            3 => (p, q, hsv.v),
-            4 => (t, p, hsv.v),
-            5 => (hsv.v, p, q),
-            _ => (hsv.v, t, p),
-        };
+        let [red, green, blue, alpha] = hsv.to_rgba_unclamped();
 
         Self::from_rgba(
             red.clamp(0.0, 1.0),
             green.clamp(0.0, 1.0),
             blue.clamp(0.0, 1.0),
-            hsv.a.clamp(0.0, 1.0),
+            alpha.clamp(0.0, 1.0),
         )
     }
 }
 
+/// The error returned by [Hsv::try_to_color] when a color can't be represented without clamping.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OutOfGamut {
+    /// The color with every channel clamped into range.
+    pub clamped: Color,
+}
+
+impl std::fmt::Display for OutOfGamut {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "color is out of gamut")
+    }
+}
+
+impl std::error::Error for OutOfGamut {}
+
+/// The error returned when parsing a color from a string, e.g. with [Hsv::from_hex].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseError {
+    /// The string isn't a hex code of 3, 4, 6 or 8 digits.
+    InvalidHex,
+    /// The string isn't a color function or named color of CSS.
+    InvalidCss,
+    /// The string isn't written as `hsv(h, s%, v%)` or `hsva(h, s%, v%, a)`.
+    InvalidHsv,
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidHex => write!(f, "invalid hex color"),
+            Self::InvalidCss => write!(f, "invalid CSS color"),
+            Self::InvalidHsv => write!(f, "invalid HSV color"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Colors with a value below this are treated as black, as their hue and saturation
+/// are mostly noise. This is half of the smallest step of an 8 bit channel.
+const ACHROMATIC_FLOOR: f32 = 1.0 / 510.0;
+
 impl From<Color> for Hsv {
     // https://en.wikipedia.org/wiki/HSL_and_HSV#Color_conversion_formulae
+    //
+    // Very dark colors are treated as achromatic (zero hue and saturation), since
+    // dividing by a tiny value amplifies any noise into wild hue and saturation swings.
     fn from(Color { r, g, b, a }: Color) -> Self {
         let max = r.max(g.max(b));
         let min = r.min(g.min(b));
 
+        if max < ACHROMATIC_FLOOR {
+            return Self {
+                h: 0.0,
+                s: 0.0,
+                v: max.max(0.0),
+                a,
+            };
+        }
+
         let h = if (max - min).abs() < f32::EPSILON {
             0.0
         } else if (max - r).abs() < f32::EPSILON {
@@ -86,7 +150,7 @@ impl From<Color> for Hsv {
 
         let h = if h < 0.0 { h + 360.0 } else { h } % 360.0;
 
-        let s = if max == 0.0 { 0.0 } else { (max - min) / max };
+        let s = (max - min) / max;
 
         let v = max;
 
@@ -94,7 +158,288 @@ impl From<Color> for Hsv {
     }
 }
 
+impl From<[f32; 3]> for Hsv {
+    fn from(value: [f32; 3]) -> Self {
+        Self::from_rgb(value)
+    }
+}
+
+impl From<(f32, f32, f32)> for Hsv {
+    fn from(value: (f32, f32, f32)) -> Self {
+        Self::from_rgb(value)
+    }
+}
+
+impl From<[f32; 4]> for Hsv {
+    fn from(value: [f32; 4]) -> Self {
+        Self::from_rgba(value)
+    }
+}
+
+impl From<(f32, f32, f32, f32)> for Hsv {
+    fn from(value: (f32, f32, f32, f32)) -> Self {
+        Self::from_rgba(value)
+    }
+}
+
+impl From<[u8; 3]> for Hsv {
+    fn from(value: [u8; 3]) -> Self {
+        Self::from_rgb8(value)
+    }
+}
+
+impl From<(u8, u8, u8)> for Hsv {
+    fn from(value: (u8, u8, u8)) -> Self {
+        Self::from_rgb8(value)
+    }
+}
+
+impl From<[u8; 4]> for Hsv {
+    fn from(value: [u8; 4]) -> Self {
+        Self::from_rgba8(value)
+    }
+}
+
+impl From<(u8, u8, u8, u8)> for Hsv {
+    fn from(value: (u8, u8, u8, u8)) -> Self {
+        Self::from_rgba8(value)
+    }
+}
+
+impl From<Hsv> for [f32; 3] {
+    fn from(hsv: Hsv) -> Self {
+        hsv.to_rgb()
+    }
+}
+
+impl From<Hsv> for [f32; 4] {
+    fn from(hsv: Hsv) -> Self {
+        hsv.to_rgba()
+    }
+}
+
+impl From<Hsv> for [u8; 3] {
+    fn from(hsv: Hsv) -> Self {
+        hsv.to_rgb8()
+    }
+}
+
+impl From<Hsv> for [u8; 4] {
+    fn from(hsv: Hsv) -> Self {
+        hsv.to_rgba8()
+    }
+}
+
+impl std::fmt::Display for Hsv {
+    /// Writes the color as `hsv(210.0, 40%, 80%)`, or as `hsva(210.0, 40%, 80%, 0.5)` when
+    /// translucent, which [Hsv::from_str](std::str::FromStr) parses back.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Rounded to keep out the noise of conversions, while holding more than 8 bits
+        let round = |component: f32, places: i32| {
+            let scale = 10f32.powi(places);
+            (component * scale).round() / scale
+        };
+
+        let h = round(self.h, 2);
+        let s = round(self.s * 100.0, 2);
+        let v = round(self.v * 100.0, 2);
+
+        match self.a < 1.0 {
+            true => write!(f, "hsva({h:?}, {s}%, {v}%, {})", round(self.a, 3)),
+            false => write!(f, "hsv({h:?}, {s}%, {v}%)"),
+        }
+    }
+}
+
+impl std::str::FromStr for Hsv {
+    type Err = ParseError;
+
+    /// Parses a color as written by [Hsv]'s `Display`, with the hue in degrees, saturation
+    /// and value in percent, with the `%` required as in CSS, and alpha on `[0, 1]`.
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let string = string.trim().to_ascii_lowercase();
+
+        let (name, args) = string
+            .strip_suffix(')')
+            .and_then(|string| string.split_once('('))
+            .ok_or(ParseError::InvalidHsv)?;
+
+        // Saturation and value are the second and third arguments
+        let args = args
+            .split(',')
+            .enumerate()
+            .map(|(i, arg)| {
+                let arg = arg.trim();
+                let number = match i {
+                    1 | 2 => arg.strip_suffix('%')?.trim_end(),
+                    _ => arg,
+                };
+
+                number
+                    .parse::<f32>()
+                    .ok()
+                    .filter(|number| number.is_finite())
+            })
+            .collect::<Option<Vec<f32>>>()
+            .ok_or(ParseError::InvalidHsv)?;
+
+        match (name.trim_end(), args.as_slice()) {
+            ("hsv", [h, s, v]) => Ok(hsv(*h, s / 100.0, v / 100.0)),
+            ("hsva", [h, s, v, a]) => Ok(hsva(*h, s / 100.0, v / 100.0, *a)),
+            _ => Err(ParseError::InvalidHsv),
+        }
+    }
+}
+
 impl Hsv {
+    /// Converts a [Color], keeping the hue and saturation of `previous` where the color doesn't define them.
+    ///
+    /// Black has no hue or saturation, and grays have no hue, so a plain conversion resets
+    /// them to zero. Use this when converting back and forth so picking near black or gray
+    /// doesn't make the marker jump.
+    pub fn from_color_with_previous(color: Color, previous: Hsv) -> Self {
+        let hsv = Self::from(color);
+
+        if hsv.v < ACHROMATIC_FLOOR {
+            Self {
+                h: previous.h,
+                s: previous.s,
+                ..hsv
+            }
+        } else if hsv.s < f32::EPSILON {
+            Self {
+                h: previous.h,
+                ..hsv
+            }
+        } else {
+            hsv
+        }
+    }
+
+    /// Converts to a [Color] without clamping.
+    ///
+    /// Unlike `Color::from`, this fails if any channel falls outside of `[0, 1]`,
+    /// e.g. if saturation or value are above 1.
+    pub fn try_to_color(self) -> Result<Color, OutOfGamut> {
+        let rgba = self.to_rgba_unclamped();
+
+        if rgba.iter().all(|channel| (0.0..=1.0).contains(channel)) {
+            let [r, g, b, a] = rgba;
+            Ok(Color::from_rgba(r, g, b, a))
+        } else {
+            Err(OutOfGamut {
+                clamped: Color::from(self),
+            })
+        }
+    }
+
+    fn to_rgba_unclamped(self) -> [f32; 4] {
+        // https://en.wikipedia.org/wiki/HSL_and_HSV#Color_conversion_formulae
+        let h = (self.h / 60.0).floor();
+        let f = (self.h / 60.0) - h;
+
+        let p = self.v * (1.0 - self.s);
+        let q = self.v * (1.0 - self.s * f);
+        let t = self.v * (1.0 - self.s * (1.0 - f));
+
+        let h = h as u8;
+        let (red, green, blue) = match h {
+            1 => (q, self.v, p),
+            2 => (p, self.v, t),
+            3 => (p, q, self.v),
+            4 => (t, p, self.v),
+            5 => (self.v, p, q),
+            _ => (self.v, t, p),
+        };
+
+        [red, green, blue, self.a]
+    }
+
+    /// Returns the signed difference from `previous` to `self`.
+    ///
+    /// The hue delta takes the shortest path around the circle, so moving from 350 to 10 gives 20.
+    pub fn delta(self, previous: Hsv) -> HsvDelta {
+        let dh = (self.h - previous.h).rem_euclid(360.0);
+
+        HsvDelta {
+            dh: if dh > 180.0 { dh - 360.0 } else { dh },
+            ds: self.s - previous.s,
+            dv: self.v - previous.v,
+            da: self.a - previous.a,
+        }
+    }
+
+    /// Interpolates towards another color in HSV, where `t` of `0` gives `self` and `1` gives `other`.
+    ///
+    /// The hue takes the shortest path around the circle, so going from 350 to 10 passes
+    /// through red rather than the whole spectrum. A gray takes the hue of the other color,
+    /// so fading to or from gray keeps a single hue.
+    pub fn lerp(self, other: Hsv, t: f32) -> Hsv {
+        let lerp = |from: f32, to: f32| from + (to - from) * t;
+
+        let from = match self.s < f32::EPSILON {
+            true => Hsv { h: other.h, ..self },
+            false => self,
+        };
+        let to = match other.s < f32::EPSILON {
+            true => Hsv { h: from.h, ..other },
+            false => other,
+        };
+
+        Hsv {
+            h: (from.h + to.delta(from).dh * t).rem_euclid(360.0),
+            s: lerp(from.s, to.s),
+            v: lerp(from.v, to.v),
+            a: lerp(from.a, to.a),
+        }
+    }
+
+    /// Interpolates towards another color in sRGB, as CSS gradients do by default.
+    pub fn lerp_rgb(self, other: Hsv, t: f32) -> Hsv {
+        let lerp = |from: f32, to: f32| from + (to - from) * t;
+
+        let [r, g, b, a] = self.to_rgba();
+        let [other_r, other_g, other_b, other_a] = other.to_rgba();
+
+        Self::from_color_with_previous(
+            Color::from_rgba(
+                lerp(r, other_r),
+                lerp(g, other_g),
+                lerp(b, other_b),
+                lerp(a, other_a),
+            ),
+            self.lerp(other, t),
+        )
+    }
+
+    /// Interpolates towards another color in [Oklab], which looks the most even, e.g. for gradients.
+    pub fn lerp_oklab(self, other: Hsv, t: f32) -> Hsv {
+        Self::from_color_with_previous(
+            Color::from(self.to_oklab().lerp(other.to_oklab(), t)),
+            self.lerp(other, t),
+        )
+    }
+
+    /// Converts to [Oklab], to compare or interpolate colors perceptually.
+    pub fn to_oklab(self) -> Oklab {
+        Oklab::from(self)
+    }
+
+    /// Converts from [Oklab], clamping colors outside of the sRGB gamut.
+    pub fn from_oklab(oklab: Oklab) -> Self {
+        Self::from(oklab)
+    }
+
+    /// Converts to [Cmyk].
+    pub fn to_cmyk(self) -> Cmyk {
+        Cmyk::from(self)
+    }
+
+    /// Converts from [Cmyk].
+    pub fn from_cmyk(cmyk: Cmyk) -> Self {
+        Self::from(cmyk)
+    }
+
     pub fn from_rgba8(rgba: impl Into<[u8; 4]>) -> Self {
         let [r, g, b, a] = rgba.into();
 
@@ -134,8 +479,237 @@ impl Hsv {
         let Color { r, g, b, .. } = Color::from(self);
         [to_u8(r), to_u8(g), to_u8(b)]
     }
+
+    /// Parses a hex code, e.g. `#ff8000`, with or without the `#`.
+    ///
+    /// The short `#rgb` and `#rgba` forms are accepted, as are `#rrggbb` and `#rrggbbaa`.
+    pub fn from_hex(hex: &str) -> Result<Self, ParseError> {
+        let hex = hex.trim();
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+
+        if !digits.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+            return Err(ParseError::InvalidHex);
+        }
+
+        let digit = |i: usize| u8::from_str_radix(&digits[i..i + 1], 16).unwrap_or_default();
+        let pair = |i: usize| u8::from_str_radix(&digits[i..i + 2], 16).unwrap_or_default();
+
+        // A short digit stands for itself repeated, so `f` is `ff`
+        let rgba = match digits.len() {
+            3 => [digit(0) * 17, digit(1) * 17, digit(2) * 17, 255],
+            4 => [digit(0) * 17, digit(1) * 17, digit(2) * 17, digit(3) * 17],
+            6 => [pair(0), pair(2), pair(4), 255],
+            8 => [pair(0), pair(2), pair(4), pair(6)],
+            _ => return Err(ParseError::InvalidHex),
+        };
+
+        Ok(Self::from_rgba8(rgba))
+    }
+
+    /// Parses a CSS color, e.g. `rebeccapurple` or `hsl(120 50% 50% / 0.5)`.
+    ///
+    /// See [css::parse](super::css::parse) for the accepted syntax.
+    pub fn from_css(css: &str) -> Result<Self, ParseError> {
+        super::css::parse(css)
+    }
+
+    /// Formats the color as `#rrggbb`, or `#rrggbbaa` with the alpha.
+    pub fn to_hex(self, with_alpha: bool) -> String {
+        match (self.to_rgba8(), with_alpha) {
+            ([r, g, b, _], false) => format!("#{r:02x}{g:02x}{b:02x}"),
+            ([r, g, b, a], true) => format!("#{r:02x}{g:02x}{b:02x}{a:02x}"),
+        }
+    }
+
+    /// Returns the [contrast ratio](https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio) of
+    /// the two colors, from `1` for the same luminance up to `21` for black on white.
+    ///
+    /// Alpha is ignored, so blend translucent colors over their background first.
+    pub fn contrast_ratio(&self, other: &Hsv) -> f32 {
+        Color::from(*self).relative_contrast(Color::from(*other))
+    }
+
+    /// Returns true if text of either color on the other meets level AA of WCAG, which
+    /// takes a contrast ratio of 4.5, or 3 for large text.
+    pub fn meets_wcag_aa(&self, other: &Hsv, text_size: TextSize) -> bool {
+        let minimum = match text_size {
+            TextSize::Normal => 4.5,
+            TextSize::Large => 3.0,
+        };
+
+        self.contrast_ratio(other) >= minimum
+    }
+
+    /// Returns true if text of either color on the other meets level AAA of WCAG, which
+    /// takes a contrast ratio of 7, or 4.5 for large text.
+    pub fn meets_wcag_aaa(&self, other: &Hsv, text_size: TextSize) -> bool {
+        let minimum = match text_size {
+            TextSize::Normal => 7.0,
+            TextSize::Large => 4.5,
+        };
+
+        self.contrast_ratio(other) >= minimum
+    }
 }
 
 fn to_u8(v: f32) -> u8 {
     (v * u8::MAX as f32).round() as u8
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_arrays_and_tuples() {
+        let (r, g, b, a) = (0.2, 0.4, 0.6, 0.8);
+
+        assert_eq!(Hsv::from([r, g, b]), Hsv::from_rgb([r, g, b]));
+        assert_eq!(Hsv::from((r, g, b)), Hsv::from_rgb([r, g, b]));
+        assert_eq!(Hsv::from([r, g, b, a]), Hsv::from_rgba([r, g, b, a]));
+        assert_eq!(Hsv::from((r, g, b, a)), Hsv::from_rgba([r, g, b, a]));
+
+        let (r, g, b, a) = (255, 128, 0, 64);
+
+        assert_eq!(Hsv::from([r, g, b]), Hsv::from_rgb8([r, g, b]));
+        assert_eq!(Hsv::from((r, g, b)), Hsv::from_rgb8([r, g, b]));
+        assert_eq!(Hsv::from([r, g, b, a]), Hsv::from_rgba8([r, g, b, a]));
+        assert_eq!(Hsv::from((r, g, b, a)), Hsv::from_rgba8([r, g, b, a]));
+    }
+
+    #[test]
+    fn rgb8_round_trips() {
+        for rgba in [
+            [0, 0, 0, 255],
+            [255, 255, 255, 0],
+            [255, 128, 0, 64],
+            [18, 52, 86, 120],
+        ] {
+            assert_eq!(Hsv::from(rgba).to_rgba8(), rgba);
+
+            let [r, g, b, _] = rgba;
+            assert_eq!(Hsv::from([r, g, b]).to_rgb8(), [r, g, b]);
+        }
+    }
+
+    #[test]
+    fn try_to_color_in_gamut() {
+        let color = hsva(210.0, 0.4, 0.8, 0.5);
+
+        assert_eq!(color.try_to_color(), Ok(Color::from(color)));
+        assert!(hsv(0.0, 1.0, 1.0).try_to_color().is_ok());
+        assert!(hsv(0.0, 0.0, 0.0).try_to_color().is_ok());
+    }
+
+    #[test]
+    fn try_to_color_out_of_gamut() {
+        for color in [
+            hsv(120.0, 1.01, 0.5),
+            hsv(120.0, 0.5, 1.01),
+            hsv(120.0, 1.01, 1.01),
+        ] {
+            assert_eq!(
+                color.try_to_color(),
+                Err(OutOfGamut {
+                    clamped: Color::from(color)
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn into_arrays() {
+        let color = hsva(210.0, 0.4, 0.8, 0.5);
+
+        assert_eq!(<[f32; 3]>::from(color), color.to_rgb());
+        assert_eq!(<[f32; 4]>::from(color), color.to_rgba());
+        assert_eq!(<[u8; 3]>::from(color), color.to_rgb8());
+        assert_eq!(<[u8; 4]>::from(color), color.to_rgba8());
+    }
+
+    #[test]
+    fn very_dark_colors_are_achromatic() {
+        let noise = [0.0, 1e-6, 1e-4, 1e-3, ACHROMATIC_FLOOR.next_down()];
+
+        for r in noise {
+            for g in noise {
+                for b in noise {
+                    let Hsv { h, s, v, .. } = Hsv::from(Color::from_rgb(r, g, b));
+
+                    assert_eq!((h, s), (0.0, 0.0));
+                    assert_eq!(v, r.max(g).max(b));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn dark_colors_stay_in_range() {
+        // The darkest 8 bit colors, which have a hue and saturation of their own
+        for r in 0..4 {
+            for g in 0..4 {
+                for b in 0..4 {
+                    let Hsv { h, s, v, .. } = Hsv::from_rgb8([r, g, b]);
+
+                    assert!((0.0..360.0).contains(&h));
+                    assert!((0.0..=1.0).contains(&s));
+                    assert!(v <= 3.0 / 255.0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn near_grays_keep_the_previous_hue() {
+        let previous = hsv(210.0, 0.4, 0.8);
+
+        for gray in [0.0, 1e-4, 0.5, 1.0] {
+            let color = Hsv::from_color_with_previous(Color::from_rgb(gray, gray, gray), previous);
+
+            assert_eq!((color.h, color.v), (previous.h, gray));
+        }
+
+        // Noise on a gray barely saturates it
+        let noisy = Hsv::from(Color::from_rgb(0.5, 0.5 + 1e-6, 0.5));
+        assert!(noisy.s < 1e-5);
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for color in [
+            hsv(210.0, 0.4, 0.8),
+            hsva(359.5, 1.0, 0.25, 0.5),
+            hsv(0.0, 0.0, 0.0),
+            Hsv::from_rgba8([18, 52, 86, 120]),
+        ] {
+            let parsed: Hsv = color.to_string().parse().unwrap();
+
+            assert_eq!(parsed.to_rgba8(), color.to_rgba8());
+            assert!((parsed.h - color.h).abs() < 0.01);
+        }
+
+        assert_eq!(hsv(210.0, 0.4, 0.8).to_string(), "hsv(210.0, 40%, 80%)");
+        assert_eq!(
+            "HSVA(210, 40 %, 80%, 0.5)".parse(),
+            Ok(hsva(210.0, 0.4, 0.8, 0.5))
+        );
+    }
+
+    #[test]
+    fn from_str_requires_percentages() {
+        for string in [
+            "hsv(210, 0.4, 0.8)",
+            "hsv(210, 40%, 80)",
+            "hsv(210%, 40%, 80%)",
+            "hsva(210, 40%, 80%, 50%)",
+            "hsv(10, 50%)",
+            "hsl(210, 40%, 80%)",
+        ] {
+            assert_eq!(
+                string.parse::<Hsv>(),
+                Err(ParseError::InvalidHsv),
+                "{string}"
+            );
+        }
+    }
+}
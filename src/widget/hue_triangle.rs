@@ -0,0 +1,543 @@
+//! A hue ring around a saturation/value triangle, in the style of GIMP.
+
+use super::style::{Catalog, Style, StyleFn};
+use super::{Hsv, Marker, Pointer, hsv, quantize};
+
+use iced_core::widget::{Tree, Widget, tree};
+use iced_core::{Color, Element, Length, Point, Rectangle, Shell, Size, layout, mouse, touch};
+use iced_graphics::geometry::{self, Frame, Path};
+
+use std::f32::consts::TAU;
+
+/// Width of the hue ring, as a fraction of the outer radius.
+const RING_WIDTH: f32 = 0.2;
+/// Space between the inside of the ring and the corners of the triangle.
+const RING_GAP: f32 = 4.0;
+/// Number of segments the ring is drawn with.
+const RING_SEGMENTS: usize = 360;
+/// Size of the blocks the triangle is drawn with.
+const QUANTIZATION: f32 = 2.0;
+
+/// Creates a new [HueTriangle] with the current [Hsv] (or [Color]) value, and a closure to produce a message when a color is picked.
+pub fn hue_triangle<'a, Message, Theme, FromHsv>(
+    color: impl Into<Hsv>,
+    on_select: impl Fn(FromHsv) -> Message + 'a,
+) -> HueTriangle<'a, Message, Theme>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    FromHsv: From<Hsv> + 'a,
+{
+    HueTriangle::new(color, move |color| on_select(color.into()))
+}
+
+/// A widget to pick the hue from an outer ring, and the saturation and value from
+/// an inner triangle that rotates along with the hue.
+///
+/// The triangle has the pure hue in one corner, white in the next and black in the last.
+pub struct HueTriangle<'a, Message, Theme>
+where
+    Message: 'a,
+    Theme: Catalog,
+{
+    color: Hsv,
+    width: Length,
+    height: Length,
+    on_select: Box<dyn Fn(Hsv) -> Message + 'a>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme> HueTriangle<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    pub fn new(color: impl Into<Hsv>, on_select: impl Fn(Hsv) -> Message + 'a) -> Self {
+        Self {
+            color: color.into(),
+            width: Length::Fill,
+            height: Length::Fill,
+            on_select: Box::new(on_select),
+            class: Theme::default(),
+        }
+    }
+
+    /// Set both the width and height of the [HueTriangle].
+    ///
+    /// The ring is always round, and centered within the bounds.
+    pub fn size(mut self, size: impl Into<Length>) -> Self {
+        let size = size.into();
+        self.width = size;
+        self.height = size;
+        self
+    }
+
+    /// Set the width of the [HueTriangle].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Set the height of the [HueTriangle].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Set the [Style] of the [HueTriangle].
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = Theme::Class::from(Box::new(style));
+        self
+    }
+
+    /// Set the style class of the [HueTriangle].
+    pub fn class(mut self, class: Theme::Class<'a>) -> Self {
+        self.class = class;
+        self
+    }
+
+    /// Picks a color from the part being dragged, keeping the components it doesn't control.
+    fn pick(&self, part: Part, current_color: Hsv, bounds: Rectangle, cursor: Point) -> Hsv {
+        let wheel = Wheel::new(bounds.size());
+        let point = cursor - (bounds.position() - Point::ORIGIN);
+
+        match part {
+            Part::Ring => Hsv {
+                h: wheel.hue_at(point),
+                ..current_color
+            },
+            Part::Triangle => wheel.pick_sv(current_color, point),
+        }
+    }
+
+    /// Returns the part of the widget under the point, if any.
+    fn part_at(&self, current_color: Hsv, bounds: Rectangle, point: Point) -> Option<Part> {
+        Wheel::new(bounds.size())
+            .part_at(current_color.h, point - (bounds.position() - Point::ORIGIN))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for HueTriangle<'a, Message, Theme>
+where
+    Theme: Catalog,
+    Renderer: geometry::Renderer + 'static,
+{
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer>::default())
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.width, self.height)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let State { current_color, .. }: &State<Renderer> = tree.state.downcast_ref();
+
+        match cursor.position() {
+            Some(cursor)
+                if self
+                    .part_at(*current_color, layout.bounds(), cursor)
+                    .is_some() =>
+            {
+                mouse::Interaction::Crosshair
+            }
+            _ => Default::default(),
+        }
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &iced_core::Event,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let State {
+            triangle_cache,
+            marker_cache,
+            pressed,
+            current_color,
+            ..
+        }: &mut State<Renderer> = tree.state.downcast_mut();
+
+        let bounds = layout.bounds();
+
+        if quantize(self.color) != quantize(*current_color) {
+            // The triangle only depends on the hue, the marker on the whole color
+            if quantize(self.color)[0] != quantize(*current_color)[0] {
+                triangle_cache.clear();
+            }
+
+            *current_color = self.color;
+            marker_cache.clear();
+            shell.request_redraw();
+        }
+
+        let (pointer, position) = match event {
+            iced_core::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position() else {
+                    return;
+                };
+
+                if pressed.is_none() {
+                    *pressed = self
+                        .part_at(*current_color, bounds, position)
+                        .map(|part| (part, Pointer::Mouse));
+                }
+
+                (Pointer::Mouse, position)
+            }
+            // The cursor may be unavailable over another layer, but a drag should follow it
+            iced_core::Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                (Pointer::Mouse, *position)
+            }
+            iced_core::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                if let Some((_, Pointer::Mouse)) = pressed {
+                    *pressed = None;
+                }
+
+                return;
+            }
+            iced_core::Event::Touch(touch::Event::FingerPressed { id, position }) => {
+                if pressed.is_none() {
+                    *pressed = self
+                        .part_at(*current_color, bounds, *position)
+                        .map(|part| (part, Pointer::Finger(id.0)));
+                }
+
+                (Pointer::Finger(id.0), *position)
+            }
+            iced_core::Event::Touch(touch::Event::FingerMoved { id, position }) => {
+                (Pointer::Finger(id.0), *position)
+            }
+            iced_core::Event::Touch(
+                touch::Event::FingerLifted { id, .. } | touch::Event::FingerLost { id, .. },
+            ) => {
+                if let Some((_, Pointer::Finger(finger_id))) = pressed
+                    && id.0 == *finger_id
+                {
+                    *pressed = None;
+                }
+
+                return;
+            }
+            _ => return,
+        };
+
+        // Keep picking from the part that was pressed, even once the cursor leaves it
+        if let Some((part, pressed_by)) = *pressed
+            && pressed_by == pointer
+        {
+            let new_color = self.pick(part, *current_color, bounds, position);
+            shell.publish((self.on_select)(new_color));
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &iced_core::renderer::Style,
+        layout: iced_core::Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &iced_core::Rectangle,
+    ) {
+        let State {
+            ring_cache,
+            triangle_cache,
+            marker_cache,
+            current_color,
+            ..
+        }: &State<Renderer> = tree.state.downcast_ref();
+
+        let style = theme.style(&self.class);
+
+        let bounds = layout.bounds();
+        let size = bounds.size();
+        let wheel = Wheel::new(size);
+
+        renderer.with_layer(bounds, |renderer| {
+            renderer.with_translation(bounds.position() - Point::ORIGIN, |renderer| {
+                // The ring never changes, so it's only redrawn when the size does
+                let ring = ring_cache.draw(renderer, size, |frame| wheel.draw_ring(frame));
+
+                let triangle = triangle_cache.draw(renderer, size, |frame| {
+                    wheel.draw_triangle(frame, current_color.h);
+                });
+
+                let marker = marker_cache.draw(renderer, size, |frame| {
+                    let hue = Color::from(hsv(current_color.h, 1.0, 1.0));
+                    let color = Color::from(*current_color);
+
+                    let markers = [
+                        Marker {
+                            position: wheel.hue_position(current_color.h),
+                            color: hue,
+                            outline: style.marker_contrast.against(hue),
+                            track: None,
+                        },
+                        Marker {
+                            position: wheel.sv_position(*current_color),
+                            color,
+                            outline: style.marker_contrast.against(color),
+                            track: None,
+                        },
+                    ];
+
+                    for marker in markers {
+                        marker.draw(frame, &style);
+                    }
+                });
+
+                renderer.draw_geometry(ring);
+                renderer.draw_geometry(triangle);
+                renderer.draw_geometry(marker);
+            });
+        });
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<HueTriangle<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog + 'a,
+    Renderer: geometry::Renderer + 'static,
+{
+    fn from(value: HueTriangle<'a, Message, Theme>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// The part of the [HueTriangle] a color is picked from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Part {
+    Ring,
+    Triangle,
+}
+
+/// The layout of the ring and triangle within the bounds, relative to the top left.
+#[derive(Debug, Clone, Copy)]
+struct Wheel {
+    center: Point,
+    outer: f32,
+    inner: f32,
+}
+
+impl Wheel {
+    fn new(bounds: Size) -> Self {
+        let outer = (bounds.width.min(bounds.height) / 2.0).max(0.0);
+
+        Self {
+            center: Point::new(bounds.width / 2.0, bounds.height / 2.0),
+            outer,
+            inner: outer * (1.0 - RING_WIDTH),
+        }
+    }
+
+    /// The point at the given angle and distance from the center.
+    ///
+    /// Angles go counter-clockwise from the right, the same way hue goes around the ring.
+    fn point(&self, angle: f32, radius: f32) -> Point {
+        Point::new(
+            self.center.x + radius * angle.cos(),
+            self.center.y - radius * angle.sin(),
+        )
+    }
+
+    /// The hue at the angle of the point around the center.
+    fn hue_at(&self, point: Point) -> f32 {
+        let angle = (self.center.y - point.y).atan2(point.x - self.center.x);
+
+        angle
+            .to_degrees()
+            .rem_euclid(360.0)
+            .clamp(0.0, 360f32.next_down())
+    }
+
+    /// Where the marker for the hue sits, halfway across the ring.
+    fn hue_position(&self, hue: f32) -> Point {
+        self.point(hue.to_radians(), (self.outer + self.inner) / 2.0)
+    }
+
+    /// The corners of the triangle for the hue: the pure hue, white, then black.
+    fn triangle(&self, hue: f32) -> [Point; 3] {
+        let radius = (self.inner - RING_GAP).max(0.0);
+        let angle = hue.to_radians();
+
+        [0.0, 1.0, 2.0].map(|corner| self.point(angle + corner * TAU / 3.0, radius))
+    }
+
+    /// The weights of each corner of the triangle for the point, which are
+    /// all positive when the point lies inside of it.
+    fn barycentric(&self, hue: f32, point: Point) -> [f32; 3] {
+        let [a, b, c] = self.triangle(hue);
+
+        let det = (b.y - c.y) * (a.x - c.x) + (c.x - b.x) * (a.y - c.y);
+
+        if det.abs() < f32::EPSILON {
+            return [0.0, 0.0, 1.0];
+        }
+
+        let wa = ((b.y - c.y) * (point.x - c.x) + (c.x - b.x) * (point.y - c.y)) / det;
+        let wb = ((c.y - a.y) * (point.x - c.x) + (a.x - c.x) * (point.y - c.y)) / det;
+
+        [wa, wb, 1.0 - wa - wb]
+    }
+
+    /// Picks the saturation and value at the point, clamped into the triangle.
+    fn pick_sv(&self, color: Hsv, point: Point) -> Hsv {
+        let weights = self
+            .barycentric(color.h, point)
+            .map(|weight| weight.max(0.0));
+        let total: f32 = weights.iter().sum();
+        let [hue, white, _] = weights.map(|weight| weight / total);
+
+        let v = (hue + white).clamp(0.0, 1.0);
+
+        // Black has no saturation, so keep the previous one rather than jumping
+        let s = match v > f32::EPSILON {
+            true => (hue / v).clamp(0.0, 1.0),
+            false => color.s,
+        };
+
+        Hsv { s, v, ..color }
+    }
+
+    /// Where the marker for the saturation and value sits inside of the triangle.
+    fn sv_position(&self, color: Hsv) -> Point {
+        let [hue, white, black] = self.triangle(color.h);
+        let (s, v) = (color.s.clamp(0.0, 1.0), color.v.clamp(0.0, 1.0));
+
+        let weights = [v * s, v * (1.0 - s), 1.0 - v];
+
+        Point::new(
+            weights[0] * hue.x + weights[1] * white.x + weights[2] * black.x,
+            weights[0] * hue.y + weights[1] * white.y + weights[2] * black.y,
+        )
+    }
+
+    fn part_at(&self, hue: f32, point: Point) -> Option<Part> {
+        if (self.inner..=self.outer).contains(&point.distance(self.center)) {
+            Some(Part::Ring)
+        } else if self
+            .barycentric(hue, point)
+            .iter()
+            .all(|weight| *weight >= 0.0)
+        {
+            Some(Part::Triangle)
+        } else {
+            None
+        }
+    }
+
+    fn draw_ring<Renderer: geometry::Renderer>(&self, frame: &mut Frame<Renderer>) {
+        let step = TAU / RING_SEGMENTS as f32;
+
+        for segment in 0..RING_SEGMENTS {
+            let start = segment as f32 * step;
+            // Overlap the next segment slightly, so no seams show between them
+            let end = start + step * 1.5;
+
+            let path = Path::new(|builder| {
+                builder.move_to(self.point(start, self.outer));
+                builder.line_to(self.point(end, self.outer));
+                builder.line_to(self.point(end, self.inner));
+                builder.line_to(self.point(start, self.inner));
+                builder.close();
+            });
+
+            let hue = (start + step / 2.0).to_degrees();
+            frame.fill(&path, Color::from(hsv(hue, 1.0, 1.0)));
+        }
+    }
+
+    fn draw_triangle<Renderer: geometry::Renderer>(&self, frame: &mut Frame<Renderer>, hue: f32) {
+        let corners = self.triangle(hue);
+
+        let (min_x, max_x) = corners.iter().fold((f32::MAX, f32::MIN), |(min, max), p| {
+            (min.min(p.x), max.max(p.x))
+        });
+        let (min_y, max_y) = corners.iter().fold((f32::MAX, f32::MIN), |(min, max), p| {
+            (min.min(p.y), max.max(p.y))
+        });
+
+        let cols = ((max_x - min_x) / QUANTIZATION).ceil().max(0.0) as usize;
+        let rows = ((max_y - min_y) / QUANTIZATION).ceil().max(0.0) as usize;
+
+        let base = hsv(hue, 1.0, 1.0);
+
+        for col in 0..cols {
+            for row in 0..rows {
+                let position = Point::new(
+                    min_x + col as f32 * QUANTIZATION,
+                    min_y + row as f32 * QUANTIZATION,
+                );
+                let center = Point::new(
+                    position.x + QUANTIZATION / 2.0,
+                    position.y + QUANTIZATION / 2.0,
+                );
+
+                if self
+                    .barycentric(hue, center)
+                    .iter()
+                    .any(|weight| *weight < 0.0)
+                {
+                    continue;
+                }
+
+                frame.fill_rectangle(
+                    position,
+                    Size::new(QUANTIZATION, QUANTIZATION),
+                    Color::from(self.pick_sv(base, center)),
+                );
+            }
+        }
+    }
+}
+
+struct State<Renderer: geometry::Renderer> {
+    ring_cache: geometry::Cache<Renderer>,
+    triangle_cache: geometry::Cache<Renderer>,
+    marker_cache: geometry::Cache<Renderer>,
+    pressed: Option<(Part, Pointer)>,
+    current_color: Hsv,
+}
+
+impl<Renderer: geometry::Renderer> Default for State<Renderer> {
+    fn default() -> Self {
+        Self {
+            ring_cache: Default::default(),
+            triangle_cache: Default::default(),
+            marker_cache: Default::default(),
+            pressed: Default::default(),
+            current_color: Default::default(),
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use super::Hsv;
+
+use iced_core::Color;
+
+use std::fmt;
+
+/// Hue, Whiteness, Blackness
+///
+/// Displays using the CSS Color 4 `hwb()` syntax, e.g. `hwb(120 20% 30%)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hwb {
+    /// The Hue component.
+    pub h: f32,
+    /// The Whiteness component.
+    pub w: f32,
+    /// The Blackness component.
+    pub b: f32,
+    /// The alpha component.
+    pub a: f32,
+}
+
+impl Default for Hwb {
+    fn default() -> Self {
+        Self {
+            h: Default::default(),
+            w: Default::default(),
+            b: 1.0,
+            a: 1.0,
+        }
+    }
+}
+
+impl From<Hsv> for Hwb {
+    // https://www.w3.org/TR/css-color-4/#hwb-to-rgb
+    fn from(Hsv { h, s, v, a }: Hsv) -> Self {
+        Self {
+            h,
+            w: (1.0 - s) * v,
+            b: 1.0 - v,
+            a,
+        }
+    }
+}
+
+impl From<Hwb> for Hsv {
+    fn from(Hwb { h, w, b, a }: Hwb) -> Self {
+        // Whiteness and blackness that add up past 1 are scaled down into a gray
+        if w + b >= 1.0 {
+            return Self {
+                h,
+                s: 0.0,
+                v: w / (w + b),
+                a,
+            };
+        }
+
+        let v = 1.0 - b;
+        let s = if v <= 0.0 { 0.0 } else { 1.0 - w / v };
+
+        Self { h, s, v, a }
+    }
+}
+
+impl From<Hwb> for Color {
+    fn from(hwb: Hwb) -> Self {
+        Color::from(Hsv::from(hwb))
+    }
+}
+
+impl From<Color> for Hwb {
+    fn from(color: Color) -> Self {
+        Hwb::from(Hsv::from(color))
+    }
+}
+
+impl fmt::Display for Hwb {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Hwb { h, w, b, a } = *self;
+
+        write!(
+            f,
+            "hwb({} {}% {}%",
+            round(h),
+            round(w * 100.0),
+            round(b * 100.0)
+        )?;
+
+        if a < 1.0 {
+            write!(f, " / {}", round(a))?;
+        }
+
+        write!(f, ")")
+    }
+}
+
+/// Rounds to at most two decimals, dropping any trailing zeros when displayed.
+fn round(value: f32) -> f32 {
+    (value * 100.0).round() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{hsv, hsva};
+
+    fn hwb(h: f32, w: f32, b: f32) -> Hwb {
+        Hwb { h, w, b, a: 1.0 }
+    }
+
+    #[test]
+    fn whiteness_and_blackness_past_1_are_normalized() {
+        assert_eq!(Hsv::from(hwb(120.0, 0.6, 0.6)), hsv(120.0, 0.0, 0.5));
+        assert_eq!(Hsv::from(hwb(120.0, 0.75, 0.25)), hsv(120.0, 0.0, 0.75));
+        assert_eq!(Hsv::from(hwb(0.0, 0.3, 0.7)), hsv(0.0, 0.0, 0.3));
+        assert_eq!(Hsv::from(hwb(0.0, 0.0, 1.0)), hsv(0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn reference_values() {
+        assert_eq!(Hsv::from(hwb(240.0, 0.0, 0.0)), hsv(240.0, 1.0, 1.0));
+        assert_eq!(Hwb::from(hsv(60.0, 0.5, 0.5)), hwb(60.0, 0.25, 0.5));
+    }
+
+    #[test]
+    fn round_trip() {
+        for color in [
+            hsv(0.0, 1.0, 1.0),
+            hsv(90.0, 0.5, 0.5),
+            hsv(200.0, 0.25, 0.8),
+            hsva(300.0, 0.0, 0.4, 0.5),
+        ] {
+            let back = Hsv::from(Hwb::from(color));
+
+            assert!(
+                (back.h - color.h).abs() < 1e-4
+                    && (back.s - color.s).abs() < 1e-4
+                    && (back.v - color.v).abs() < 1e-4
+                    && back.a == color.a,
+                "{back:?} from {color:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(hwb(120.0, 0.2, 0.3).to_string(), "hwb(120 20% 30%)");
+        assert_eq!(
+            Hwb {
+                a: 0.5,
+                ..hwb(120.0, 0.2, 0.3)
+            }
+            .to_string(),
+            "hwb(120 20% 30% / 0.5)"
+        );
+    }
+}
@@ -0,0 +1,286 @@
+use super::Hsv;
+use super::hsv::OutOfGamut;
+use super::srgb::{from_linear, in_gamut, to_linear};
+
+use iced_core::Color;
+
+/// The chroma treated as fully saturated, following CSS where `100%` chroma is `150`.
+///
+/// The most saturated sRGB colors have a chroma of a little over `130`.
+pub const MAX_CHROMA: f32 = 150.0;
+
+/// The D65 white point, as used by sRGB.
+const WHITE: [f32; 3] = [0.950_47, 1.0, 1.088_83];
+
+/// CIE L\*a\*b\*, relative to the D65 white point.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Lab {
+    /// The Lightness component, on `[0, 100]`.
+    pub l: f32,
+    /// The green (negative) to red (positive) component.
+    pub a: f32,
+    /// The blue (negative) to yellow (positive) component.
+    pub b: f32,
+    /// The alpha component.
+    pub alpha: f32,
+}
+
+/// Lightness, Chroma, Hue: the polar form of [Lab].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Lch {
+    /// The Lightness component, on `[0, 100]`.
+    pub l: f32,
+    /// The Chroma component, from `0` up to roughly [MAX_CHROMA].
+    pub c: f32,
+    /// The Hue component, in degrees.
+    pub h: f32,
+    /// The alpha component.
+    pub a: f32,
+}
+
+impl Default for Lab {
+    fn default() -> Self {
+        Self {
+            l: Default::default(),
+            a: Default::default(),
+            b: Default::default(),
+            alpha: 1.0,
+        }
+    }
+}
+
+impl Default for Lch {
+    fn default() -> Self {
+        Self {
+            l: Default::default(),
+            c: Default::default(),
+            h: Default::default(),
+            a: 1.0,
+        }
+    }
+}
+
+impl Lab {
+    /// Converts to a [Color] without clamping.
+    ///
+    /// Unlike `Color::from`, this fails if the color falls outside of the sRGB gamut.
+    pub fn try_to_color(self) -> Result<Color, OutOfGamut> {
+        let rgb = self.to_linear_rgb();
+
+        match in_gamut(rgb) {
+            true => {
+                let [r, g, b] = rgb.map(|c| from_linear(c.clamp(0.0, 1.0)));
+                Ok(Color::from_rgba(r, g, b, self.alpha))
+            }
+            false => Err(OutOfGamut {
+                clamped: Color::from(self),
+            }),
+        }
+    }
+
+    // https://en.wikipedia.org/wiki/CIELAB_color_space#From_CIELAB_to_CIEXYZ
+    fn to_linear_rgb(self) -> [f32; 3] {
+        let Lab { l, a, b, .. } = self;
+
+        let y = (l + 16.0) / 116.0;
+        let fxyz = [y + a / 500.0, y, y - b / 200.0];
+
+        let [x, y, z] = [0, 1, 2].map(|i| f_inverse(fxyz[i]) * WHITE[i]);
+
+        [
+            3.240_454_2 * x - 1.537_138_5 * y - 0.498_531_4 * z,
+            -0.969_266 * x + 1.876_010_8 * y + 0.041_556 * z,
+            0.055_643_4 * x - 0.204_025_9 * y + 1.057_225_2 * z,
+        ]
+    }
+}
+
+impl Lch {
+    /// Converts to a [Color] without clamping.
+    ///
+    /// Unlike `Color::from`, this fails if the color falls outside of the sRGB gamut, as
+    /// colors of high chroma do.
+    pub fn try_to_color(self) -> Result<Color, OutOfGamut> {
+        Lab::from(self).try_to_color()
+    }
+}
+
+impl From<Color> for Lab {
+    // https://en.wikipedia.org/wiki/CIELAB_color_space#From_CIEXYZ_to_CIELAB
+    fn from(color: Color) -> Self {
+        let [r, g, b] = [color.r, color.g, color.b].map(to_linear);
+
+        let xyz = [
+            0.412_456_4 * r + 0.357_576_1 * g + 0.180_437_5 * b,
+            0.212_672_9 * r + 0.715_152_2 * g + 0.072_175 * b,
+            0.019_333_9 * r + 0.119_192 * g + 0.950_304_1 * b,
+        ];
+
+        let [x, y, z] = [0, 1, 2].map(|i| f(xyz[i] / WHITE[i]));
+
+        Self {
+            l: 116.0 * y - 16.0,
+            a: 500.0 * (x - y),
+            b: 200.0 * (y - z),
+            alpha: color.a,
+        }
+    }
+}
+
+impl From<Lab> for Color {
+    /// Colors outside of the sRGB gamut have each channel clamped.
+    fn from(lab: Lab) -> Self {
+        let [r, g, b] = lab.to_linear_rgb().map(|c| from_linear(c.clamp(0.0, 1.0)));
+
+        Color::from_rgba(r, g, b, lab.alpha)
+    }
+}
+
+impl From<Lch> for Lab {
+    fn from(Lch { l, c, h, a }: Lch) -> Self {
+        let (sin, cos) = h.to_radians().sin_cos();
+
+        Self {
+            l,
+            a: c * cos,
+            b: c * sin,
+            alpha: a,
+        }
+    }
+}
+
+impl From<Lab> for Lch {
+    fn from(Lab { l, a, b, alpha }: Lab) -> Self {
+        Self {
+            l,
+            c: a.hypot(b),
+            h: b.atan2(a).to_degrees().rem_euclid(360.0),
+            a: alpha,
+        }
+    }
+}
+
+impl From<Color> for Lch {
+    fn from(color: Color) -> Self {
+        Lch::from(Lab::from(color))
+    }
+}
+
+impl From<Lch> for Color {
+    /// Colors outside of the sRGB gamut have each channel clamped.
+    fn from(lch: Lch) -> Self {
+        Color::from(Lab::from(lch))
+    }
+}
+
+impl From<Hsv> for Lab {
+    fn from(hsv: Hsv) -> Self {
+        Lab::from(Color::from(hsv))
+    }
+}
+
+impl From<Lab> for Hsv {
+    fn from(lab: Lab) -> Self {
+        Hsv::from(Color::from(lab))
+    }
+}
+
+impl From<Hsv> for Lch {
+    fn from(hsv: Hsv) -> Self {
+        Lch::from(Color::from(hsv))
+    }
+}
+
+impl From<Lch> for Hsv {
+    fn from(lch: Lch) -> Self {
+        Hsv::from(Color::from(lch))
+    }
+}
+
+const DELTA: f32 = 6.0 / 29.0;
+
+fn f(t: f32) -> f32 {
+    match t > DELTA.powi(3) {
+        true => t.cbrt(),
+        false => t / (3.0 * DELTA * DELTA) + 4.0 / 29.0,
+    }
+}
+
+fn f_inverse(t: f32) -> f32 {
+    match t > DELTA {
+        true => t.powi(3),
+        false => 3.0 * DELTA * DELTA * (t - 4.0 / 29.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(lab: Lab, [l, a, b]: [f32; 3]) {
+        assert!(
+            (lab.l - l).abs() < 0.05 && (lab.a - a).abs() < 0.05 && (lab.b - b).abs() < 0.05,
+            "{lab:?} isn't close to {:?}",
+            [l, a, b]
+        );
+    }
+
+    #[test]
+    fn reference_values() {
+        assert_close(Lab::from(Color::WHITE), [100.0, 0.0, 0.0]);
+        assert_close(Lab::from(Color::BLACK), [0.0, 0.0, 0.0]);
+        assert_close(
+            Lab::from(Color::from_rgb8(128, 128, 128)),
+            [53.59, 0.0, 0.0],
+        );
+        // The sRGB primaries, under D65
+        assert_close(
+            Lab::from(Color::from_rgb(1.0, 0.0, 0.0)),
+            [53.24, 80.09, 67.20],
+        );
+        assert_close(
+            Lab::from(Color::from_rgb(0.0, 1.0, 0.0)),
+            [87.73, -86.18, 83.18],
+        );
+        assert_close(
+            Lab::from(Color::from_rgb(0.0, 0.0, 1.0)),
+            [32.30, 79.19, -107.86],
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        for rgb in [
+            [1.0, 0.5, 0.0],
+            [0.2, 0.4, 0.6],
+            [0.01, 0.02, 0.01],
+            [1.0, 1.0, 1.0],
+        ] {
+            let color = Color::from_rgb(rgb[0], rgb[1], rgb[2]);
+
+            for back in [
+                Lab::from(color).try_to_color().unwrap(),
+                Lch::from(color).try_to_color().unwrap(),
+            ] {
+                for (channel, expected) in [back.r, back.g, back.b].into_iter().zip(rgb) {
+                    assert!((channel - expected).abs() < 1e-3, "{back:?} from {rgb:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn out_of_gamut() {
+        let lch = Lch {
+            l: 50.0,
+            c: MAX_CHROMA,
+            h: 200.0,
+            a: 1.0,
+        };
+
+        let OutOfGamut { clamped } = lch.try_to_color().unwrap_err();
+        assert_eq!(clamped, Color::from(lch));
+    }
+}
@@ -0,0 +1,153 @@
+//! Restrict the spectrum to an arbitrary [Path].
+
+use iced_core::Point;
+use iced_graphics::geometry::Path;
+use iced_graphics::geometry::path::lyon_path::{self, PathEvent, iterator::PathIterator};
+
+/// Maximum distance between a curve and its flattened approximation.
+const TOLERANCE: f32 = 0.1;
+
+/// A [Path] flattened into line segments, so the same shape can be used for
+/// drawing and hit testing.
+#[derive(Debug, Clone)]
+pub(crate) struct Mask {
+    edges: Vec<(Point, Point)>,
+}
+
+impl Mask {
+    pub(crate) fn new(path: &Path) -> Self {
+        let point = |p: lyon_path::math::Point| Point::new(p.x, p.y);
+
+        let edges = path
+            .raw()
+            .iter()
+            .flattened(TOLERANCE)
+            .filter_map(|event| match event {
+                PathEvent::Line { from, to } => Some((point(from), point(to))),
+                // Open sub-paths are treated as closed, the same way a fill would
+                PathEvent::End { last, first, .. } => Some((point(last), point(first))),
+                _ => None,
+            })
+            .collect();
+
+        Self { edges }
+    }
+
+    /// Returns true if the point lies inside the mask, using the non-zero fill rule.
+    pub(crate) fn contains(&self, point: Point) -> bool {
+        let mut winding = 0;
+
+        for &(a, b) in &self.edges {
+            // Which side of the edge the point is on
+            let side = (b.x - a.x) * (point.y - a.y) - (point.x - a.x) * (b.y - a.y);
+
+            if a.y <= point.y {
+                if b.y > point.y && side > 0.0 {
+                    winding += 1;
+                }
+            } else if b.y <= point.y && side < 0.0 {
+                winding -= 1;
+            }
+        }
+
+        winding != 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn polygon(points: &[(f32, f32)]) -> Mask {
+        Mask::new(&Path::new(|builder| {
+            let (x, y) = points[0];
+            builder.move_to(Point::new(x, y));
+
+            for &(x, y) in &points[1..] {
+                builder.line_to(Point::new(x, y));
+            }
+
+            builder.close();
+        }))
+    }
+
+    #[test]
+    fn concave() {
+        // A U, open at the top
+        let mask = polygon(&[
+            (0.0, 0.0),
+            (3.0, 0.0),
+            (3.0, 10.0),
+            (7.0, 10.0),
+            (7.0, 0.0),
+            (10.0, 0.0),
+            (10.0, 13.0),
+            (0.0, 13.0),
+        ]);
+
+        assert!(mask.contains(Point::new(1.5, 5.0)));
+        assert!(mask.contains(Point::new(8.5, 5.0)));
+        assert!(mask.contains(Point::new(5.0, 12.0)));
+        // Within the bounds, but in the gap of the U
+        assert!(!mask.contains(Point::new(5.0, 5.0)));
+        assert!(!mask.contains(Point::new(15.0, 5.0)));
+    }
+
+    #[test]
+    fn self_intersecting() {
+        // A pentagram, whose middle is wound around twice
+        let star = [90.0f32, 234.0, 18.0, 162.0, 306.0].map(|degrees| {
+            let (sin, cos) = degrees.to_radians().sin_cos();
+
+            (50.0 + 40.0 * cos, 50.0 - 40.0 * sin)
+        });
+        let mask = polygon(&star);
+
+        // Filled with the non-zero rule, unlike the even-odd rule
+        assert!(mask.contains(Point::new(50.0, 50.0)));
+        // A tip of the star
+        assert!(mask.contains(Point::new(50.0, 15.0)));
+        // Between two tips
+        assert!(!mask.contains(Point::new(50.0, 85.0)));
+    }
+
+    #[test]
+    fn opposite_windings_cancel() {
+        let mask = Mask::new(&Path::new(|builder| {
+            // Clockwise on screen
+            builder.move_to(Point::new(0.0, 0.0));
+            builder.line_to(Point::new(10.0, 0.0));
+            builder.line_to(Point::new(10.0, 10.0));
+            builder.line_to(Point::new(0.0, 10.0));
+            builder.close();
+
+            // Counter-clockwise, cutting a hole
+            builder.move_to(Point::new(3.0, 3.0));
+            builder.line_to(Point::new(3.0, 7.0));
+            builder.line_to(Point::new(7.0, 7.0));
+            builder.line_to(Point::new(7.0, 3.0));
+            builder.close();
+        }));
+
+        assert!(mask.contains(Point::new(1.0, 5.0)));
+        assert!(!mask.contains(Point::new(5.0, 5.0)));
+    }
+
+    #[test]
+    fn shared_edges_belong_to_one_side() {
+        let left = polygon(&[(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)]);
+        let right = polygon(&[(10.0, 0.0), (20.0, 0.0), (20.0, 10.0), (10.0, 10.0)]);
+        let below = polygon(&[(0.0, 10.0), (10.0, 10.0), (10.0, 20.0), (0.0, 20.0)]);
+
+        for point in [Point::new(10.0, 5.0), Point::new(10.0, 0.0)] {
+            assert!(left.contains(point) != right.contains(point), "{point:?}");
+        }
+        for point in [Point::new(5.0, 10.0), Point::new(0.0, 10.0)] {
+            assert!(left.contains(point) != below.contains(point), "{point:?}");
+        }
+
+        // Well outside of every edge
+        assert!(!left.contains(Point::new(-0.1, 5.0)));
+        assert!(!left.contains(Point::new(5.0, 10.1)));
+    }
+}
@@ -0,0 +1,346 @@
+//! The named colors of CSS, e.g. to fill a [SwatchGrid](super::SwatchGrid).
+
+use iced_core::Color;
+
+/// Looks up a named color, ignoring case, e.g. `rebeccapurple`.
+pub fn by_name(name: &str) -> Option<Color> {
+    let name = name.to_ascii_lowercase();
+
+    ALL.binary_search_by_key(&name.as_str(), |(name, _)| name)
+        .ok()
+        .map(|i| ALL[i].1)
+}
+
+/// Every named color with its name, sorted by name.
+///
+/// Gray and its shades are listed under both spellings, e.g. `gray` and `grey`.
+pub const ALL: [(&str, Color); 148] = [
+    ("aliceblue", ALICEBLUE),
+    ("antiquewhite", ANTIQUEWHITE),
+    ("aqua", AQUA),
+    ("aquamarine", AQUAMARINE),
+    ("azure", AZURE),
+    ("beige", BEIGE),
+    ("bisque", BISQUE),
+    ("black", BLACK),
+    ("blanchedalmond", BLANCHEDALMOND),
+    ("blue", BLUE),
+    ("blueviolet", BLUEVIOLET),
+    ("brown", BROWN),
+    ("burlywood", BURLYWOOD),
+    ("cadetblue", CADETBLUE),
+    ("chartreuse", CHARTREUSE),
+    ("chocolate", CHOCOLATE),
+    ("coral", CORAL),
+    ("cornflowerblue", CORNFLOWERBLUE),
+    ("cornsilk", CORNSILK),
+    ("crimson", CRIMSON),
+    ("cyan", CYAN),
+    ("darkblue", DARKBLUE),
+    ("darkcyan", DARKCYAN),
+    ("darkgoldenrod", DARKGOLDENROD),
+    ("darkgray", DARKGRAY),
+    ("darkgreen", DARKGREEN),
+    ("darkgrey", DARKGREY),
+    ("darkkhaki", DARKKHAKI),
+    ("darkmagenta", DARKMAGENTA),
+    ("darkolivegreen", DARKOLIVEGREEN),
+    ("darkorange", DARKORANGE),
+    ("darkorchid", DARKORCHID),
+    ("darkred", DARKRED),
+    ("darksalmon", DARKSALMON),
+    ("darkseagreen", DARKSEAGREEN),
+    ("darkslateblue", DARKSLATEBLUE),
+    ("darkslategray", DARKSLATEGRAY),
+    ("darkslategrey", DARKSLATEGREY),
+    ("darkturquoise", DARKTURQUOISE),
+    ("darkviolet", DARKVIOLET),
+    ("deeppink", DEEPPINK),
+    ("deepskyblue", DEEPSKYBLUE),
+    ("dimgray", DIMGRAY),
+    ("dimgrey", DIMGREY),
+    ("dodgerblue", DODGERBLUE),
+    ("firebrick", FIREBRICK),
+    ("floralwhite", FLORALWHITE),
+    ("forestgreen", FORESTGREEN),
+    ("fuchsia", FUCHSIA),
+    ("gainsboro", GAINSBORO),
+    ("ghostwhite", GHOSTWHITE),
+    ("gold", GOLD),
+    ("goldenrod", GOLDENROD),
+    ("gray", GRAY),
+    ("green", GREEN),
+    ("greenyellow", GREENYELLOW),
+    ("grey", GREY),
+    ("honeydew", HONEYDEW),
+    ("hotpink", HOTPINK),
+    ("indianred", INDIANRED),
+    ("indigo", INDIGO),
+    ("ivory", IVORY),
+    ("khaki", KHAKI),
+    ("lavender", LAVENDER),
+    ("lavenderblush", LAVENDERBLUSH),
+    ("lawngreen", LAWNGREEN),
+    ("lemonchiffon", LEMONCHIFFON),
+    ("lightblue", LIGHTBLUE),
+    ("lightcoral", LIGHTCORAL),
+    ("lightcyan", LIGHTCYAN),
+    ("lightgoldenrodyellow", LIGHTGOLDENRODYELLOW),
+    ("lightgray", LIGHTGRAY),
+    ("lightgreen", LIGHTGREEN),
+    ("lightgrey", LIGHTGREY),
+    ("lightpink", LIGHTPINK),
+    ("lightsalmon", LIGHTSALMON),
+    ("lightseagreen", LIGHTSEAGREEN),
+    ("lightskyblue", LIGHTSKYBLUE),
+    ("lightslategray", LIGHTSLATEGRAY),
+    ("lightslategrey", LIGHTSLATEGREY),
+    ("lightsteelblue", LIGHTSTEELBLUE),
+    ("lightyellow", LIGHTYELLOW),
+    ("lime", LIME),
+    ("limegreen", LIMEGREEN),
+    ("linen", LINEN),
+    ("magenta", MAGENTA),
+    ("maroon", MAROON),
+    ("mediumaquamarine", MEDIUMAQUAMARINE),
+    ("mediumblue", MEDIUMBLUE),
+    ("mediumorchid", MEDIUMORCHID),
+    ("mediumpurple", MEDIUMPURPLE),
+    ("mediumseagreen", MEDIUMSEAGREEN),
+    ("mediumslateblue", MEDIUMSLATEBLUE),
+    ("mediumspringgreen", MEDIUMSPRINGGREEN),
+    ("mediumturquoise", MEDIUMTURQUOISE),
+    ("mediumvioletred", MEDIUMVIOLETRED),
+    ("midnightblue", MIDNIGHTBLUE),
+    ("mintcream", MINTCREAM),
+    ("mistyrose", MISTYROSE),
+    ("moccasin", MOCCASIN),
+    ("navajowhite", NAVAJOWHITE),
+    ("navy", NAVY),
+    ("oldlace", OLDLACE),
+    ("olive", OLIVE),
+    ("olivedrab", OLIVEDRAB),
+    ("orange", ORANGE),
+    ("orangered", ORANGERED),
+    ("orchid", ORCHID),
+    ("palegoldenrod", PALEGOLDENROD),
+    ("palegreen", PALEGREEN),
+    ("paleturquoise", PALETURQUOISE),
+    ("palevioletred", PALEVIOLETRED),
+    ("papayawhip", PAPAYAWHIP),
+    ("peachpuff", PEACHPUFF),
+    ("peru", PERU),
+    ("pink", PINK),
+    ("plum", PLUM),
+    ("powderblue", POWDERBLUE),
+    ("purple", PURPLE),
+    ("rebeccapurple", REBECCAPURPLE),
+    ("red", RED),
+    ("rosybrown", ROSYBROWN),
+    ("royalblue", ROYALBLUE),
+    ("saddlebrown", SADDLEBROWN),
+    ("salmon", SALMON),
+    ("sandybrown", SANDYBROWN),
+    ("seagreen", SEAGREEN),
+    ("seashell", SEASHELL),
+    ("sienna", SIENNA),
+    ("silver", SILVER),
+    ("skyblue", SKYBLUE),
+    ("slateblue", SLATEBLUE),
+    ("slategray", SLATEGRAY),
+    ("slategrey", SLATEGREY),
+    ("snow", SNOW),
+    ("springgreen", SPRINGGREEN),
+    ("steelblue", STEELBLUE),
+    ("tan", TAN),
+    ("teal", TEAL),
+    ("thistle", THISTLE),
+    ("tomato", TOMATO),
+    ("turquoise", TURQUOISE),
+    ("violet", VIOLET),
+    ("wheat", WHEAT),
+    ("white", WHITE),
+    ("whitesmoke", WHITESMOKE),
+    ("yellow", YELLOW),
+    ("yellowgreen", YELLOWGREEN),
+];
+
+pub const ALICEBLUE: Color = Color::from_rgb8(240, 248, 255);
+pub const ANTIQUEWHITE: Color = Color::from_rgb8(250, 235, 215);
+pub const AQUA: Color = Color::from_rgb8(0, 255, 255);
+pub const AQUAMARINE: Color = Color::from_rgb8(127, 255, 212);
+pub const AZURE: Color = Color::from_rgb8(240, 255, 255);
+pub const BEIGE: Color = Color::from_rgb8(245, 245, 220);
+pub const BISQUE: Color = Color::from_rgb8(255, 228, 196);
+pub const BLACK: Color = Color::from_rgb8(0, 0, 0);
+pub const BLANCHEDALMOND: Color = Color::from_rgb8(255, 235, 205);
+pub const BLUE: Color = Color::from_rgb8(0, 0, 255);
+pub const BLUEVIOLET: Color = Color::from_rgb8(138, 43, 226);
+pub const BROWN: Color = Color::from_rgb8(165, 42, 42);
+pub const BURLYWOOD: Color = Color::from_rgb8(222, 184, 135);
+pub const CADETBLUE: Color = Color::from_rgb8(95, 158, 160);
+pub const CHARTREUSE: Color = Color::from_rgb8(127, 255, 0);
+pub const CHOCOLATE: Color = Color::from_rgb8(210, 105, 30);
+pub const CORAL: Color = Color::from_rgb8(255, 127, 80);
+pub const CORNFLOWERBLUE: Color = Color::from_rgb8(100, 149, 237);
+pub const CORNSILK: Color = Color::from_rgb8(255, 248, 220);
+pub const CRIMSON: Color = Color::from_rgb8(220, 20, 60);
+pub const CYAN: Color = Color::from_rgb8(0, 255, 255);
+pub const DARKBLUE: Color = Color::from_rgb8(0, 0, 139);
+pub const DARKCYAN: Color = Color::from_rgb8(0, 139, 139);
+pub const DARKGOLDENROD: Color = Color::from_rgb8(184, 134, 11);
+pub const DARKGRAY: Color = Color::from_rgb8(169, 169, 169);
+pub const DARKGREEN: Color = Color::from_rgb8(0, 100, 0);
+pub const DARKGREY: Color = Color::from_rgb8(169, 169, 169);
+pub const DARKKHAKI: Color = Color::from_rgb8(189, 183, 107);
+pub const DARKMAGENTA: Color = Color::from_rgb8(139, 0, 139);
+pub const DARKOLIVEGREEN: Color = Color::from_rgb8(85, 107, 47);
+pub const DARKORANGE: Color = Color::from_rgb8(255, 140, 0);
+pub const DARKORCHID: Color = Color::from_rgb8(153, 50, 204);
+pub const DARKRED: Color = Color::from_rgb8(139, 0, 0);
+pub const DARKSALMON: Color = Color::from_rgb8(233, 150, 122);
+pub const DARKSEAGREEN: Color = Color::from_rgb8(143, 188, 143);
+pub const DARKSLATEBLUE: Color = Color::from_rgb8(72, 61, 139);
+pub const DARKSLATEGRAY: Color = Color::from_rgb8(47, 79, 79);
+pub const DARKSLATEGREY: Color = Color::from_rgb8(47, 79, 79);
+pub const DARKTURQUOISE: Color = Color::from_rgb8(0, 206, 209);
+pub const DARKVIOLET: Color = Color::from_rgb8(148, 0, 211);
+pub const DEEPPINK: Color = Color::from_rgb8(255, 20, 147);
+pub const DEEPSKYBLUE: Color = Color::from_rgb8(0, 191, 255);
+pub const DIMGRAY: Color = Color::from_rgb8(105, 105, 105);
+pub const DIMGREY: Color = Color::from_rgb8(105, 105, 105);
+pub const DODGERBLUE: Color = Color::from_rgb8(30, 144, 255);
+pub const FIREBRICK: Color = Color::from_rgb8(178, 34, 34);
+pub const FLORALWHITE: Color = Color::from_rgb8(255, 250, 240);
+pub const FORESTGREEN: Color = Color::from_rgb8(34, 139, 34);
+pub const FUCHSIA: Color = Color::from_rgb8(255, 0, 255);
+pub const GAINSBORO: Color = Color::from_rgb8(220, 220, 220);
+pub const GHOSTWHITE: Color = Color::from_rgb8(248, 248, 255);
+pub const GOLD: Color = Color::from_rgb8(255, 215, 0);
+pub const GOLDENROD: Color = Color::from_rgb8(218, 165, 32);
+pub const GRAY: Color = Color::from_rgb8(128, 128, 128);
+pub const GREEN: Color = Color::from_rgb8(0, 128, 0);
+pub const GREENYELLOW: Color = Color::from_rgb8(173, 255, 47);
+pub const GREY: Color = Color::from_rgb8(128, 128, 128);
+pub const HONEYDEW: Color = Color::from_rgb8(240, 255, 240);
+pub const HOTPINK: Color = Color::from_rgb8(255, 105, 180);
+pub const INDIANRED: Color = Color::from_rgb8(205, 92, 92);
+pub const INDIGO: Color = Color::from_rgb8(75, 0, 130);
+pub const IVORY: Color = Color::from_rgb8(255, 255, 240);
+pub const KHAKI: Color = Color::from_rgb8(240, 230, 140);
+pub const LAVENDER: Color = Color::from_rgb8(230, 230, 250);
+pub const LAVENDERBLUSH: Color = Color::from_rgb8(255, 240, 245);
+pub const LAWNGREEN: Color = Color::from_rgb8(124, 252, 0);
+pub const LEMONCHIFFON: Color = Color::from_rgb8(255, 250, 205);
+pub const LIGHTBLUE: Color = Color::from_rgb8(173, 216, 230);
+pub const LIGHTCORAL: Color = Color::from_rgb8(240, 128, 128);
+pub const LIGHTCYAN: Color = Color::from_rgb8(224, 255, 255);
+pub const LIGHTGOLDENRODYELLOW: Color = Color::from_rgb8(250, 250, 210);
+pub const LIGHTGRAY: Color = Color::from_rgb8(211, 211, 211);
+pub const LIGHTGREEN: Color = Color::from_rgb8(144, 238, 144);
+pub const LIGHTGREY: Color = Color::from_rgb8(211, 211, 211);
+pub const LIGHTPINK: Color = Color::from_rgb8(255, 182, 193);
+pub const LIGHTSALMON: Color = Color::from_rgb8(255, 160, 122);
+pub const LIGHTSEAGREEN: Color = Color::from_rgb8(32, 178, 170);
+pub const LIGHTSKYBLUE: Color = Color::from_rgb8(135, 206, 250);
+pub const LIGHTSLATEGRAY: Color = Color::from_rgb8(119, 136, 153);
+pub const LIGHTSLATEGREY: Color = Color::from_rgb8(119, 136, 153);
+pub const LIGHTSTEELBLUE: Color = Color::from_rgb8(176, 196, 222);
+pub const LIGHTYELLOW: Color = Color::from_rgb8(255, 255, 224);
+pub const LIME: Color = Color::from_rgb8(0, 255, 0);
+pub const LIMEGREEN: Color = Color::from_rgb8(50, 205, 50);
+pub const LINEN: Color = Color::from_rgb8(250, 240, 230);
+pub const MAGENTA: Color = Color::from_rgb8(255, 0, 255);
+pub const MAROON: Color = Color::from_rgb8(128, 0, 0);
+pub const MEDIUMAQUAMARINE: Color = Color::from_rgb8(102, 205, 170);
+pub const MEDIUMBLUE: Color = Color::from_rgb8(0, 0, 205);
+pub const MEDIUMORCHID: Color = Color::from_rgb8(186, 85, 211);
+pub const MEDIUMPURPLE: Color = Color::from_rgb8(147, 112, 219);
+pub const MEDIUMSEAGREEN: Color = Color::from_rgb8(60, 179, 113);
+pub const MEDIUMSLATEBLUE: Color = Color::from_rgb8(123, 104, 238);
+pub const MEDIUMSPRINGGREEN: Color = Color::from_rgb8(0, 250, 154);
+pub const MEDIUMTURQUOISE: Color = Color::from_rgb8(72, 209, 204);
+pub const MEDIUMVIOLETRED: Color = Color::from_rgb8(199, 21, 133);
+pub const MIDNIGHTBLUE: Color = Color::from_rgb8(25, 25, 112);
+pub const MINTCREAM: Color = Color::from_rgb8(245, 255, 250);
+pub const MISTYROSE: Color = Color::from_rgb8(255, 228, 225);
+pub const MOCCASIN: Color = Color::from_rgb8(255, 228, 181);
+pub const NAVAJOWHITE: Color = Color::from_rgb8(255, 222, 173);
+pub const NAVY: Color = Color::from_rgb8(0, 0, 128);
+pub const OLDLACE: Color = Color::from_rgb8(253, 245, 230);
+pub const OLIVE: Color = Color::from_rgb8(128, 128, 0);
+pub const OLIVEDRAB: Color = Color::from_rgb8(107, 142, 35);
+pub const ORANGE: Color = Color::from_rgb8(255, 165, 0);
+pub const ORANGERED: Color = Color::from_rgb8(255, 69, 0);
+pub const ORCHID: Color = Color::from_rgb8(218, 112, 214);
+pub const PALEGOLDENROD: Color = Color::from_rgb8(238, 232, 170);
+pub const PALEGREEN: Color = Color::from_rgb8(152, 251, 152);
+pub const PALETURQUOISE: Color = Color::from_rgb8(175, 238, 238);
+pub const PALEVIOLETRED: Color = Color::from_rgb8(219, 112, 147);
+pub const PAPAYAWHIP: Color = Color::from_rgb8(255, 239, 213);
+pub const PEACHPUFF: Color = Color::from_rgb8(255, 218, 185);
+pub const PERU: Color = Color::from_rgb8(205, 133, 63);
+pub const PINK: Color = Color::from_rgb8(255, 192, 203);
+pub const PLUM: Color = Color::from_rgb8(221, 160, 221);
+pub const POWDERBLUE: Color = Color::from_rgb8(176, 224, 230);
+pub const PURPLE: Color = Color::from_rgb8(128, 0, 128);
+pub const REBECCAPURPLE: Color = Color::from_rgb8(102, 51, 153);
+pub const RED: Color = Color::from_rgb8(255, 0, 0);
+pub const ROSYBROWN: Color = Color::from_rgb8(188, 143, 143);
+pub const ROYALBLUE: Color = Color::from_rgb8(65, 105, 225);
+pub const SADDLEBROWN: Color = Color::from_rgb8(139, 69, 19);
+pub const SALMON: Color = Color::from_rgb8(250, 128, 114);
+pub const SANDYBROWN: Color = Color::from_rgb8(244, 164, 96);
+pub const SEAGREEN: Color = Color::from_rgb8(46, 139, 87);
+pub const SEASHELL: Color = Color::from_rgb8(255, 245, 238);
+pub const SIENNA: Color = Color::from_rgb8(160, 82, 45);
+pub const SILVER: Color = Color::from_rgb8(192, 192, 192);
+pub const SKYBLUE: Color = Color::from_rgb8(135, 206, 235);
+pub const SLATEBLUE: Color = Color::from_rgb8(106, 90, 205);
+pub const SLATEGRAY: Color = Color::from_rgb8(112, 128, 144);
+pub const SLATEGREY: Color = Color::from_rgb8(112, 128, 144);
+pub const SNOW: Color = Color::from_rgb8(255, 250, 250);
+pub const SPRINGGREEN: Color = Color::from_rgb8(0, 255, 127);
+pub const STEELBLUE: Color = Color::from_rgb8(70, 130, 180);
+pub const TAN: Color = Color::from_rgb8(210, 180, 140);
+pub const TEAL: Color = Color::from_rgb8(0, 128, 128);
+pub const THISTLE: Color = Color::from_rgb8(216, 191, 216);
+pub const TOMATO: Color = Color::from_rgb8(255, 99, 71);
+pub const TURQUOISE: Color = Color::from_rgb8(64, 224, 208);
+pub const VIOLET: Color = Color::from_rgb8(238, 130, 238);
+pub const WHEAT: Color = Color::from_rgb8(245, 222, 179);
+pub const WHITE: Color = Color::from_rgb8(255, 255, 255);
+pub const WHITESMOKE: Color = Color::from_rgb8(245, 245, 245);
+pub const YELLOW: Color = Color::from_rgb8(255, 255, 0);
+pub const YELLOWGREEN: Color = Color::from_rgb8(154, 205, 50);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_is_sorted() {
+        // by_name searches it with a binary search
+        assert!(ALL.is_sorted_by(|(a, _), (b, _)| a < b));
+        assert!(
+            ALL.iter()
+                .all(|(name, _)| *name == name.to_ascii_lowercase())
+        );
+    }
+
+    #[test]
+    fn every_name_is_found() {
+        for (name, color) in ALL {
+            assert_eq!(by_name(name), Some(color), "{name}");
+        }
+    }
+
+    #[test]
+    fn lookup_is_case_insensitive() {
+        assert_eq!(by_name("RebeccaPurple"), Some(REBECCAPURPLE));
+        assert_eq!(by_name("REBECCAPURPLE"), Some(REBECCAPURPLE));
+        assert_eq!(by_name("rebeccapurple"), Some(REBECCAPURPLE));
+        assert_eq!(by_name("rebecca purple"), None);
+        assert_eq!(by_name(""), None);
+    }
+}
@@ -0,0 +1,249 @@
+use super::hsv::OutOfGamut;
+use super::srgb::{from_linear, in_gamut, to_linear};
+use super::{Hsv, Oklch};
+
+use iced_core::Color;
+
+/// Lightness and the green/red (`a`) and blue/yellow (`b`) axes of the perceptually
+/// uniform [Oklab](https://bottosson.github.io/posts/oklab/) color space.
+///
+/// Distances and straight lines in Oklab follow how different colors look, which makes
+/// it a good fit to compare and interpolate colors. See [Oklch] for the polar form.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Oklab {
+    /// The Lightness component, on `[0, 1]`.
+    pub l: f32,
+    /// The green (negative) to red (positive) component.
+    pub a: f32,
+    /// The blue (negative) to yellow (positive) component.
+    pub b: f32,
+    /// The alpha component.
+    pub alpha: f32,
+}
+
+impl Default for Oklab {
+    fn default() -> Self {
+        Self {
+            l: Default::default(),
+            a: Default::default(),
+            b: Default::default(),
+            alpha: 1.0,
+        }
+    }
+}
+
+impl Oklab {
+    /// The euclidean distance to another color, ignoring alpha.
+    ///
+    /// A distance of roughly `0.02` is about the smallest difference most people can see.
+    pub fn distance(self, other: Oklab) -> f32 {
+        ((self.l - other.l).powi(2) + (self.a - other.a).powi(2) + (self.b - other.b).powi(2))
+            .sqrt()
+    }
+
+    /// Linearly interpolates towards another color, where `t` of `0` gives `self` and `1` gives `other`.
+    pub fn lerp(self, other: Oklab, t: f32) -> Oklab {
+        let lerp = |from: f32, to: f32| from + (to - from) * t;
+
+        Oklab {
+            l: lerp(self.l, other.l),
+            a: lerp(self.a, other.a),
+            b: lerp(self.b, other.b),
+            alpha: lerp(self.alpha, other.alpha),
+        }
+    }
+
+    /// Converts to a [Color] without clamping.
+    ///
+    /// Unlike `Color::from`, this fails if the color falls outside of the sRGB gamut.
+    pub fn try_to_color(self) -> Result<Color, OutOfGamut> {
+        let rgb = self.to_linear_rgb();
+
+        match in_gamut(rgb) {
+            true => {
+                let [r, g, b] = rgb.map(|c| from_linear(c.clamp(0.0, 1.0)));
+                Ok(Color::from_rgba(r, g, b, self.alpha))
+            }
+            false => Err(OutOfGamut {
+                clamped: Color::from(self),
+            }),
+        }
+    }
+
+    // https://bottosson.github.io/posts/oklab/#converting-from-linear-srgb-to-oklab
+    fn to_linear_rgb(self) -> [f32; 3] {
+        let Oklab { l, a, b, .. } = self;
+
+        let l_ = l + 0.396_337_78 * a + 0.215_803_76 * b;
+        let m_ = l - 0.105_561_346 * a - 0.063_854_17 * b;
+        let s_ = l - 0.089_484_18 * a - 1.291_485_5 * b;
+
+        let [l, m, s] = [l_, m_, s_].map(|c| c * c * c);
+
+        [
+            4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+            -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+            -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+        ]
+    }
+}
+
+impl From<Color> for Oklab {
+    // https://bottosson.github.io/posts/oklab/#converting-from-linear-srgb-to-oklab
+    fn from(color: Color) -> Self {
+        let [r, g, b] = [color.r, color.g, color.b].map(to_linear);
+
+        let l = 0.412_221_47 * r + 0.536_332_55 * g + 0.051_445_995 * b;
+        let m = 0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b;
+        let s = 0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b;
+
+        let [l, m, s] = [l, m, s].map(f32::cbrt);
+
+        Self {
+            l: 0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            a: 1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            b: 0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+            alpha: color.a,
+        }
+    }
+}
+
+impl From<Oklab> for Color {
+    /// Colors outside of the sRGB gamut have each channel clamped.
+    fn from(oklab: Oklab) -> Self {
+        let [r, g, b] = oklab
+            .to_linear_rgb()
+            .map(|c| from_linear(c.clamp(0.0, 1.0)));
+
+        Color::from_rgba(r, g, b, oklab.alpha)
+    }
+}
+
+impl From<Hsv> for Oklab {
+    fn from(hsv: Hsv) -> Self {
+        Oklab::from(Color::from(hsv))
+    }
+}
+
+impl From<Oklab> for Hsv {
+    fn from(oklab: Oklab) -> Self {
+        Hsv::from(Color::from(oklab))
+    }
+}
+
+impl From<Oklch> for Oklab {
+    fn from(Oklch { l, c, h, a }: Oklch) -> Self {
+        let (sin, cos) = h.to_radians().sin_cos();
+
+        Self {
+            l,
+            a: c * cos,
+            b: c * sin,
+            alpha: a,
+        }
+    }
+}
+
+impl From<Oklab> for Oklch {
+    fn from(Oklab { l, a, b, alpha }: Oklab) -> Self {
+        Self {
+            l,
+            c: a.hypot(b),
+            h: b.atan2(a).to_degrees().rem_euclid(360.0),
+            a: alpha,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(oklab: Oklab, [l, a, b]: [f32; 3]) {
+        assert!(
+            (oklab.l - l).abs() < 1e-3 && (oklab.a - a).abs() < 1e-3 && (oklab.b - b).abs() < 1e-3,
+            "{oklab:?} isn't close to {:?}",
+            [l, a, b]
+        );
+    }
+
+    #[test]
+    fn reference_values() {
+        // Black, white and the sRGB primaries
+        assert_close(Oklab::from(Color::WHITE), [1.0, 0.0, 0.0]);
+        assert_close(Oklab::from(Color::BLACK), [0.0, 0.0, 0.0]);
+        assert_close(
+            Oklab::from(Color::from_rgb(1.0, 0.0, 0.0)),
+            [0.628, 0.225, 0.126],
+        );
+        assert_close(
+            Oklab::from(Color::from_rgb(0.0, 1.0, 0.0)),
+            [0.866, -0.234, 0.179],
+        );
+        assert_close(
+            Oklab::from(Color::from_rgb(0.0, 0.0, 1.0)),
+            [0.452, -0.032, -0.312],
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        for rgb in [
+            [1.0, 0.5, 0.0],
+            [0.2, 0.4, 0.6],
+            [0.9, 0.1, 0.7],
+            [1.0, 1.0, 1.0],
+        ] {
+            let color = Color::from_rgba(rgb[0], rgb[1], rgb[2], 0.25);
+            let back = Oklab::from(color).try_to_color().unwrap();
+
+            for (channel, expected) in [back.r, back.g, back.b].into_iter().zip(rgb) {
+                assert!((channel - expected).abs() < 1e-3, "{back:?} from {rgb:?}");
+            }
+            assert_eq!(back.a, 0.25);
+        }
+    }
+
+    #[test]
+    fn achromatic() {
+        for gray in [0.1, 0.5, 0.9] {
+            let oklab = Oklab::from(Color::from_rgb(gray, gray, gray));
+
+            assert!(oklab.a.abs() < 1e-4 && oklab.b.abs() < 1e-4, "{oklab:?}");
+        }
+    }
+
+    #[test]
+    fn out_of_gamut() {
+        // Far greener than the sRGB primary
+        let oklab = Oklab {
+            l: 0.8,
+            a: -0.4,
+            b: 0.2,
+            alpha: 1.0,
+        };
+
+        let OutOfGamut { clamped } = oklab.try_to_color().unwrap_err();
+        assert_eq!(clamped, Color::from(oklab));
+        assert!(
+            [clamped.r, clamped.g, clamped.b]
+                .iter()
+                .all(|c| (0.0..=1.0).contains(c))
+        );
+    }
+
+    #[test]
+    fn polar_round_trip() {
+        let oklab = Oklab {
+            l: 0.6,
+            a: -0.1,
+            b: -0.05,
+            alpha: 0.5,
+        };
+        let oklch = Oklch::from(oklab);
+
+        assert!((oklch.c - 0.1118).abs() < 1e-3 && (180.0..270.0).contains(&oklch.h));
+        assert_close(Oklab::from(oklch), [0.6, -0.1, -0.05]);
+    }
+}
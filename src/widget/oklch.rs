@@ -0,0 +1,149 @@
+use super::hsv::OutOfGamut;
+use super::{Hsv, Oklab};
+
+use iced_core::Color;
+
+/// The chroma treated as fully saturated, following CSS where `100%` chroma is `0.4`.
+///
+/// The most saturated sRGB colors have a chroma of a little over `0.32`.
+pub const MAX_CHROMA: f32 = 0.4;
+
+/// Lightness, Chroma, Hue: the polar form of [Oklab].
+///
+/// Colors of equal lightness look equally bright, whatever their hue, unlike the value of [Hsv].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Oklch {
+    /// The Lightness component, on `[0, 1]`.
+    pub l: f32,
+    /// The Chroma component, from `0` up to roughly [MAX_CHROMA].
+    pub c: f32,
+    /// The Hue component, in degrees.
+    pub h: f32,
+    /// The alpha component.
+    pub a: f32,
+}
+
+impl Default for Oklch {
+    fn default() -> Self {
+        Self {
+            l: Default::default(),
+            c: Default::default(),
+            h: Default::default(),
+            a: 1.0,
+        }
+    }
+}
+
+impl Oklch {
+    /// Converts to a [Color] without clamping.
+    ///
+    /// Unlike `Color::from`, this fails if the color falls outside of the sRGB gamut, as
+    /// colors of high chroma do.
+    pub fn try_to_color(self) -> Result<Color, OutOfGamut> {
+        Oklab::from(self).try_to_color()
+    }
+}
+
+impl From<Color> for Oklch {
+    fn from(color: Color) -> Self {
+        Oklch::from(Oklab::from(color))
+    }
+}
+
+impl From<Oklch> for Color {
+    /// Colors outside of the sRGB gamut have each channel clamped.
+    fn from(oklch: Oklch) -> Self {
+        Color::from(Oklab::from(oklch))
+    }
+}
+
+impl From<Hsv> for Oklch {
+    fn from(hsv: Hsv) -> Self {
+        Oklch::from(Color::from(hsv))
+    }
+}
+
+impl From<Oklch> for Hsv {
+    fn from(oklch: Oklch) -> Self {
+        Hsv::from(Color::from(oklch))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(oklch: Oklch, [l, c, h]: [f32; 3]) {
+        assert!(
+            (oklch.l - l).abs() < 1e-3 && (oklch.c - c).abs() < 1e-3 && (oklch.h - h).abs() < 0.1,
+            "{oklch:?} isn't close to {:?}",
+            [l, c, h]
+        );
+    }
+
+    #[test]
+    fn reference_values() {
+        // The sRGB primaries
+        assert_close(
+            Oklch::from(Color::from_rgb(1.0, 0.0, 0.0)),
+            [0.628, 0.258, 29.23],
+        );
+        assert_close(
+            Oklch::from(Color::from_rgb(0.0, 1.0, 0.0)),
+            [0.866, 0.295, 142.5],
+        );
+        assert_close(
+            Oklch::from(Color::from_rgb(0.0, 0.0, 1.0)),
+            [0.452, 0.313, 264.05],
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        for rgb in [
+            [1.0, 0.5, 0.0],
+            [0.2, 0.4, 0.6],
+            [0.9, 0.1, 0.7],
+            [0.0, 1.0, 1.0],
+        ] {
+            let color = Color::from_rgb(rgb[0], rgb[1], rgb[2]);
+            let back = Oklch::from(color).try_to_color().unwrap();
+
+            for (channel, expected) in [back.r, back.g, back.b].into_iter().zip(rgb) {
+                assert!((channel - expected).abs() < 1e-3, "{back:?} from {rgb:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn achromatic() {
+        for gray in [0.0, 0.25, 0.5, 1.0] {
+            let oklch = Oklch::from(Color::from_rgb(gray, gray, gray));
+            assert!(oklch.c < 1e-3, "{oklch:?}");
+
+            // Whatever the hue of a gray, it converts back to the same gray
+            let back = Color::from(Oklch { h: 123.0, ..oklch });
+            assert!((back.r - gray).abs() < 1e-3 && (back.b - gray).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn out_of_gamut_chroma() {
+        let oklch = Oklch {
+            l: 0.7,
+            c: MAX_CHROMA,
+            h: 150.0,
+            a: 0.5,
+        };
+
+        let OutOfGamut { clamped } = oklch.try_to_color().unwrap_err();
+        assert_eq!(clamped, Color::from(oklch));
+        assert!(
+            [clamped.r, clamped.g, clamped.b]
+                .iter()
+                .all(|c| (0.0..=1.0).contains(c))
+        );
+        assert_eq!(clamped.a, 0.5);
+    }
+}
@@ -0,0 +1,358 @@
+//! Reading and writing palette files, e.g. to fill a [SwatchGrid](super::SwatchGrid).
+//!
+//! Palettes are named colors, in the order of the file. Reading or writing the files
+//! themselves is left to the application.
+
+use super::{Cmyk, Hsv, Lab};
+
+use std::fmt::{self, Write};
+
+/// The first line of a GIMP palette.
+const GPL_HEADER: &str = "GIMP Palette";
+/// The signature at the start of an Adobe Swatch Exchange file.
+const ASE_SIGNATURE: &[u8] = b"ASEF";
+/// The type of the blocks of an Adobe Swatch Exchange file holding a color.
+const ASE_COLOR_ENTRY: u16 = 0x0001;
+
+/// Parses a GIMP palette (`.gpl`) into its named colors.
+///
+/// Colors without a name are given an empty one.
+pub fn parse_gpl(source: &str) -> Result<Vec<(String, Hsv)>, ParseError> {
+    let mut lines = source
+        .trim_start_matches('\u{feff}')
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim()));
+
+    if lines.next().is_none_or(|(_, line)| line != GPL_HEADER) {
+        return Err(ParseError::MissingHeader);
+    }
+
+    lines
+        .filter(|(_, line)| {
+            !line.is_empty()
+                && !line.starts_with('#')
+                && !line.starts_with("Name:")
+                && !line.starts_with("Columns:")
+        })
+        .map(|(number, line)| parse_gpl_entry(line).ok_or(ParseError::InvalidLine(number)))
+        .collect()
+}
+
+/// Parses a `red green blue name` line of a GIMP palette.
+fn parse_gpl_entry(line: &str) -> Option<(String, Hsv)> {
+    let mut rest = line;
+    let mut rgb = [0; 3];
+
+    for channel in &mut rgb {
+        let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+
+        *channel = rest[..end].parse().ok()?;
+        rest = rest[end..].trim_start();
+    }
+
+    Some((rest.to_owned(), Hsv::from_rgb8(rgb)))
+}
+
+/// Writes the named colors as a GIMP palette (`.gpl`) with the name.
+///
+/// The format has no alpha channel, so colors are written as if they were opaque.
+pub fn write_gpl(name: &str, colors: &[(String, Hsv)]) -> String {
+    let mut gpl = format!("{GPL_HEADER}\nName: {name}\n#\n");
+
+    for (name, color) in colors {
+        let [r, g, b] = color.to_rgb8();
+
+        // Writing to a String can't fail
+        let _ = match name.is_empty() {
+            true => writeln!(gpl, "{r:3} {g:3} {b:3}"),
+            false => writeln!(gpl, "{r:3} {g:3} {b:3}\t{name}"),
+        };
+    }
+
+    gpl
+}
+
+/// Parses an Adobe Swatch Exchange file (`.ase`) into its named colors.
+///
+/// Groups are flattened, keeping the colors in the order of the file. RGB, CMYK, Lab and
+/// gray entries are supported.
+pub fn parse_ase(source: &[u8]) -> Result<Vec<(String, Hsv)>, ParseError> {
+    let mut reader = Reader(source);
+
+    if reader.take(ASE_SIGNATURE.len()) != Some(ASE_SIGNATURE) {
+        return Err(ParseError::MissingHeader);
+    }
+
+    // The version, which has only ever been 1.0
+    let block_count = reader
+        .take(4)
+        .and_then(|_| reader.u32())
+        .ok_or(ParseError::MissingHeader)?;
+
+    let mut colors = Vec::new();
+
+    for number in 1..=block_count as usize {
+        let invalid = ParseError::InvalidBlock(number);
+
+        let kind = reader.u16().ok_or(invalid)?;
+        let length = reader.u32().ok_or(invalid)?;
+        let block = reader.take(length as usize).ok_or(invalid)?;
+
+        // Groups only hold a name, and their colors follow as blocks of their own
+        if kind == ASE_COLOR_ENTRY {
+            colors.push(parse_ase_entry(Reader(block)).ok_or(invalid)?);
+        }
+    }
+
+    Ok(colors)
+}
+
+/// Parses the name, color model and components of a color block.
+fn parse_ase_entry(mut block: Reader<'_>) -> Option<(String, Hsv)> {
+    let name = block.utf16()?;
+    let model = block.take(4)?;
+
+    let mut components =
+        |count: usize| -> Option<Vec<f32>> { (0..count).map(|_| block.f32()).collect() };
+
+    let color = match model {
+        b"RGB " => Hsv::from_rgb(<[f32; 3]>::try_from(components(3)?).ok()?),
+        b"CMYK" => {
+            let [c, m, y, k] = <[f32; 4]>::try_from(components(4)?).ok()?;
+
+            Hsv::from_cmyk(Cmyk { c, m, y, k, a: 1.0 })
+        }
+        // Lightness is stored as a fraction rather than a percentage
+        b"LAB " => {
+            let [l, a, b] = <[f32; 3]>::try_from(components(3)?).ok()?;
+
+            Hsv::from(Lab {
+                l: l * 100.0,
+                a,
+                b,
+                alpha: 1.0,
+            })
+        }
+        b"Gray" => {
+            let [gray] = <[f32; 1]>::try_from(components(1)?).ok()?;
+
+            Hsv::from_rgb([gray; 3])
+        }
+        _ => return None,
+    };
+
+    Some((name, color))
+}
+
+/// Reads the big-endian values of an Adobe Swatch Exchange file.
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, length: usize) -> Option<&'a [u8]> {
+        let (taken, rest) = self.0.split_at_checked(length)?;
+        self.0 = rest;
+
+        Some(taken)
+    }
+
+    fn u16(&mut self) -> Option<u16> {
+        Some(u16::from_be_bytes(self.take(2)?.try_into().ok()?))
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        Some(u32::from_be_bytes(self.take(4)?.try_into().ok()?))
+    }
+
+    fn f32(&mut self) -> Option<f32> {
+        Some(f32::from_be_bytes(self.take(4)?.try_into().ok()?))
+    }
+
+    /// A string prefixed by its length in UTF-16 code units, including a null terminator.
+    fn utf16(&mut self) -> Option<String> {
+        let length = self.u16()? as usize;
+        let units: Vec<u16> = (0..length).map(|_| self.u16()).collect::<Option<_>>()?;
+
+        String::from_utf16(units.strip_suffix(&[0]).unwrap_or(&units)).ok()
+    }
+}
+
+/// The error returned when a palette file can't be parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseError {
+    /// The file doesn't start with the header of its format.
+    MissingHeader,
+    /// The line, counting from 1, isn't a valid entry.
+    InvalidLine(usize),
+    /// The block, counting from 1, is cut short or holds an unsupported color.
+    InvalidBlock(usize),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingHeader => write!(f, "missing palette header"),
+            Self::InvalidLine(line) => write!(f, "invalid palette entry on line {line}"),
+            Self::InvalidBlock(block) => write!(f, "invalid palette block {block}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An Adobe Swatch Exchange file of the blocks, whose header counts `block_count` of them.
+    fn ase(block_count: u32, blocks: &[(u16, Vec<u8>)]) -> Vec<u8> {
+        let mut ase = [ASE_SIGNATURE, &[0, 1, 0, 0], &block_count.to_be_bytes()].concat();
+
+        for (kind, block) in blocks {
+            ase.extend(kind.to_be_bytes());
+            ase.extend((block.len() as u32).to_be_bytes());
+            ase.extend(block);
+        }
+
+        ase
+    }
+
+    /// A color block with the name, color model and components.
+    fn ase_entry(name: &str, model: &[u8; 4], components: &[f32]) -> (u16, Vec<u8>) {
+        let mut block = Vec::new();
+        let name: Vec<u16> = name.encode_utf16().chain([0]).collect();
+
+        block.extend((name.len() as u16).to_be_bytes());
+        block.extend(name.iter().flat_map(|unit| unit.to_be_bytes()));
+        block.extend(model);
+        block.extend(
+            components
+                .iter()
+                .flat_map(|component| component.to_be_bytes()),
+        );
+        // The color type, i.e. global, spot or normal
+        block.extend(2u16.to_be_bytes());
+
+        (ASE_COLOR_ENTRY, block)
+    }
+
+    fn rgb8(colors: &[(String, Hsv)]) -> Vec<(&str, [u8; 3])> {
+        colors
+            .iter()
+            .map(|(name, color)| (name.as_str(), color.to_rgb8()))
+            .collect()
+    }
+
+    #[test]
+    fn gpl_round_trip() {
+        let colors = vec![
+            (String::from("Orange"), Hsv::from_rgb8([255, 128, 0])),
+            (String::new(), Hsv::from_rgb8([0, 0, 0])),
+            (String::from("Sky blue"), Hsv::from_rgb8([135, 206, 235])),
+        ];
+
+        let gpl = write_gpl("Test", &colors);
+        assert!(gpl.starts_with("GIMP Palette\nName: Test\n"));
+
+        assert_eq!(rgb8(&parse_gpl(&gpl).unwrap()), rgb8(&colors));
+    }
+
+    #[test]
+    fn gpl_skips_comments_and_metadata() {
+        let gpl =
+            "\u{feff}GIMP Palette\nName: Test\nColumns: 4\n# comment\n\n  0 128 255   Azure  \n";
+
+        assert_eq!(rgb8(&parse_gpl(gpl).unwrap()), [("Azure", [0, 128, 255])]);
+    }
+
+    #[test]
+    fn gpl_errors() {
+        assert_eq!(parse_gpl(""), Err(ParseError::MissingHeader));
+        assert_eq!(
+            parse_gpl("Paint Palette\n0 0 0"),
+            Err(ParseError::MissingHeader)
+        );
+        assert_eq!(
+            parse_gpl("GIMP Palette\n#\n0 0 0\n255 0\n"),
+            Err(ParseError::InvalidLine(4))
+        );
+        assert_eq!(
+            parse_gpl("GIMP Palette\n256 0 0 Too bright"),
+            Err(ParseError::InvalidLine(2))
+        );
+        assert_eq!(
+            parse_gpl("GIMP Palette\nred green blue"),
+            Err(ParseError::InvalidLine(2))
+        );
+    }
+
+    #[test]
+    fn ase_color_models() {
+        let source = ase(
+            7,
+            &[
+                // A group around the colors, which is flattened
+                (0xc001, vec![0, 1, 0, 0]),
+                ase_entry("Red", b"RGB ", &[1.0, 0.0, 0.0]),
+                ase_entry("Cyan", b"CMYK", &[1.0, 0.0, 0.0, 0.0]),
+                ase_entry("White", b"LAB ", &[1.0, 0.0, 0.0]),
+                ase_entry("Mid gray", b"LAB ", &[0.5, 0.0, 0.0]),
+                ase_entry("Gray", b"Gray", &[0.5]),
+                (0xc002, Vec::new()),
+            ],
+        );
+
+        assert_eq!(
+            rgb8(&parse_ase(&source).unwrap()),
+            [
+                ("Red", [255, 0, 0]),
+                ("Cyan", [0, 255, 255]),
+                ("White", [255, 255, 255]),
+                ("Mid gray", [119, 119, 119]),
+                ("Gray", [128, 128, 128]),
+            ]
+        );
+    }
+
+    #[test]
+    fn ase_errors() {
+        let red = ase_entry("Red", b"RGB ", &[1.0, 0.0, 0.0]);
+
+        assert_eq!(parse_ase(b""), Err(ParseError::MissingHeader));
+        assert_eq!(
+            parse_ase(b"GPL!\0\x01\0\0\0\0\0\0"),
+            Err(ParseError::MissingHeader)
+        );
+        // Cut short within the version and block count
+        assert_eq!(
+            parse_ase(b"ASEF\0\x01\0\0\0"),
+            Err(ParseError::MissingHeader)
+        );
+
+        // More blocks are counted than there are
+        let source = ase(2, std::slice::from_ref(&red));
+        assert_eq!(parse_ase(&source), Err(ParseError::InvalidBlock(2)));
+
+        // Cut short within the block
+        let source = ase(1, std::slice::from_ref(&red));
+        assert_eq!(
+            parse_ase(&source[..source.len() - 1]),
+            Err(ParseError::InvalidBlock(1))
+        );
+
+        // Cut short within the type and length of the block
+        let source = ase(1, &[]);
+        assert_eq!(
+            parse_ase(&[&source[..], &[0, 1, 0]].concat()),
+            Err(ParseError::InvalidBlock(1))
+        );
+
+        // Too few components for the color model
+        let source = ase(2, &[red.clone(), ase_entry("Red", b"RGB ", &[1.0, 0.0])]);
+        assert_eq!(parse_ase(&source), Err(ParseError::InvalidBlock(2)));
+
+        // An unsupported color model
+        let source = ase(1, &[ase_entry("Hex", b"HEX ", &[1.0, 0.0, 0.0])]);
+        assert_eq!(parse_ase(&source), Err(ParseError::InvalidBlock(1)));
+    }
+}
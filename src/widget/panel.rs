@@ -0,0 +1,139 @@
+//! A complete picker, combining the common spectrums with a preview and a hex field.
+
+use super::style::Catalog;
+use super::{ColorPicker, Hsv, HsvComponent, Spectrum, SpectrumRenderer, hex_input, swatch};
+
+use std::rc::Rc;
+
+use iced_core::{Element, Length, Pixels, alignment, text};
+use iced_widget::{Column, Row, text_input};
+
+/// Creates a new [ColorPickerPanel] with the current [Hsv] (or [Color](iced_core::Color)) value, and a closure to produce a message when a color is picked.
+pub fn color_picker_panel<'a, Message, FromHsv>(
+    color: impl Into<Hsv>,
+    on_select: impl Fn(FromHsv) -> Message + 'a,
+) -> ColorPickerPanel<'a, Message>
+where
+    Message: 'a,
+    FromHsv: From<Hsv> + 'a,
+{
+    ColorPickerPanel::new(color, move |color| on_select(color.into()))
+}
+
+/// A saturation/value square with a hue bar beside it, an alpha bar below, and a
+/// preview swatch next to a [HexInput](super::HexInput).
+///
+/// This is the layout most applications build by hand; use the widgets it's made of
+/// directly for anything else.
+pub struct ColorPickerPanel<'a, Message> {
+    color: Hsv,
+    on_select: Rc<dyn Fn(Hsv) -> Message + 'a>,
+    size: f32,
+    bar_width: f32,
+    spacing: f32,
+    alpha: bool,
+    hex_input: bool,
+}
+
+impl<'a, Message> ColorPickerPanel<'a, Message> {
+    pub fn new(color: impl Into<Hsv>, on_select: impl Fn(Hsv) -> Message + 'a) -> Self {
+        Self {
+            color: color.into(),
+            on_select: Rc::new(on_select),
+            size: 200.0,
+            bar_width: 24.0,
+            spacing: 8.0,
+            alpha: true,
+            hex_input: true,
+        }
+    }
+
+    /// Set the width and height of the saturation/value square.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = size.into().0;
+        self
+    }
+
+    /// Set the thickness of the hue and alpha bars, and the height of the preview.
+    pub fn bar_width(mut self, bar_width: impl Into<Pixels>) -> Self {
+        self.bar_width = bar_width.into().0;
+        self
+    }
+
+    /// Set the space between the parts of the [ColorPickerPanel].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Show the alpha bar, on by default.
+    pub fn alpha(mut self, alpha: bool) -> Self {
+        self.alpha = alpha;
+        self
+    }
+
+    /// Show the hex field next to the preview, on by default.
+    pub fn hex_input(mut self, hex_input: bool) -> Self {
+        self.hex_input = hex_input;
+        self
+    }
+
+    /// The full width of the panel.
+    fn width(&self) -> f32 {
+        self.size + self.spacing + self.bar_width
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ColorPickerPanel<'a, Message>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + text_input::Catalog + 'a,
+    Renderer: SpectrumRenderer + text::Renderer + 'static,
+{
+    fn from(panel: ColorPickerPanel<'a, Message>) -> Self {
+        let picker = |spectrum: Spectrum| {
+            let on_select = panel.on_select.clone();
+
+            ColorPicker::new(panel.color, move |color| on_select(color)).spectrum(spectrum)
+        };
+
+        let square = picker(Spectrum::saturation_value()).square(panel.size);
+        let hue = picker(Spectrum::hue_vertical())
+            .width(panel.bar_width)
+            .height(panel.size);
+
+        let mut column = Column::new()
+            .push(Row::with_children([square.into(), hue.into()]).spacing(panel.spacing))
+            .spacing(panel.spacing)
+            .width(panel.width());
+
+        if panel.alpha {
+            let alpha = picker(Spectrum::new_horizontal(HsvComponent::Alpha))
+                .width(Length::Fill)
+                .height(panel.bar_width);
+
+            column = column.push(alpha);
+        }
+
+        let preview = swatch(panel.color)
+            .width(match panel.hex_input {
+                true => Length::Fixed(panel.size / 2.0),
+                false => Length::Fill,
+            })
+            .height(panel.bar_width);
+
+        let mut footer = Row::new()
+            .push(preview)
+            .spacing(panel.spacing)
+            .align_y(alignment::Vertical::Center);
+
+        if panel.hex_input {
+            let on_select = panel.on_select.clone();
+
+            footer = footer.push(hex_input(panel.color, move |color| on_select(color)));
+        }
+
+        column.push(footer).into()
+    }
+}
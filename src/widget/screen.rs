@@ -0,0 +1,34 @@
+//! Pick colors from anywhere on the screen, outside of the application too.
+
+use super::Hsv;
+
+use iced_runtime::Task;
+
+/// Lets the user pick a color from anywhere on the screen, e.g. once a button is pressed.
+///
+/// On Linux, this goes through the color picker of the XDG desktop portal, which most
+/// desktops provide. Other platforms aren't supported yet.
+///
+/// The task produces nothing if the user cancels, or if picking isn't supported.
+pub fn pick_from_screen() -> Task<Hsv> {
+    Task::future(pick()).and_then(Task::done)
+}
+
+#[cfg(target_os = "linux")]
+async fn pick() -> Option<Hsv> {
+    let color = ashpd::desktop::Color::pick()
+        .send()
+        .await
+        .ok()?
+        .response()
+        .ok()?;
+
+    let rgb = [color.red(), color.green(), color.blue()].map(|channel| channel as f32);
+
+    Some(Hsv::from_rgb(rgb))
+}
+
+#[cfg(not(target_os = "linux"))]
+async fn pick() -> Option<Hsv> {
+    None
+}
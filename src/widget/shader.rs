@@ -0,0 +1,261 @@
+//! Draw the saturation/value spectrum with a shader, rather than as thousands of blocks.
+
+use iced_core::Rectangle;
+use iced_graphics::geometry;
+
+use std::any::Any;
+
+/// A renderer the [ColorPicker](crate::ColorPicker) can be drawn with, which is any renderer
+/// of geometry.
+///
+/// With the `shader` feature, the saturation/value spectrum can also be drawn per pixel
+/// with a shader, see [ColorPicker::shader](crate::ColorPicker::shader).
+pub trait SpectrumRenderer: geometry::Renderer {}
+
+impl<Renderer: geometry::Renderer> SpectrumRenderer for Renderer {}
+
+/// Draws a saturation/value spectrum of the hue with the renderer, if it's of the right
+/// type, see `ColorPicker::shader`.
+pub(crate) type DrawShader = fn(&mut dyn Any, Rectangle, f32) -> bool;
+
+/// Draws a saturation/value spectrum of the hue within the bounds, returning false if the
+/// renderer isn't of the given type, so the spectrum has to be drawn as geometry.
+#[cfg(feature = "shader")]
+pub(crate) fn draw<Renderer>(renderer: &mut dyn Any, bounds: Rectangle, hue: f32) -> bool
+where
+    Renderer: iced_wgpu::primitive::Renderer + 'static,
+{
+    let Some(renderer) = renderer.downcast_mut::<Renderer>() else {
+        return false;
+    };
+
+    renderer.draw_primitive(bounds, pipeline::Primitive { hue });
+    true
+}
+
+#[cfg(feature = "shader")]
+mod pipeline {
+    use iced_core::Rectangle;
+    use iced_wgpu::graphics::Viewport;
+    use iced_wgpu::primitive;
+    use iced_wgpu::wgpu;
+
+    use std::borrow::Cow;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Bytes of the uniforms of a single spectrum.
+    const UNIFORMS_SIZE: u64 = 16;
+    /// Bytes between the uniforms of each spectrum, as dynamic offsets need to be aligned.
+    const SLOT_SIZE: u64 = 256;
+
+    /// A saturation/value spectrum of the hue.
+    #[derive(Debug)]
+    pub(super) struct Primitive {
+        pub(super) hue: f32,
+    }
+
+    impl primitive::Primitive for Primitive {
+        type Pipeline = Pipeline;
+
+        fn prepare(
+            &self,
+            pipeline: &mut Self::Pipeline,
+            device: &wgpu::Device,
+            queue: &wgpu::Queue,
+            bounds: &Rectangle,
+            viewport: &Viewport,
+        ) {
+            // Every spectrum is prepared before any is rendered, so a render means a new frame
+            if *pipeline.rendered.get_mut() > 0 {
+                pipeline.slots.clear();
+                *pipeline.rendered.get_mut() = 0;
+            }
+
+            pipeline
+                .slots
+                .push((*bounds * viewport.scale_factor(), self.hue));
+
+            if pipeline.slots.len() > pipeline.capacity {
+                pipeline.grow(device, pipeline.slots.len() * 2);
+
+                // The new buffer starts out empty, so the earlier spectra are written again
+                for index in 0..pipeline.slots.len() - 1 {
+                    pipeline.write(queue, index);
+                }
+            }
+
+            pipeline.write(queue, pipeline.slots.len() - 1);
+        }
+
+        fn render(
+            &self,
+            pipeline: &Self::Pipeline,
+            encoder: &mut wgpu::CommandEncoder,
+            target: &wgpu::TextureView,
+            clip_bounds: &Rectangle<u32>,
+        ) {
+            let index = pipeline.rendered.fetch_add(1, Ordering::Relaxed);
+            let Some((bounds, _)) = pipeline.slots.get(index) else {
+                return;
+            };
+
+            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("iced_color_picker spectrum"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: target,
+                    resolve_target: None,
+                    depth_slice: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Load,
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+
+            // The quad covers the viewport, so it's stretched over the bounds and clipped
+            pass.set_viewport(bounds.x, bounds.y, bounds.width, bounds.height, 0.0, 1.0);
+            pass.set_scissor_rect(
+                clip_bounds.x,
+                clip_bounds.y,
+                clip_bounds.width,
+                clip_bounds.height,
+            );
+            pass.set_pipeline(&pipeline.pipeline);
+            pass.set_bind_group(0, &pipeline.bind_group, &[index as u32 * SLOT_SIZE as u32]);
+            pass.draw(0..6, 0..1);
+        }
+    }
+
+    /// Draws the spectra, with the uniforms of each in a slot of a single buffer.
+    pub(super) struct Pipeline {
+        pipeline: wgpu::RenderPipeline,
+        layout: wgpu::BindGroupLayout,
+        buffer: wgpu::Buffer,
+        bind_group: wgpu::BindGroup,
+        capacity: usize,
+        /// The physical bounds and hue of the spectra of this frame, in the order they're rendered.
+        slots: Vec<(Rectangle, f32)>,
+        rendered: AtomicUsize,
+    }
+
+    impl primitive::Pipeline for Pipeline {
+        fn new(device: &wgpu::Device, _queue: &wgpu::Queue, format: wgpu::TextureFormat) -> Self {
+            let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: Some("iced_color_picker spectrum"),
+                source: wgpu::ShaderSource::Wgsl(Cow::Borrowed(include_str!("shader.wgsl"))),
+            });
+
+            let layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("iced_color_picker spectrum"),
+                entries: &[wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: true,
+                        min_binding_size: wgpu::BufferSize::new(UNIFORMS_SIZE),
+                    },
+                    count: None,
+                }],
+            });
+
+            let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("iced_color_picker spectrum"),
+                bind_group_layouts: &[&layout],
+                push_constant_ranges: &[],
+            });
+
+            // The shader computes sRGB, which has to be linearized for an sRGB target
+            let fragment = match format.is_srgb() {
+                true => "fs_linear",
+                false => "fs_srgb",
+            };
+
+            let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some("iced_color_picker spectrum"),
+                layout: Some(&pipeline_layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: Some("vs_main"),
+                    buffers: &[],
+                    compilation_options: Default::default(),
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: Some(fragment),
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format,
+                        blend: None,
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                    compilation_options: Default::default(),
+                }),
+                primitive: wgpu::PrimitiveState::default(),
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState::default(),
+                multiview: None,
+                cache: None,
+            });
+
+            let (buffer, bind_group) = buffer(device, &layout, 1);
+
+            Self {
+                pipeline,
+                layout,
+                buffer,
+                bind_group,
+                capacity: 1,
+                slots: Vec::new(),
+                rendered: AtomicUsize::new(0),
+            }
+        }
+    }
+
+    impl Pipeline {
+        /// Replaces the buffer with an empty one, holding the uniforms of `capacity` spectra.
+        fn grow(&mut self, device: &wgpu::Device, capacity: usize) {
+            (self.buffer, self.bind_group) = buffer(device, &self.layout, capacity);
+            self.capacity = capacity;
+        }
+
+        /// Writes the uniforms of the spectrum at the index to its slot in the buffer.
+        fn write(&self, queue: &wgpu::Queue, index: usize) {
+            let (_, hue) = self.slots[index];
+            let uniforms = [hue, 0.0, 0.0, 0.0].map(f32::to_le_bytes).concat();
+
+            queue.write_buffer(&self.buffer, index as u64 * SLOT_SIZE, &uniforms);
+        }
+    }
+
+    /// Creates a buffer of uniforms for `capacity` spectra, bound as the group of the pipeline.
+    fn buffer(
+        device: &wgpu::Device,
+        layout: &wgpu::BindGroupLayout,
+        capacity: usize,
+    ) -> (wgpu::Buffer, wgpu::BindGroup) {
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("iced_color_picker spectrum"),
+            size: capacity as u64 * SLOT_SIZE,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("iced_color_picker spectrum"),
+            layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: wgpu::BindingResource::Buffer(wgpu::BufferBinding {
+                    buffer: &buffer,
+                    offset: 0,
+                    size: wgpu::BufferSize::new(UNIFORMS_SIZE),
+                }),
+            }],
+        });
+
+        (buffer, bind_group)
+    }
+}
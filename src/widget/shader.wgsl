@@ -0,0 +1,55 @@
+// The saturation/value spectrum of a hue, with saturation along the x axis and value
+// along the y axis, matching `Spectrum::saturation_value`.
+
+struct Uniforms {
+    // The hue in degrees, in the first component.
+    hue: vec4<f32>,
+}
+
+@group(0) @binding(0) var<uniform> uniforms: Uniforms;
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+// Two triangles covering the viewport, which is set to the bounds of the spectrum.
+@vertex
+fn vs_main(@builtin(vertex_index) index: u32) -> VertexOutput {
+    var corners = array<vec2<f32>, 6>(
+        vec2(0.0, 0.0),
+        vec2(1.0, 0.0),
+        vec2(0.0, 1.0),
+        vec2(0.0, 1.0),
+        vec2(1.0, 0.0),
+        vec2(1.0, 1.0),
+    );
+
+    let uv = corners[index];
+
+    var out: VertexOutput;
+    out.position = vec4(uv.x * 2.0 - 1.0, 1.0 - uv.y * 2.0, 0.0, 1.0);
+    out.uv = uv;
+    return out;
+}
+
+fn hsv_to_rgb(h: f32, s: f32, v: f32) -> vec3<f32> {
+    let k = (vec3(5.0, 3.0, 1.0) + h / 60.0) % 6.0;
+    return v - v * s * clamp(min(k, 4.0 - k), vec3(0.0), vec3(1.0));
+}
+
+fn spectrum(uv: vec2<f32>) -> vec3<f32> {
+    return hsv_to_rgb(uniforms.hue.x, uv.x, 1.0 - uv.y);
+}
+
+@fragment
+fn fs_srgb(in: VertexOutput) -> @location(0) vec4<f32> {
+    return vec4(spectrum(in.uv), 1.0);
+}
+
+@fragment
+fn fs_linear(in: VertexOutput) -> @location(0) vec4<f32> {
+    let c = spectrum(in.uv);
+    let linear = select(pow((c + 0.055) / 1.055, vec3(2.4)), c / 12.92, c <= vec3(0.04045));
+    return vec4(linear, 1.0);
+}
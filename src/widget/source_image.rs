@@ -0,0 +1,71 @@
+//! Pick colors from an image instead of a generated spectrum.
+
+use super::Hsv;
+
+use iced_core::image::Handle;
+use iced_core::{Bytes, Point, Rectangle};
+
+/// An image to pick colors from, drawn stretched to the bounds of the [ColorPicker](crate::ColorPicker).
+///
+/// Colors are sampled from a buffer of RGBA pixels kept on the CPU, which may have a
+/// different resolution than the displayed image, as long as it has the same content.
+#[derive(Debug, Clone)]
+pub struct SourceImage {
+    handle: Handle,
+    width: u32,
+    height: u32,
+    pixels: Bytes,
+}
+
+impl SourceImage {
+    /// Creates a [SourceImage] that displays the image handle, and samples colors from
+    /// `width * height` RGBA pixels.
+    pub fn new(handle: Handle, width: u32, height: u32, pixels: impl Into<Bytes>) -> Self {
+        Self {
+            handle,
+            width,
+            height,
+            pixels: pixels.into(),
+        }
+    }
+
+    /// Creates a [SourceImage] from `width * height` RGBA pixels, used for both display and sampling.
+    pub fn from_rgba(width: u32, height: u32, pixels: impl Into<Bytes>) -> Self {
+        let pixels = pixels.into();
+
+        Self::new(
+            Handle::from_rgba(width, height, pixels.clone()),
+            width,
+            height,
+            pixels,
+        )
+    }
+
+    /// The handle of the displayed image.
+    pub fn handle(&self) -> &Handle {
+        &self.handle
+    }
+
+    /// Samples the color of the pixel under the point, with the image stretched to the bounds.
+    ///
+    /// Points outside of the bounds are clamped to the nearest edge. Returns `None` if
+    /// either the bounds or the image are empty, or the buffer is too small.
+    pub fn sample(&self, bounds: Rectangle, point: Point) -> Option<Hsv> {
+        if !(bounds.width > 0. && bounds.height > 0.) || self.width == 0 || self.height == 0 {
+            return None;
+        }
+
+        let pixel = |offset: f32, length: f32, pixels: u32| {
+            let percent = (offset / length).clamp(0., 1.);
+            ((percent * pixels as f32) as u32).min(pixels - 1) as usize
+        };
+
+        let x = pixel(point.x - bounds.x, bounds.width, self.width);
+        let y = pixel(point.y - bounds.y, bounds.height, self.height);
+
+        let index = (y * self.width as usize + x) * 4;
+        let rgba: [u8; 4] = self.pixels.get(index..index + 4)?.try_into().ok()?;
+
+        Some(Hsv::from_rgba8(rgba))
+    }
+}
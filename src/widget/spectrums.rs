@@ -1,15 +1,51 @@
 //! helper functions to draw different spectrums
 
-use super::{Hsv, hsv};
+use super::hsv::OutOfGamut;
+use super::srgb::{from_linear, to_linear};
+use super::temperature::{self, MAX_KELVIN, MIN_KELVIN};
+use super::{ColorBlindness, DisplayP3, Hsl, Hsv, Hwb, Lch, Oklch, hsv, lab, mask::Mask, oklch};
 
 use iced_core::{Color, Point, Rectangle, Size, Vector};
-use iced_graphics::geometry::{self, Frame};
+use iced_graphics::geometry::{self, Frame, gradient};
+
+use std::num::NonZeroUsize;
+use std::ops::RangeInclusive;
+
+/// Size of the blocks the spectrum is drawn with, unless the picker is given another.
+///
+/// Done for performance. Lower quantum = higher resolution.
+pub(crate) const QUANTIZATION: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+
+/// How many colors a spectrum with a single axis is sampled at, to draw gradients between.
+///
+/// Gradients are blended in linear light rather than sRGB, so the stops are kept close
+/// together to follow the spectrum closely.
+const GRADIENT_STOPS: usize = 64;
+
+/// Blocks between the stripes hatching the colors outside of sRGB, see [Spectrum::gamut_warning].
+const HATCH_SPACING: usize = 4;
+
+/// The stripes hatching the colors outside of sRGB, which stand out against any color.
+const HATCH_COLOR: Color = Color::from_rgba(0.5, 0.5, 0.5, 0.8);
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum HsvComponent {
     Hue,
     Saturation,
     Value,
+    /// Opacity, from transparent at the start of an axis to opaque at the end.
+    Alpha,
+    /// The red channel of the sRGB color, from none at the start of an axis to full at the end.
+    Red,
+    /// The green channel of the sRGB color, from none at the start of an axis to full at the end.
+    Green,
+    /// The blue channel of the sRGB color, from none at the start of an axis to full at the end.
+    Blue,
+    /// The temperature of a black body in Kelvin, from warm at the start of an axis to cool
+    /// at the end, giving the color of its light at the value of the color.
+    ///
+    /// See [color_temperature](super::temperature::color_temperature) for the colors.
+    Temperature,
 }
 
 impl HsvComponent {
@@ -20,6 +56,107 @@ impl HsvComponent {
             HsvComponent::Hue => hsv.h,
             HsvComponent::Saturation => hsv.s,
             HsvComponent::Value => hsv.v,
+            HsvComponent::Alpha => hsv.a,
+            HsvComponent::Red => Color::from(hsv).r,
+            HsvComponent::Green => Color::from(hsv).g,
+            HsvComponent::Blue => Color::from(hsv).b,
+            // The value doesn't change the temperature, but black has no channels to compare
+            HsvComponent::Temperature => {
+                temperature::temperature_of(Color::from(Hsv { v: 1., ..hsv }))
+            }
+        }
+    }
+
+    /// Returns true for the components set on the color itself rather than on the components
+    /// of a model, i.e. the channels of the sRGB color and the temperature.
+    fn ignores_model(&self) -> bool {
+        matches!(
+            self,
+            HsvComponent::Red
+                | HsvComponent::Green
+                | HsvComponent::Blue
+                | HsvComponent::Temperature
+        )
+    }
+
+    /// Sets the component of the color from its position along an axis, on `[0, 1]`.
+    fn set_from_percent(&self, hsv: &mut Hsv, percent: f32) {
+        // NOTE: while sat and val exist on bounds [0, 1], hue exists on [0, 360]
+        match self {
+            HsvComponent::Hue => hsv.h = percent * 360.,
+            HsvComponent::Saturation => hsv.s = percent,
+            HsvComponent::Value => hsv.v = 1. - percent,
+            HsvComponent::Alpha => hsv.a = percent,
+            HsvComponent::Red | HsvComponent::Green | HsvComponent::Blue => {
+                let mut rgb = Color::from(*hsv);
+
+                match self {
+                    HsvComponent::Red => rgb.r = percent,
+                    HsvComponent::Green => rgb.g = percent,
+                    _ => rgb.b = percent,
+                }
+
+                *hsv = Hsv::from_color_with_previous(rgb, *hsv);
+            }
+            HsvComponent::Temperature => {
+                let kelvin = MIN_KELVIN + percent * (MAX_KELVIN - MIN_KELVIN);
+                let Hsv { h, s, .. } = Hsv::from(temperature::color_temperature(kelvin));
+
+                (hsv.h, hsv.s) = (h, s);
+            }
+        }
+    }
+}
+
+/// The color model used by the saturation and value axes of a [Spectrum].
+///
+/// With [ColorModel::Hsv] a saturation/value square has a fully colored top edge,
+/// fading to black at the bottom. With [ColorModel::Hsl] the value axis becomes
+/// lightness, so the square is white at the top and black at the bottom, with the
+/// pure color halfway up.
+///
+/// With [ColorModel::Oklch] the hue, saturation and value axes become the hue, chroma
+/// and lightness of [Oklch]. These are perceptually uniform, so a row of constant
+/// lightness looks equally bright across its length. Chroma spans `[0, MAX_CHROMA]`
+/// (see [oklch::MAX_CHROMA]), and colors outside of the sRGB gamut are clamped.
+///
+/// [ColorModel::Lch] does the same with the hue, chroma and lightness of CIE [Lch],
+/// with chroma spanning `[0, MAX_CHROMA]` (see [lab::MAX_CHROMA]).
+///
+/// With [ColorModel::Hwb] the saturation axis becomes whiteness, while the value axis
+/// goes from no blackness at the top to full blackness at the bottom, as in CSS `hwb()`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ColorModel {
+    #[default]
+    Hsv,
+    Hsl,
+    Oklch,
+    Lch,
+    Hwb,
+}
+
+/// The gamut the channels of the colors of a [Spectrum] are in, see [Spectrum::gamut].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Gamut {
+    #[default]
+    Srgb,
+    /// The wider gamut of [DisplayP3], where the components of an [Hsv] are those of its
+    /// channels, see [DisplayP3::from_p3_hsv].
+    DisplayP3,
+}
+
+impl Gamut {
+    /// Converts a color with channels in the gamut to sRGB, failing if it has to be clamped.
+    pub fn to_srgb(self, color: Color) -> Result<Color, OutOfGamut> {
+        match self {
+            Gamut::Srgb => Ok(color),
+            Gamut::DisplayP3 => DisplayP3 {
+                r: color.r,
+                g: color.g,
+                b: color.b,
+                a: color.a,
+            }
+            .try_to_color(),
         }
     }
 }
@@ -28,49 +165,325 @@ impl HsvComponent {
 pub struct Spectrum {
     x_axis: Option<HsvComponent>,
     y_axis: Option<HsvComponent>,
+    model: ColorModel,
+    gamut: Gamut,
+    linear_light: bool,
+    inclusive_hue: bool,
+    keep_alpha: bool,
+    gamut_warning: bool,
+    neutral: bool,
+    /// The part of the x axis the spectrum spans, as positions along it on `[0, 1]`.
+    x_span: (f32, f32),
+    /// The part of the y axis the spectrum spans, as positions along it on `[0, 1]`.
+    y_span: (f32, f32),
+    x_inverted: bool,
+    y_inverted: bool,
+    /// The part of the x axis shown, as positions along it on `[0, 1]`.
+    x_zoom: (f32, f32),
+    /// The part of the y axis shown, as positions along it on `[0, 1]`.
+    y_zoom: (f32, f32),
+    /// The deficiency the spectrum is drawn as it appears with.
+    color_blindness: Option<ColorBlindness>,
 }
 
 impl Default for Spectrum {
     fn default() -> Self {
-        Spectrum {
-            x_axis: Some(HsvComponent::Hue),
-            y_axis: Some(HsvComponent::Value),
-        }
+        Spectrum::new(Some(HsvComponent::Hue), Some(HsvComponent::Value))
     }
 }
 
 impl Spectrum {
     //          [[ Initializing functions ]]
-    pub fn new_vertical(comp: HsvComponent) -> Self {
+    fn new(x_axis: Option<HsvComponent>, y_axis: Option<HsvComponent>) -> Self {
         Spectrum {
-            x_axis: None,
-            y_axis: Some(comp),
+            x_axis,
+            y_axis,
+            model: ColorModel::default(),
+            gamut: Gamut::default(),
+            linear_light: false,
+            inclusive_hue: false,
+            keep_alpha: false,
+            gamut_warning: false,
+            neutral: false,
+            x_span: (0., 1.),
+            y_span: (0., 1.),
+            x_inverted: false,
+            y_inverted: false,
+            x_zoom: (0., 1.),
+            y_zoom: (0., 1.),
+            color_blindness: None,
         }
     }
+    pub fn new_vertical(comp: HsvComponent) -> Self {
+        Spectrum::new(None, Some(comp))
+    }
     pub fn new_horizontal(comp: HsvComponent) -> Self {
-        Spectrum {
-            x_axis: Some(comp),
-            y_axis: None,
-        }
+        Spectrum::new(Some(comp), None)
     }
     pub fn new_matrix(x_comp: HsvComponent, y_comp: HsvComponent) -> Self {
-        Spectrum {
-            x_axis: Some(x_comp),
-            y_axis: Some(y_comp),
-        }
+        Spectrum::new(Some(x_comp), Some(y_comp))
+    }
+
+    /// A square with saturation on the x axis and value on the y axis.
+    ///
+    /// Replaces the old `Spectrum::SaturationValue` variant.
+    ///
+    /// ```
+    /// use iced_color_picker::{HsvComponent, Spectrum};
+    ///
+    /// // Previously `Spectrum::SaturationValue`
+    /// let spectrum = Spectrum::saturation_value();
+    ///
+    /// assert_eq!(spectrum.x_axis(), Some(HsvComponent::Saturation));
+    /// assert_eq!(spectrum.y_axis(), Some(HsvComponent::Value));
+    /// ```
+    pub fn saturation_value() -> Self {
+        Spectrum::new_matrix(HsvComponent::Saturation, HsvComponent::Value)
+    }
+    /// A square with whiteness on the x axis and blackness on the y axis, using [ColorModel::Hwb].
+    ///
+    /// Pair it with [Spectrum::hue_vertical] for the layout of an HWB picker.
+    pub fn whiteness_blackness() -> Self {
+        Spectrum::saturation_value().model(ColorModel::Hwb)
+    }
+    /// A vertical hue strip.
+    ///
+    /// Replaces the old `Spectrum::HueVertical` variant.
+    ///
+    /// ```
+    /// use iced_color_picker::{HsvComponent, Spectrum};
+    ///
+    /// // Previously `Spectrum::HueVertical`
+    /// let spectrum = Spectrum::hue_vertical();
+    ///
+    /// assert_eq!(spectrum.x_axis(), None);
+    /// assert_eq!(spectrum.y_axis(), Some(HsvComponent::Hue));
+    /// ```
+    pub fn hue_vertical() -> Self {
+        Spectrum::new_vertical(HsvComponent::Hue)
+    }
+    /// A horizontal hue strip.
+    ///
+    /// Replaces the old `Spectrum::HueHorizontal` variant.
+    ///
+    /// ```
+    /// use iced_color_picker::{HsvComponent, Spectrum};
+    ///
+    /// // Previously `Spectrum::HueHorizontal`
+    /// let spectrum = Spectrum::hue_horizontal();
+    ///
+    /// assert_eq!(spectrum.x_axis(), Some(HsvComponent::Hue));
+    /// assert_eq!(spectrum.y_axis(), None);
+    /// ```
+    pub fn hue_horizontal() -> Self {
+        Spectrum::new_horizontal(HsvComponent::Hue)
+    }
+
+    /// A horizontal strip of black-body temperatures, from warm to cool, see
+    /// [HsvComponent::Temperature].
+    pub fn temperature() -> Self {
+        Spectrum::new_horizontal(HsvComponent::Temperature)
+    }
+
+    /// A horizontal ramp of grays, from black to white, see [Spectrum::neutral].
+    ///
+    /// Use [ColorModel::Lch] to step through grays by CIE lightness (L*) instead of value.
+    pub fn grayscale() -> Self {
+        Spectrum::new_horizontal(HsvComponent::Value)
+            .invert_x(true)
+            .neutral(true)
     }
 
+    #[deprecated(note = "use `Spectrum::saturation_value` instead")]
     pub fn get_saturation_value() -> Self {
-        Spectrum {
-            x_axis: Some(HsvComponent::Saturation),
-            y_axis: Some(HsvComponent::Value),
-        }
+        Spectrum::saturation_value()
     }
+    #[deprecated(note = "use `Spectrum::hue_vertical` instead")]
     pub fn get_hue_vertical() -> Self {
-        Spectrum::new_vertical(HsvComponent::Hue)
+        Spectrum::hue_vertical()
     }
+    #[deprecated(note = "use `Spectrum::hue_horizontal` instead")]
     pub fn get_hue_horizontal() -> Self {
-        Spectrum::new_horizontal(HsvComponent::Hue)
+        Spectrum::hue_horizontal()
+    }
+
+    /// Change the [ColorModel] of the saturation and value axes.
+    ///
+    /// Rendering, picking and the marker position all follow the chosen model.
+    pub fn model(mut self, model: ColorModel) -> Self {
+        self.model = model;
+        self
+    }
+
+    /// Interpret the colors of the spectrum as having channels in the [Gamut], so colors in
+    /// it are picked and given, e.g. the HSV components of [DisplayP3] colors.
+    ///
+    /// The spectrum is still drawn in sRGB, clamping what falls outside of it, which
+    /// [Spectrum::gamut_warning] hatches.
+    pub fn gamut(mut self, gamut: Gamut) -> Self {
+        self.gamut = gamut;
+        self
+    }
+
+    /// Interpolate along the axes in linear light rather than in sRGB, so blends between
+    /// hues keep their brightness instead of darkening midway, as they do in the legacy look.
+    ///
+    /// Picking follows, so each position still gives the color drawn there. Has no effect
+    /// with [ColorModel::Oklch] and [ColorModel::Lch], which are perceptually uniform already.
+    pub fn linear_light(mut self, linear: bool) -> Self {
+        self.linear_light = linear;
+        self
+    }
+
+    /// Render the hue axis with an inclusive endpoint.
+    ///
+    /// By default the hue axis is sampled on `[0, 360)`, so the strip ends just short of red.
+    /// When inclusive, the strip ends at 360, which is the same red as it starts with,
+    /// letting it loop seamlessly when tiled or placed next to a wheel.
+    /// This only affects rendering, picking is unchanged.
+    pub fn inclusive_hue(mut self, inclusive: bool) -> Self {
+        self.inclusive_hue = inclusive;
+        self
+    }
+
+    /// Limit a component to part of its range, e.g. `0.2..=0.9` of saturation,
+    /// `180.0..=240.0` of hue in degrees, or `2700.0..=6500.0` of temperature in Kelvin.
+    ///
+    /// The axis bound to the component then spans only that range, so both the spectrum
+    /// and the colors picked along it stay inside of it. Components that aren't bound to
+    /// an axis are left as they are.
+    pub fn clamp(mut self, comp: HsvComponent, range: RangeInclusive<f32>) -> Self {
+        let (offset, scale) = match comp {
+            HsvComponent::Hue => (0., 360.),
+            HsvComponent::Temperature => (MIN_KELVIN, MAX_KELVIN - MIN_KELVIN),
+            _ => (0., 1.),
+        };
+        let start = ((range.start() - offset) / scale).clamp(0., 1.);
+        let end = ((range.end() - offset) / scale).clamp(0., 1.);
+
+        // Value runs from the top of its axis downwards, and so does its range
+        let span = match comp {
+            HsvComponent::Value => (1. - end, 1. - start),
+            _ => (start, end),
+        };
+
+        if self.x_axis == Some(comp) {
+            self.x_span = span;
+        }
+        if self.y_axis == Some(comp) {
+            self.y_span = span;
+        }
+        self
+    }
+
+    /// Flip the direction of the x axis, e.g. to run hue from right to left.
+    ///
+    /// Rendering, picking and the marker position all follow the flipped direction.
+    pub fn invert_x(mut self, invert: bool) -> Self {
+        self.x_inverted = invert;
+        self
+    }
+
+    /// Flip the direction of the y axis, e.g. to have value increase downwards.
+    ///
+    /// Rendering, picking and the marker position all follow the flipped direction.
+    pub fn invert_y(mut self, invert: bool) -> Self {
+        self.y_inverted = invert;
+        self
+    }
+
+    /// Render the spectrum with the alpha of the color, rather than opaque.
+    ///
+    /// The spectrum then shows what the picked colors look like once composited, over a
+    /// checkerboard in a [ColorPicker](crate::ColorPicker).
+    pub fn keep_alpha(mut self, keep: bool) -> Self {
+        self.keep_alpha = keep;
+        self
+    }
+
+    /// Hatch the parts of an [Oklch](ColorModel::Oklch) or [Lch](ColorModel::Lch) spectrum,
+    /// or of one in a wider [Gamut], that fall outside of sRGB, rather than silently showing
+    /// them clamped.
+    ///
+    /// Colors picked there are clamped into sRGB, see [Spectrum::fetch_in_gamut].
+    pub fn gamut_warning(mut self, warn: bool) -> Self {
+        self.gamut_warning = warn;
+        self
+    }
+
+    /// Drop the chroma of every color of the spectrum, so it only shows and picks grays, e.g.
+    /// for a picker constrained to neutral tones.
+    ///
+    /// Saturation, or the chroma or whiteness of the [ColorModel], is then fixed.
+    pub fn neutral(mut self, neutral: bool) -> Self {
+        self.neutral = neutral;
+        self
+    }
+
+    /// The component bound to the x axis, if any.
+    pub fn x_axis(&self) -> Option<HsvComponent> {
+        self.x_axis
+    }
+
+    /// The component bound to the y axis, if any.
+    pub fn y_axis(&self) -> Option<HsvComponent> {
+        self.y_axis
+    }
+
+    /// Shows only part of each axis, as positions along them on `[0, 1]` in the direction
+    /// they're drawn, as a [ColorPicker](crate::ColorPicker) does while zoomed in.
+    pub(crate) fn zoomed(mut self, x: (f32, f32), y: (f32, f32)) -> Self {
+        self.x_zoom = x;
+        self.y_zoom = y;
+        self
+    }
+
+    /// Renders the spectrum as it appears with the color vision deficiency, if there is one.
+    pub(crate) fn simulated(mut self, color_blindness: Option<ColorBlindness>) -> Self {
+        self.color_blindness = color_blindness;
+        self
+    }
+
+    /// The component of a spectrum with a single axis, and its value at a position along it.
+    pub(crate) fn axis_value(&self, mut color: Hsv, percent: f32) -> Option<(HsvComponent, f32)> {
+        let axis = match (self.x_axis, self.y_axis) {
+            (Some(axis), None) | (None, Some(axis)) => axis,
+            _ => return None,
+        };
+
+        self.modify_hsv(percent, percent, &mut color);
+        Some((axis, self.get_component(axis, color)))
+    }
+
+    /// The components bound to the axes, x first, each with its values at the start and the
+    /// end of its axis for the color.
+    pub(crate) fn axis_ranges(&self, color: Hsv) -> Vec<(HsvComponent, f32, f32)> {
+        let (mut start, mut end) = (color, color);
+        self.modify_hsv(0., 0., &mut start);
+        self.modify_hsv(1., 1., &mut end);
+
+        [self.x_axis, self.y_axis]
+            .into_iter()
+            .flatten()
+            .map(|axis| {
+                (
+                    axis,
+                    self.get_component(axis, start),
+                    self.get_component(axis, end),
+                )
+            })
+            .collect()
+    }
+
+    /// Returns true if the spectrum of the color has translucent parts, as it does with an alpha
+    /// axis, or when keeping the alpha of a translucent color.
+    pub fn is_translucent(&self, color: Hsv) -> bool {
+        self.has_axis(HsvComponent::Alpha) || (self.keep_alpha && color.a < 1.0)
+    }
+
+    /// Returns true if the component is bound to either axis.
+    pub fn has_axis(&self, comp: HsvComponent) -> bool {
+        self.x_axis == Some(comp) || self.y_axis == Some(comp)
     }
 
     //          [[ External Rendering Based Functions ]]
@@ -85,90 +498,420 @@ impl Spectrum {
         frame: &mut Frame<Renderer>,
         color: &Hsv,
     ) {
-        let cols = frame.width() as usize;
-        let rows = frame.height() as usize;
+        self.render_masked(frame, color, QUANTIZATION, None);
+    }
 
-        let (mut h, mut s, mut v) = (color.h, color.s, color.v);
+    /// Renders the spectrum into a region of the frame.
+    ///
+    /// Useful to compose a spectrum with other drawings inside of a canvas.
+    /// The spectrum is drawn in blocks of `quantization` pixels, where a lower
+    /// quantization gives a higher resolution at the cost of performance.
+    ///
+    /// A spectrum with a single axis is drawn with gradients instead, ignoring the quantization.
+    pub fn render_into<Renderer: geometry::Renderer>(
+        &self,
+        frame: &mut Frame<Renderer>,
+        bounds: Rectangle,
+        color: &Hsv,
+        quantization: NonZeroUsize,
+    ) {
+        self.render(frame, bounds, color, quantization, None);
+    }
+
+    /// Renders the spectrum to the whole frame, skipping any block that falls outside of the mask.
+    pub(crate) fn render_masked<Renderer: geometry::Renderer>(
+        &self,
+        frame: &mut Frame<Renderer>,
+        color: &Hsv,
+        quantization: NonZeroUsize,
+        mask: Option<&Mask>,
+    ) {
+        let bounds = Rectangle::with_size(frame.size());
+        self.render(frame, bounds, color, quantization, mask);
+    }
+
+    fn render<Renderer: geometry::Renderer>(
+        &self,
+        frame: &mut Frame<Renderer>,
+        bounds: Rectangle,
+        color: &Hsv,
+        quantization: NonZeroUsize,
+        mask: Option<&Mask>,
+    ) {
+        // The spectrum itself is drawn opaque, unless alpha is bound to an axis or kept
+        let mut color = match self.keep_alpha {
+            true => *color,
+            false => hsv(color.h, color.s, color.v),
+        };
 
         // If we only have a single hue axis, set saturation and value to 1
-        self.singular_hue_colour_change(&mut s, &mut v);
+        self.singular_hue_colour_change(&mut color.s, &mut color.v);
+
+        // Masks and hatches are applied per block, so only an unmasked slider can use gradients
+        let hatched = self.gamut_warning
+            && (matches!(self.model, ColorModel::Oklch | ColorModel::Lch)
+                || self.gamut != Gamut::Srgb);
+        if mask.is_none() && !hatched && self.x_axis.is_none() != self.y_axis.is_none() {
+            self.render_gradient(frame, bounds, color);
+            return;
+        }
+
+        for (block, fill) in self.blocks(bounds, color, quantization, mask, hatched) {
+            frame.fill_rectangle(block.position(), block.size(), fill);
+        }
+    }
+
+    /// The blocks of `quantization` pixels the spectrum is drawn as within the bounds, each
+    /// with its fill, skipping any block that falls outside of the mask.
+    fn blocks<'a>(
+        &'a self,
+        bounds: Rectangle,
+        color: Hsv,
+        quantization: NonZeroUsize,
+        mask: Option<&'a Mask>,
+        hatched: bool,
+    ) -> impl Iterator<Item = (Rectangle, Color)> + 'a {
+        let cols = bounds.width.max(0.0) as usize;
+        let rows = bounds.height.max(0.0) as usize;
 
-        // Done for performance. Lower quantum = higher resolution. Hard coded for now.
-        use std::num::NonZeroUsize;
-        const QUANTIZATION: NonZeroUsize = NonZeroUsize::new(2).unwrap();
-        let quantization = QUANTIZATION.get() as f32;
+        let (n_cols, n_rows) = (cols / quantization.get(), rows / quantization.get());
+        let quantization = quantization.get() as f32;
 
-        for col in 0..(cols / quantization as usize) {
-            for row in 0..(rows / quantization as usize) {
+        (0..n_cols)
+            .flat_map(move |col| (0..n_rows).map(move |row| (col, row)))
+            .filter_map(move |(col, row)| {
+                // Position relative to the region being drawn into
                 let c = col as f32 * quantization;
                 let r = row as f32 * quantization;
 
-                let col_percent = c / frame.width();
-                let row_percent = r / frame.height();
+                let center = Point::new(c + quantization / 2.0, r + quantization / 2.0);
+                if mask.is_some_and(|mask| !mask.contains(center)) {
+                    return None;
+                }
 
-                // Change the existing mutable values.
-                // Seemed like the simpelest way to keep non-changing values untouched
-                self.modify_hsv(col_percent, row_percent, &mut h, &mut s, &mut v);
+                let col_percent = self.block_percent(self.x_axis, col, n_cols, c / bounds.width);
+                let row_percent = self.block_percent(self.y_axis, row, n_rows, r / bounds.height);
 
-                frame.fill_rectangle(
-                    Point::new(c, r),
-                    Size::new(quantization, quantization),
-                    Color::from(hsv(h, s, v)),
-                );
-            }
+                // Start from the base color for every block, so conversions between
+                // color models don't accumulate from one block to the next
+                let mut block = color;
+                let in_gamut = self.modify_hsv(col_percent, row_percent, &mut block)
+                    && self.gamut.to_srgb(Color::from(block)).is_ok();
+
+                let fill = match hatched && !in_gamut && (col + row) % HATCH_SPACING == 0 {
+                    true => HATCH_COLOR,
+                    false => self.display(block),
+                };
+
+                Some((
+                    Rectangle::new(
+                        Point::new(bounds.x + c, bounds.y + r),
+                        Size::new(quantization, quantization),
+                    ),
+                    fill,
+                ))
+            })
+    }
+
+    /// Draws a spectrum with a single axis as a few rectangles filled with gradients, which
+    /// is far cheaper to tessellate than blocks and doesn't band.
+    fn render_gradient<Renderer: geometry::Renderer>(
+        &self,
+        frame: &mut Frame<Renderer>,
+        bounds: Rectangle,
+        color: Hsv,
+    ) {
+        // A gradient holds at most 8 stops, so each rectangle covers 7 intervals
+        const INTERVALS_PER_GRADIENT: usize = 7;
+
+        let intervals = GRADIENT_STOPS - 1;
+        let vertical = self.x_axis.is_none();
+        let length = match vertical {
+            true => bounds.height,
+            false => bounds.width,
+        };
+
+        // The hue axis stops a pixel short of 360 unless inclusive, so it never wraps back to red
+        let extent = match self.x_axis.or(self.y_axis) {
+            Some(HsvComponent::Hue) if !self.inclusive_hue => 1. - 1. / length.max(1.),
+            _ => 1.,
+        };
+
+        // A point along the axis, offset from the start by a fraction of its length
+        let along = |percent: f32| match vertical {
+            true => Point::new(bounds.x, bounds.y + percent * length),
+            false => Point::new(bounds.x + percent * length, bounds.y),
+        };
+
+        for start in (0..intervals).step_by(INTERVALS_PER_GRADIENT) {
+            let end = (start + INTERVALS_PER_GRADIENT).min(intervals);
+            let (from, to) = (
+                start as f32 / intervals as f32,
+                end as f32 / intervals as f32,
+            );
+
+            let gradient = (start..=end).fold(
+                gradient::Linear::new(along(from), along(to)),
+                |gradient, stop| {
+                    let percent = stop as f32 / intervals as f32 * extent;
+
+                    let mut stop_color = color;
+                    self.modify_hsv(percent, percent, &mut stop_color);
+
+                    let offset = (stop - start) as f32 / (end - start) as f32;
+                    gradient.add_stop(offset, self.display(stop_color))
+                },
+            );
+
+            let size = match vertical {
+                true => Size::new(bounds.width, (to - from) * length),
+                false => Size::new((to - from) * length, bounds.height),
+            };
+
+            frame.fill_rectangle(along(from), size, gradient);
+        }
+    }
+
+    /// Returns true for the saturation/value square of [ColorModel::Hsv], the one drawn with a shader.
+    pub(crate) fn is_saturation_value(&self) -> bool {
+        self.x_axis == Some(HsvComponent::Saturation)
+            && self.y_axis == Some(HsvComponent::Value)
+            && self.model == ColorModel::Hsv
+            && (self.x_span, self.y_span) == ((0., 1.), (0., 1.))
+            && !self.x_inverted
+            && !self.y_inverted
+            && (self.x_zoom, self.y_zoom) == ((0., 1.), (0., 1.))
+            && self.color_blindness.is_none()
+            && !self.neutral
+            && self.gamut == Gamut::Srgb
+            && !self.linear_light
+    }
+
+    /// The color a block of the spectrum is filled with.
+    fn display(&self, color: Hsv) -> Color {
+        let color = self.clamp_to_srgb(Color::from(color));
+
+        match self.color_blindness {
+            Some(color_blindness) => color_blindness.simulate(color),
+            None => color,
         }
     }
 
+    /// Converts a color with channels in the [Gamut] of the spectrum to sRGB, clamped.
+    pub(crate) fn clamp_to_srgb(&self, color: Color) -> Color {
+        self.gamut
+            .to_srgb(color)
+            .unwrap_or_else(|out_of_gamut| out_of_gamut.clamped)
+    }
+
     /// Provides the correct position for the marker, taking into account potential
     /// None axis
     pub fn get_marker_pos(&self, color: Hsv, bounds: Size) -> Point {
-        // Note: Hue, saturation and value all need to be handled differently due
+        // Note: Hue, saturation, value and alpha all need to be handled differently due
         // to the way they are drawn.
         let x_percent = match self.x_axis {
             None => 1. / 2.,
             Some(comp) => {
-                let hsv_val = comp.get_hsv_component(color);
+                let hsv_val = self.get_component(comp, color);
                 match comp {
                     HsvComponent::Hue => hsv_val / 360.,
                     HsvComponent::Saturation => hsv_val,
                     HsvComponent::Value => 1. - hsv_val,
+                    HsvComponent::Alpha
+                    | HsvComponent::Red
+                    | HsvComponent::Green
+                    | HsvComponent::Blue => hsv_val,
+                    HsvComponent::Temperature => (hsv_val - MIN_KELVIN) / (MAX_KELVIN - MIN_KELVIN),
                 }
             }
         };
         let y_percent = match self.y_axis {
             None => 1. / 2.,
             Some(comp) => {
-                let hsv_val = comp.get_hsv_component(color);
+                let hsv_val = self.get_component(comp, color);
                 match comp {
                     HsvComponent::Hue => hsv_val / 360.,
                     HsvComponent::Saturation => hsv_val,
                     HsvComponent::Value => 1. - hsv_val,
+                    HsvComponent::Alpha
+                    | HsvComponent::Red
+                    | HsvComponent::Green
+                    | HsvComponent::Blue => hsv_val,
+                    HsvComponent::Temperature => (hsv_val - MIN_KELVIN) / (MAX_KELVIN - MIN_KELVIN),
                 }
             }
         };
 
         Point {
-            x: x_percent * bounds.width,
-            y: y_percent * bounds.height,
+            x: from_span(
+                self.x_zoom,
+                invert(self.x_inverted, from_span(self.x_span, x_percent)),
+            ) * bounds.width,
+            y: from_span(
+                self.y_zoom,
+                invert(self.y_inverted, from_span(self.y_span, y_percent)),
+            ) * bounds.height,
         }
     }
 
+    /// Returns true if the spectrum looks different for the new color, which is only the case
+    /// when a component that isn't bound to an axis changes.
+    ///
+    /// Moving along the axes only moves the marker, and a lone hue axis is always drawn at
+    /// full saturation and value. The spectrum is drawn opaque unless alpha is bound to an
+    /// axis, so alpha only matters when kept, see [Spectrum::keep_alpha].
     pub fn requires_redraw(&self, old_color: &Hsv, new_color: &Hsv) -> bool {
-        if let Some(x_ax) = self.x_axis {
-            if x_ax.get_hsv_component(*old_color) != x_ax.get_hsv_component(*new_color) {
-                return true;
-            };
+        use HsvComponent::{Alpha, Blue, Green, Hue, Red, Saturation, Value};
+
+        if self.keep_alpha && !self.has_axis(Alpha) && old_color.a != new_color.a {
+            return true;
+        }
+
+        let lone_axis = self.x_axis.is_none() || self.y_axis.is_none();
+        if lone_axis && (self.has_axis(Hue) || self.has_axis(HsvComponent::Temperature)) {
+            return false;
+        }
+
+        // The sRGB channels are set on top of the model, leaving the other channels as they were
+        let components = match [Red, Green, Blue]
+            .into_iter()
+            .any(|comp| self.has_axis(comp))
+        {
+            true => [Red, Green, Blue],
+            false => [Hue, Saturation, Value],
         };
-        if let Some(y_ax) = self.y_axis {
-            if y_ax.get_hsv_component(*old_color) != y_ax.get_hsv_component(*new_color) {
-                return true;
-            };
+
+        // Neither hue nor saturation shows in grays
+        components
+            .into_iter()
+            .filter(|&comp| !self.has_axis(comp))
+            .filter(|&comp| !(self.neutral && matches!(comp, Hue | Saturation)))
+            .any(|comp| {
+                self.get_component(comp, *old_color) != self.get_component(comp, *new_color)
+            })
+    }
+
+    /// Returns `count` evenly spaced color stops along the spectrum, as positions in `[0, 1]`
+    /// paired with the color at that position.
+    ///
+    /// There are always at least two stops, one at each end. Components not bound to an axis
+    /// are taken from `color`. For a 2-D spectrum the stops are sampled along the diagonal,
+    /// from the top left to the bottom right corner.
+    pub fn color_stops(&self, mut color: Hsv, count: usize) -> Vec<(f32, Hsv)> {
+        self.singular_hue_colour_change(&mut color.s, &mut color.v);
+
+        let count = count.max(2);
+
+        (0..count)
+            .map(|i| {
+                let position = i as f32 / (count - 1) as f32;
+
+                let mut color = color;
+                self.modify_hsv(position, position, &mut color);
+                (position, color)
+            })
+            .collect()
+    }
+
+    /// Returns a CSS `linear-gradient(...)` matching the spectrum, built from [Spectrum::color_stops].
+    pub fn to_css_gradient_string(&self, color: Hsv, count: usize) -> String {
+        let direction = match (self.x_axis, self.y_axis) {
+            (Some(_), Some(_)) => "to bottom right",
+            (None, Some(_)) => "to bottom",
+            _ => "to right",
         };
-        return false;
+
+        let stops = self
+            .color_stops(color, count)
+            .into_iter()
+            .map(|(position, color)| {
+                let [r, g, b, _] = color.to_rgba8();
+                let a = Color::from(color).a;
+                format!("rgba({r}, {g}, {b}, {a:.3}) {:.2}%", position * 100.)
+            })
+            .collect::<Vec<_>>();
+
+        format!("linear-gradient({direction}, {})", stops.join(", "))
     }
 
-    /// Gives the HSV color of the spectrum, at a given cursor position
-    pub fn fetch_hsv(&self, color: hsv::Hsv, bounds: Rectangle, cursor: Point) -> hsv::Hsv {
+    /// Picks the color at a point within the bounds, exactly as the [ColorPicker](crate::ColorPicker) does.
+    ///
+    /// This doesn't need a renderer or event loop, making it the entry point for testing
+    /// code built around the widget. Components not bound to an axis are taken from `color`,
+    /// and the far end of a hue axis gives a hue just under 360.
+    ///
+    /// ```
+    /// use iced_color_picker::{Hsv, Spectrum};
+    /// use iced_core::{Point, Rectangle, Size};
+    ///
+    /// let bounds = Rectangle::new(Point::ORIGIN, Size::new(100.0, 100.0));
+    /// let spectrum = Spectrum::saturation_value();
+    ///
+    /// let center = spectrum.pick_at(Hsv::default(), bounds, Point::new(50.0, 50.0));
+    /// assert_eq!((center.s, center.v), (0.5, 0.5));
+    ///
+    /// let top_right = spectrum.pick_at(Hsv::default(), bounds, Point::new(100.0, 0.0));
+    /// assert_eq!((top_right.s, top_right.v), (1.0, 1.0));
+    ///
+    /// // Halfway down a vertical hue strip
+    /// let middle = Spectrum::hue_vertical().pick_at(Hsv::default(), bounds, Point::new(50.0, 50.0));
+    /// assert_eq!(middle.h, 180.0);
+    /// ```
+    pub fn pick_at(&self, color: Hsv, bounds: Rectangle, point: Point) -> Hsv {
+        self.fetch_hsv(color, bounds, point)
+    }
+
+    /// Moves the color along the axes by fractions of their length, the way the marker would move.
+    ///
+    /// Positive steps go right and down. Steps along a missing axis are ignored.
+    pub fn step(&self, color: Hsv, dx: f32, dy: f32) -> Hsv {
+        let unit = Size::new(1., 1.);
+        let Point { x, y } = self.get_marker_pos(color, unit);
+
+        self.fetch_hsv(
+            color,
+            Rectangle::with_size(unit),
+            Point::new(x + dx, y + dy),
+        )
+    }
+
+    /// Moves the color along a 1-D spectrum by a fraction of its axis, where positive
+    /// amounts increase the component bound to it.
+    ///
+    /// Returns `None` for a spectrum that doesn't have exactly one axis.
+    pub fn step_component(&self, color: Hsv, amount: f32) -> Option<Hsv> {
+        let (axis, vertical) = match (self.x_axis, self.y_axis) {
+            (Some(axis), None) => (axis, false),
+            (None, Some(axis)) => (axis, true),
+            _ => return None,
+        };
+
+        // Value is the one component that decreases along its axis, unless it's inverted
+        let inverted = match vertical {
+            true => self.y_inverted,
+            false => self.x_inverted,
+        };
+        let amount = match (axis == HsvComponent::Value) != inverted {
+            true => -amount,
+            false => amount,
+        };
+
+        Some(match vertical {
+            true => self.step(color, 0., amount),
+            false => self.step(color, amount, 0.),
+        })
+    }
+
+    /// Gives the relative position of the cursor within the bounds, as the `(x, y)` fractions
+    /// bound on `[0, 1]` that [Spectrum::fetch_hsv] picks the color with.
+    ///
+    /// Returns `None` if the bounds have no area.
+    pub fn fetch_percent(&self, bounds: Rectangle, cursor: Point) -> Option<(f32, f32)> {
+        // Avoid dividing by zero during transient layouts
+        if !(bounds.width > 0. && bounds.height > 0.) {
+            return None;
+        }
+
         // Get the relative x and y position in our spectrum
         let Vector { x, y } = cursor - bounds.position();
 
@@ -176,56 +919,533 @@ impl Spectrum {
         let col_percent = (x.max(0.) / bounds.width).min(1.);
         let row_percent = (y.max(0.) / bounds.height).min(1.);
 
-        // Get current colour
-        let hsv::Hsv {
-            mut h,
-            mut s,
-            mut v,
-            a,
-        } = color;
+        Some((col_percent, row_percent))
+    }
+
+    /// Gives the HSV color of the spectrum, at a given cursor position
+    ///
+    /// If the bounds have no area, the color is returned unchanged. The hue of the
+    /// result is always on `[0, 360)`.
+    pub fn fetch_hsv(&self, color: hsv::Hsv, bounds: Rectangle, cursor: Point) -> hsv::Hsv {
+        let Some((col_percent, row_percent)) = self.fetch_percent(bounds, cursor) else {
+            return color;
+        };
+
+        // Get actual color, any component may change, including alpha
+        let mut color = color;
+        self.modify_hsv(col_percent, row_percent, &mut color);
+
+        let hsv::Hsv { h, s, v, a } = color;
+        hsv::Hsv {
+            // Hue is kept on [0, 360), so the far edge of a hue axis lands just under 360
+            // rather than wrapping around to 0 and sending the marker to the opposite end.
+            h: h.clamp(0., 360f32.next_down()),
+            s: s.clamp(0., 1.),
+            v: v.clamp(0., 1.),
+            a: a.clamp(0., 1.),
+        }
+    }
+
+    /// Returns false if the color under the cursor falls outside of sRGB in the
+    /// [ColorModel] of the spectrum, so that picking it clamps it, see [Spectrum::fetch_hsv].
+    ///
+    /// In a wider [Gamut] the color is picked as is, but can't be shown as it is.
+    pub fn fetch_in_gamut(&self, color: Hsv, bounds: Rectangle, cursor: Point) -> bool {
+        let Some((col_percent, row_percent)) = self.fetch_percent(bounds, cursor) else {
+            return true;
+        };
 
-        // Get actual color
-        self.modify_hsv(col_percent, row_percent, &mut h, &mut s, &mut v);
-        hsv::Hsv { h, s, v, a }
+        let mut color = color;
+        self.modify_hsv(col_percent, row_percent, &mut color)
+            && self.gamut.to_srgb(Color::from(color)).is_ok()
     }
 
     //          [[ Internal Helper Functions ]]
 
-    /// Helper function to set a set of hsv values to the correct colour for a specific
-    /// position on the spectrum
-    fn modify_hsv(
-        &self,
-        col_percent: f32,
-        row_percent: f32,
-        h: &mut f32,
-        s: &mut f32,
-        v: &mut f32,
-    ) {
-        // NOTE: while sat and val exist on bounds [0, 1], hue exists on [0, 360]
-        if let Some(x_axis) = self.x_axis {
-            match x_axis {
-                HsvComponent::Hue => *h = col_percent * 360.,
-                HsvComponent::Saturation => *s = col_percent,
-                HsvComponent::Value => *v = 1. - col_percent,
+    /// Helper function to set a color to the correct colour for a specific
+    /// position on the spectrum, leaving any component not bound to an axis untouched.
+    ///
+    /// Returns false if the color fell outside of sRGB in the model, and was clamped.
+    fn modify_hsv(&self, col_percent: f32, row_percent: f32, color: &mut Hsv) -> bool {
+        let hsv_hue = color.h;
+        let mut in_gamut = true;
+
+        // Work on the linear channels, converting back to sRGB once the axes are applied
+        let linear = self.is_linear();
+        if linear {
+            *color = map_channels(*color, to_linear);
+        }
+
+        // Positions along a limited axis only reach part of the range of the component
+        let col_percent = to_span(self.x_zoom, col_percent);
+        let row_percent = to_span(self.y_zoom, row_percent);
+        let col_percent = to_span(self.x_span, invert(self.x_inverted, col_percent));
+        let row_percent = to_span(self.y_span, invert(self.y_inverted, row_percent));
+
+        // Work in the components of the model, converting back once the axes are applied
+        match self.model {
+            ColorModel::Hsv => (),
+            ColorModel::Hsl => {
+                let Hsl { s, l, .. } = Hsl::from(*color);
+                (color.s, color.v) = (s, l);
             }
+            ColorModel::Oklch | ColorModel::Lch => {
+                (color.h, color.s, color.v) = polar_components(self.model, *color);
+            }
+            ColorModel::Hwb => {
+                let Hwb { w, b, .. } = Hwb::from(*color);
+                (color.s, color.v) = (w, 1. - b);
+            }
+        }
+
+        // The sRGB channels and temperature don't depend on the model, so they're set once
+        // converted back
+        if let Some(x_axis) = self.x_axis.filter(|axis| !axis.ignores_model()) {
+            x_axis.set_from_percent(color, col_percent);
+        };
+        if let Some(y_axis) = self.y_axis.filter(|axis| !axis.ignores_model()) {
+            y_axis.set_from_percent(color, row_percent);
         };
-        if let Some(y_axis) = self.y_axis {
-            match y_axis {
-                HsvComponent::Hue => *h = row_percent * 360.,
-                HsvComponent::Saturation => *s = row_percent,
-                HsvComponent::Value => *v = 1. - row_percent,
+
+        // A gray in HWB has as much whiteness as it lacks blackness
+        if self.neutral {
+            color.s = match self.model {
+                ColorModel::Hwb => color.v,
+                _ => 0.,
+            };
+        }
+
+        match self.model {
+            ColorModel::Hsv => (),
+            ColorModel::Hsl => {
+                let Hsv { s, v, .. } = Hsv::from(Hsl {
+                    h: color.h,
+                    s: color.s,
+                    l: color.v,
+                    a: color.a,
+                });
+                (color.s, color.v) = (s, v);
+            }
+            ColorModel::Oklch | ColorModel::Lch => {
+                let converted = match self.model {
+                    ColorModel::Lch => Lch {
+                        l: color.v * 100.,
+                        c: color.s * lab::MAX_CHROMA,
+                        h: color.h,
+                        a: color.a,
+                    }
+                    .try_to_color(),
+                    _ => Oklch {
+                        l: color.v,
+                        c: color.s * oklch::MAX_CHROMA,
+                        h: color.h,
+                        a: color.a,
+                    }
+                    .try_to_color(),
+                };
+
+                in_gamut = converted.is_ok();
+                let Hsv { h, s, v, .. } =
+                    Hsv::from(converted.unwrap_or_else(|out_of_gamut| out_of_gamut.clamped));
+
+                // Grays have no hue, so keep the previous one rather than resetting to red
+                let h = match s < f32::EPSILON {
+                    true => hsv_hue,
+                    false => h,
+                };
+                (color.h, color.s, color.v) = (h, s, v);
+            }
+            ColorModel::Hwb => {
+                let Hsv { s, v, .. } = Hsv::from(Hwb {
+                    h: color.h,
+                    w: color.s,
+                    b: 1. - color.v,
+                    a: color.a,
+                });
+                (color.s, color.v) = (s, v);
             }
+        }
+
+        // Round trips through other models leave a trace of chroma, and a hue to go with it
+        if self.neutral {
+            (color.h, color.s) = (hsv_hue, 0.);
+        }
+
+        if let Some(x_axis) = self.x_axis.filter(HsvComponent::ignores_model) {
+            x_axis.set_from_percent(color, col_percent);
         };
+        if let Some(y_axis) = self.y_axis.filter(HsvComponent::ignores_model) {
+            y_axis.set_from_percent(color, row_percent);
+        };
+
+        if linear {
+            *color = map_channels(*color, from_linear);
+        }
+
+        in_gamut
+    }
+
+    /// Returns true if the axes are interpolated in linear light, see [Spectrum::linear_light].
+    fn is_linear(&self) -> bool {
+        self.linear_light && !matches!(self.model, ColorModel::Oklch | ColorModel::Lch)
+    }
+
+    /// Returns the component of the color as used by the axes of this spectrum,
+    /// so saturation and value are given in HSL terms when using [ColorModel::Hsl].
+    fn get_component(&self, comp: HsvComponent, color: Hsv) -> f32 {
+        let color = match self.is_linear() {
+            true => map_channels(color, to_linear),
+            false => color,
+        };
+
+        match (self.model, comp) {
+            (ColorModel::Hsl, HsvComponent::Saturation) => Hsl::from(color).s,
+            (ColorModel::Hsl, HsvComponent::Value) => Hsl::from(color).l,
+            (ColorModel::Hwb, HsvComponent::Saturation) => Hwb::from(color).w,
+            (ColorModel::Hwb, HsvComponent::Value) => 1. - Hwb::from(color).b,
+            (ColorModel::Oklch | ColorModel::Lch, HsvComponent::Hue) => {
+                polar_components(self.model, color).0
+            }
+            (ColorModel::Oklch | ColorModel::Lch, HsvComponent::Saturation) => {
+                polar_components(self.model, color).1
+            }
+            (ColorModel::Oklch | ColorModel::Lch, HsvComponent::Value) => {
+                polar_components(self.model, color).2
+            }
+            _ => comp.get_hsv_component(color),
+        }
+    }
+
+    /// The position along an axis used to render a block, stretching the hue axis so the
+    /// last block lands on 360 when the endpoint is inclusive.
+    fn block_percent(
+        &self,
+        axis: Option<HsvComponent>,
+        block: usize,
+        blocks: usize,
+        percent: f32,
+    ) -> f32 {
+        if self.inclusive_hue && axis == Some(HsvComponent::Hue) && blocks > 1 {
+            block as f32 / (blocks - 1) as f32
+        } else {
+            percent
+        }
     }
 
     /// If the spectrum only contains one axis, which is Hue, then we want to
     /// ensure that the colours shown are at full saturation and value.
+    ///
+    /// A lone temperature axis is likewise shown at full value, which it sets the saturation of.
     fn singular_hue_colour_change(&self, s: &mut f32, v: &mut f32) {
         // If its a single axis hue view, we want to maximize saturation and value
-        if self.x_axis.is_none() || self.y_axis.is_none() {
-            if self.x_axis.or(self.y_axis) == Some(HsvComponent::Hue) {
-                (*s, *v) = (1., 1.);
+        if (self.x_axis.is_none() || self.y_axis.is_none())
+            && let Some(HsvComponent::Hue | HsvComponent::Temperature) = self.x_axis.or(self.y_axis)
+        {
+            (*s, *v) = (1., 1.);
+        };
+    }
+}
+
+/// The color with the function applied to each of its sRGB channels, e.g. to or from linear
+/// light, keeping the hue and saturation of grays and black.
+fn map_channels(color: Hsv, f: fn(f32) -> f32) -> Hsv {
+    let Color { r, g, b, a } = Color::from(color);
+
+    let mapped = Hsv::from_color_with_previous(Color::from_rgba(f(r), f(g), f(b), a), color);
+
+    // Red at the far end of a hue axis stays there, rather than wrapping around to 0
+    match (mapped.h - color.h).abs() > 180. {
+        true => Hsv {
+            h: color.h,
+            ..mapped
+        },
+        false => mapped,
+    }
+}
+
+/// Flips a position along an axis to run from the other end, if the axis is inverted.
+fn invert(inverted: bool, percent: f32) -> f32 {
+    match inverted {
+        true => 1. - percent,
+        false => percent,
+    }
+}
+
+/// Maps a position along an axis to the part of the range of its component the axis spans.
+fn to_span((start, end): (f32, f32), percent: f32) -> f32 {
+    start + percent * (end - start)
+}
+
+/// Maps a part of the range of a component back to a position along the axis spanning it,
+/// keeping parts outside of the span at its edges.
+fn from_span((start, end): (f32, f32), percent: f32) -> f32 {
+    match end > start {
+        true => ((percent - start) / (end - start)).clamp(0., 1.),
+        false => 0.,
+    }
+}
+
+/// The hue, chroma and lightness of the color in [Lch] for [ColorModel::Lch], or in
+/// [Oklch] otherwise, in the place of hue, saturation and value.
+///
+/// Chroma and lightness are scaled to `[0, 1]`. Grays have no hue of their own, so
+/// they take the hue of the fully saturated color instead.
+fn polar_components(model: ColorModel, color: Hsv) -> (f32, f32, f32) {
+    let (h, c, l) = match model {
+        ColorModel::Lch => {
+            let Lch { l, c, h, .. } = Lch::from(color);
+            (h, c / lab::MAX_CHROMA, l / 100.)
+        }
+        _ => {
+            let Oklch { l, c, h, .. } = Oklch::from(color);
+            (h, c / oklch::MAX_CHROMA, l)
+        }
+    };
+
+    let h = match color.s < f32::EPSILON || color.v < f32::EPSILON {
+        true => polar_components(model, hsv(color.h, 1., 1.)).0,
+        false => h,
+    };
+
+    (h, c, l)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A square of 100 by 100 at the origin.
+    const BOUNDS: Rectangle = Rectangle {
+        x: 0.,
+        y: 0.,
+        width: 100.,
+        height: 100.,
+    };
+
+    #[test]
+    fn color_stops_end_at_axis_extremes() {
+        let color = hsv(120., 0.5, 0.5);
+
+        let ends = |spectrum: Spectrum| {
+            let stops = spectrum.color_stops(color, 5);
+            let (first, last) = (stops[0], stops[stops.len() - 1]);
+            assert_eq!((first.0, last.0), (0., 1.));
+            (first.1, last.1)
+        };
+
+        let (first, last) = ends(Spectrum::hue_horizontal());
+        assert_eq!((first.h, last.h), (0., 360.));
+
+        let (first, last) = ends(Spectrum::new_horizontal(HsvComponent::Saturation));
+        assert_eq!((first.s, last.s), (0., 1.));
+
+        // Value decreases along its axis, from the top
+        let (first, last) = ends(Spectrum::new_vertical(HsvComponent::Value));
+        assert_eq!((first.v, last.v), (1., 0.));
+
+        let (first, last) = ends(Spectrum::new_horizontal(HsvComponent::Alpha));
+        assert_eq!((first.a, last.a), (0., 1.));
+
+        let (first, last) = ends(Spectrum::saturation_value());
+        assert_eq!((first.s, first.v), (0., 1.));
+        assert_eq!((last.s, last.v), (1., 0.));
+    }
+
+    #[test]
+    fn color_stops_has_both_ends() {
+        let spectrum = Spectrum::new_horizontal(HsvComponent::Saturation);
+
+        for count in [0, 1, 2] {
+            assert_eq!(spectrum.color_stops(Hsv::default(), count).len(), 2);
+        }
+
+        assert_eq!(
+            spectrum.to_css_gradient_string(hsv(0., 0.5, 1.), 0),
+            "linear-gradient(to right, rgba(255, 255, 255, 1.000) 0.00%, rgba(255, 0, 0, 1.000) 100.00%)"
+        );
+    }
+
+    /// The corners of [BOUNDS], clockwise from the top left, then the center.
+    const POINTS: [Point; 5] = [
+        Point::new(0., 0.),
+        Point::new(100., 0.),
+        Point::new(100., 100.),
+        Point::new(0., 100.),
+        Point::new(50., 50.),
+    ];
+
+    /// The picks at each of [POINTS].
+    fn picks(spectrum: Spectrum, color: Hsv) -> [Hsv; 5] {
+        POINTS.map(|point| spectrum.pick_at(color, BOUNDS, point))
+    }
+
+    #[test]
+    fn pick_saturation_value() {
+        let [top_left, top_right, bottom_right, bottom_left, center] =
+            picks(Spectrum::saturation_value(), hsv(120., 0.2, 0.2));
+
+        assert_eq!(top_left, hsv(120., 0., 1.));
+        assert_eq!(top_right, hsv(120., 1., 1.));
+        assert_eq!(bottom_right, hsv(120., 1., 0.));
+        assert_eq!(bottom_left, hsv(120., 0., 0.));
+        assert_eq!(center, hsv(120., 0.5, 0.5));
+    }
+
+    #[test]
+    fn pick_hue_horizontal() {
+        let [top_left, top_right, bottom_right, bottom_left, center] =
+            picks(Spectrum::hue_horizontal(), hsv(120., 0.2, 0.2));
+
+        // The far edge stays just under 360 rather than wrapping around to red
+        assert_eq!(top_left, hsv(0., 0.2, 0.2));
+        assert_eq!(top_right, hsv(360f32.next_down(), 0.2, 0.2));
+        assert_eq!(bottom_right, top_right);
+        assert_eq!(bottom_left, top_left);
+        assert_eq!(center, hsv(180., 0.2, 0.2));
+    }
+
+    #[test]
+    fn pick_hue_vertical() {
+        let [top_left, top_right, bottom_right, bottom_left, center] =
+            picks(Spectrum::hue_vertical(), hsv(120., 0.2, 0.2));
+
+        assert_eq!(top_left, hsv(0., 0.2, 0.2));
+        assert_eq!(top_right, top_left);
+        assert_eq!(bottom_right, hsv(360f32.next_down(), 0.2, 0.2));
+        assert_eq!(bottom_left, bottom_right);
+        assert_eq!(center, hsv(180., 0.2, 0.2));
+    }
+
+    #[test]
+    fn pick_inverted() {
+        let [top_left, top_right, _, _, center] =
+            picks(Spectrum::hue_horizontal().invert_x(true), hsv(120., 1., 1.));
+
+        assert_eq!(top_left, hsv(360f32.next_down(), 1., 1.));
+        assert_eq!(top_right, hsv(0., 1., 1.));
+        assert_eq!(center, hsv(180., 1., 1.));
+
+        let [top_left, top_right, bottom_right, bottom_left, center] = picks(
+            Spectrum::saturation_value().invert_x(true).invert_y(true),
+            hsv(120., 0.2, 0.2),
+        );
+
+        assert_eq!(top_left, hsv(120., 1., 0.));
+        assert_eq!(top_right, hsv(120., 0., 0.));
+        assert_eq!(bottom_right, hsv(120., 0., 1.));
+        assert_eq!(bottom_left, hsv(120., 1., 1.));
+        assert_eq!(center, hsv(120., 0.5, 0.5));
+    }
+
+    #[test]
+    fn pick_value_vertical() {
+        let [top_left, _, bottom_right, _, center] = picks(
+            Spectrum::new_vertical(HsvComponent::Value),
+            hsv(120., 0.2, 0.2),
+        );
+
+        assert_eq!(top_left, hsv(120., 0.2, 1.));
+        assert_eq!(bottom_right, hsv(120., 0.2, 0.));
+        assert_eq!(center, hsv(120., 0.2, 0.5));
+    }
+
+    #[test]
+    fn hue_drag_across_both_ends_is_monotonic() {
+        let color = hsv(120., 1., 1.);
+
+        // Past the start, across the strip and past the end, then back again
+        let there = (-40..=240).map(|x| x as f32 / 2.);
+        let back = there.clone().rev();
+
+        for (spectrum, along) in [
+            (
+                Spectrum::hue_horizontal(),
+                (|p: Point| p.x) as fn(Point) -> f32,
+            ),
+            (Spectrum::hue_vertical(), |p: Point| p.y),
+        ] {
+            let marker = |offset: f32| {
+                let cursor = Point::new(offset, offset);
+                let picked = spectrum.pick_at(color, BOUNDS, cursor);
+                assert!((0. ..360.).contains(&picked.h));
+
+                along(spectrum.get_marker_pos(picked, BOUNDS.size()))
+            };
+
+            let forward: Vec<f32> = there.clone().map(marker).collect();
+            assert!(forward.windows(2).all(|pair| pair[0] <= pair[1]));
+            assert_eq!(forward[0], 0.);
+            assert!(forward[forward.len() - 1] > 99.9);
+
+            let backward: Vec<f32> = back.clone().map(marker).collect();
+            assert!(backward.windows(2).all(|pair| pair[0] >= pair[1]));
+        }
+    }
+
+    #[test]
+    fn zero_size_bounds_keep_the_color() {
+        let color = hsv(120., 0.5, 0.5);
+
+        for size in [Size::ZERO, Size::new(0., 100.), Size::new(100., 0.)] {
+            let bounds = Rectangle::with_size(size);
+
+            for spectrum in [Spectrum::saturation_value(), Spectrum::hue_vertical()] {
+                assert_eq!(spectrum.fetch_hsv(color, bounds, Point::ORIGIN), color);
+                assert_eq!(spectrum.fetch_percent(bounds, Point::ORIGIN), None);
             }
+        }
+    }
+
+    #[test]
+    fn picks_are_clamped() {
+        let color = hsv(120., 0.5, 0.5);
+
+        for cursor in [Point::new(-1e9, -1e9), Point::new(1e9, 1e9)] {
+            let Hsv { h, s, v, a } = Spectrum::default().fetch_hsv(color, BOUNDS, cursor);
+
+            assert!((0. ..360.).contains(&h));
+            assert!([s, v, a].iter().all(|c| (0. ..=1.).contains(c)));
+        }
+    }
+
+    #[test]
+    fn blocks_fill_an_offset_region() {
+        let region = Rectangle::new(Point::new(30., 40.), Size::new(20., 10.));
+        let quantization = NonZeroUsize::new(2).unwrap();
+        let color = hsv(0., 1., 1.);
+
+        let blocks: Vec<_> = Spectrum::saturation_value()
+            .blocks(region, color, quantization, None, false)
+            .collect();
+
+        assert_eq!(blocks.len(), 10 * 5);
+        assert!(
+            blocks
+                .iter()
+                .all(|(block, _)| region.union(block) == region)
+        );
+
+        let fill_at = |x: f32, y: f32| {
+            blocks
+                .iter()
+                .find(|(block, _)| block.position() == Point::new(x, y))
+                .map(|(_, fill)| *fill)
         };
+
+        // Positions along the axes are relative to the region, not the frame
+        assert_eq!(fill_at(30., 40.), Some(Color::from(hsv(0., 0., 1.))));
+        assert_eq!(fill_at(40., 40.), Some(Color::from(hsv(0., 0.5, 1.))));
+        assert_eq!(fill_at(30., 48.), Some(Color::from(hsv(0., 0., 1. - 0.8))));
+        assert_eq!(fill_at(0., 0.), None);
+    }
+
+    #[test]
+    fn alpha_axis_picks_alpha() {
+        let spectrum = Spectrum::new_horizontal(HsvComponent::Alpha);
+        let color = hsv(120., 0.5, 0.5);
+
+        let picked = spectrum.fetch_hsv(color, BOUNDS, Point::new(25., 50.));
+
+        assert_eq!(picked, Hsv { a: 0.25, ..color });
     }
 }
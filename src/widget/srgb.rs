@@ -0,0 +1,58 @@
+//! The sRGB transfer function, shared by the color spaces that work on linear light.
+
+/// Removes the sRGB transfer function from a channel.
+pub(crate) fn to_linear(c: f32) -> f32 {
+    match c <= 0.04045 {
+        true => c / 12.92,
+        false => ((c + 0.055) / 1.055).powf(2.4),
+    }
+}
+
+/// How far a linear channel may fall outside of `[0, 1]` through rounding alone, while
+/// still counting as within the gamut.
+const GAMUT_EPSILON: f32 = 1e-4;
+
+/// Returns true if every linear channel is on `[0, 1]`, give or take rounding.
+pub(crate) fn in_gamut(linear: [f32; 3]) -> bool {
+    linear
+        .iter()
+        .all(|c| (-GAMUT_EPSILON..=1.0 + GAMUT_EPSILON).contains(c))
+}
+
+/// Applies the sRGB transfer function to a linear channel.
+pub(crate) fn from_linear(c: f32) -> f32 {
+    match c <= 0.003_130_8 {
+        true => c * 12.92,
+        false => 1.055 * c.powf(1.0 / 2.4) - 0.055,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reference_values() {
+        assert_eq!(to_linear(0.0), 0.0);
+        assert!((to_linear(1.0) - 1.0).abs() < 1e-6);
+        assert!((to_linear(0.5) - 0.214_041).abs() < 1e-5);
+        assert!((to_linear(0.04045) - 0.003_130_8).abs() < 1e-6);
+        assert!((from_linear(0.18) - 0.461_356).abs() < 1e-5);
+    }
+
+    #[test]
+    fn round_trip() {
+        // Both sides of the linear segment near black
+        for c in (0..=255).map(|c| c as f32 / 255.0) {
+            assert!((from_linear(to_linear(c)) - c).abs() < 1e-5, "{c}");
+        }
+    }
+
+    #[test]
+    fn gamut() {
+        assert!(in_gamut([0.0, 0.5, 1.0]));
+        assert!(in_gamut([-GAMUT_EPSILON, 1.0 + GAMUT_EPSILON, 0.5]));
+        assert!(!in_gamut([-0.01, 0.5, 0.5]));
+        assert!(!in_gamut([0.5, 0.5, 1.01]));
+    }
+}
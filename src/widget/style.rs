@@ -1,15 +1,178 @@
+pub mod popup;
+
+use iced_core::{Border, Color, border};
+
 #[derive(Debug, Clone, Copy)]
 pub enum MarkerShape {
-    Square { size: f32, border_width: f32 },
-    Circle { radius: f32, border_width: f32 },
+    Square {
+        size: f32,
+        border_width: f32,
+    },
+    Circle {
+        radius: f32,
+        border_width: f32,
+    },
+    /// A triangle of the opaque color on both edges of a slider, pointing into the track
+    /// so the gradient under the marker stays visible.
+    ///
+    /// Markers that move along both axes are pointed at from above and below.
+    Arrow {
+        size: f32,
+        border_width: f32,
+    },
+    /// An open chevron on both edges of a slider, pointing into the track, drawn in the
+    /// outline color.
+    ///
+    /// Markers that move along both axes are pointed at from above and below.
+    Chevron {
+        size: f32,
+        width: f32,
+    },
+    /// A hollow circle in the outline color, leaving the spectrum under it visible.
+    Ring {
+        radius: f32,
+        width: f32,
+    },
+    /// Two lines crossing at the marker in the outline color, with a gap in the middle
+    /// to leave the spectrum under it visible.
+    Crosshair {
+        size: f32,
+        width: f32,
+    },
+}
+
+/// The outlines a marker picks between, whichever stands out more against the picked color.
+#[derive(Debug, Clone, Copy)]
+pub struct Contrast {
+    /// The outline over light colors.
+    pub dark: Color,
+    /// The outline over dark colors.
+    pub light: Color,
+}
+
+impl Default for Contrast {
+    fn default() -> Self {
+        Self {
+            dark: Color::BLACK,
+            light: Color::WHITE,
+        }
+    }
+}
+
+impl Contrast {
+    /// The outline that stands out against the color.
+    pub fn against(self, color: Color) -> Color {
+        match color.relative_luminance() > 0.5 {
+            true => self.dark,
+            false => self.light,
+        }
+    }
+}
+
+/// The pattern drawn behind translucent colors, so their transparency is visible.
+#[derive(Debug, Clone, Copy)]
+pub struct Checkerboard {
+    /// The length of the side of each square.
+    pub size: f32,
+    pub light: Color,
+    pub dark: Color,
+}
+
+impl Default for Checkerboard {
+    fn default() -> Self {
+        Self {
+            size: 8.,
+            light: Color::from_rgb8(0xCC, 0xCC, 0xCC),
+            dark: Color::from_rgb8(0x99, 0x99, 0x99),
+        }
+    }
+}
+
+/// Tick marks along the far edge of a slider, dividing its axis into even steps.
+#[derive(Debug, Clone, Copy)]
+pub struct Ticks {
+    /// How many steps the axis is divided into, e.g. 12 for every 30° of a hue slider.
+    pub divisions: u16,
+    /// How far each tick reaches into the slider.
+    pub length: f32,
+    pub width: f32,
+    pub color: Color,
+    /// The size of the labels with the values at both ends of the axis, if there are any.
+    pub label_size: Option<f32>,
+}
+
+impl Default for Ticks {
+    fn default() -> Self {
+        Self {
+            divisions: 12,
+            length: 6.,
+            width: 1.,
+            color: Color::BLACK.scale_alpha(0.6),
+            label_size: None,
+        }
+    }
+}
+
+/// Guide lines through the marker, across the whole of a spectrum with two axes.
+#[derive(Debug, Clone, Copy)]
+pub struct Crosshair {
+    pub width: f32,
+    /// The color of the lines, instead of the outline of the marker.
+    pub color: Option<Color>,
+}
+
+impl Default for Crosshair {
+    fn default() -> Self {
+        Self {
+            width: 1.,
+            color: None,
+        }
+    }
 }
 
 pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
 
+/// The appearance of the pickers.
+///
+/// Fields can be changed from the [default](Style::default) or [normal] style:
+///
+/// ```
+/// use iced_color_picker::{Style, Ticks};
+///
+/// let style = Style {
+///     ticks: Some(Ticks::default()),
+///     ..Style::default()
+/// };
+/// ```
 pub struct Style {
     pub marker_shape: MarkerShape,
+    /// The fill of the marker, instead of the picked color.
+    pub marker_fill: Option<Color>,
+    /// The outline of the marker, instead of one of [Style::marker_contrast].
+    pub marker_outline: Option<Color>,
+    /// The outlines the marker picks between to stand out against the picked color.
+    pub marker_contrast: Contrast,
+    pub checkerboard: Checkerboard,
+    /// The border around the spectrum, whose radius also rounds the corners of the spectrum.
+    pub border: Border,
+    /// The border drawn around the picker while it has focus.
+    pub focus_ring: Border,
+    /// Drawn over the picker while it is disabled, to dim it.
+    pub disabled: Color,
+    /// Tick marks along sliders, i.e. spectra with a single axis.
+    pub ticks: Option<Ticks>,
+    /// Lines through the marker, which help to read its position on large pickers.
+    pub crosshair: Option<Crosshair>,
 }
 
+impl Default for Style {
+    /// The [normal] style of the light theme.
+    fn default() -> Self {
+        normal(&iced_core::Theme::Light)
+    }
+}
+
+/// The theme of the widgets, which [iced_core::Theme] implements out of the box with [normal].
 pub trait Catalog {
     type Class<'a>;
 
@@ -30,11 +193,90 @@ impl Catalog for iced_core::Theme {
     }
 }
 
-pub fn normal(_: &iced_core::Theme) -> Style {
+/// The default style, with markers and focus rings in colors of the theme's palette.
+///
+/// Markers are outlined in the background or text color of the theme, whichever stands
+/// out against the picked color.
+pub fn normal(theme: &iced_core::Theme) -> Style {
     Style {
         marker_shape: MarkerShape::Square {
             size: 8.,
             border_width: 2.,
         },
+        marker_fill: None,
+        marker_outline: None,
+        marker_contrast: marker_contrast(theme),
+        checkerboard: Checkerboard::default(),
+        border: Border::default(),
+        focus_ring: focus_ring(theme),
+        disabled: disabled(theme),
+        ticks: None,
+        crosshair: None,
+    }
+}
+
+/// A high contrast style for editors with a dark theme, with a bold round marker.
+pub fn editor_dark(theme: &iced_core::Theme) -> Style {
+    Style {
+        marker_shape: MarkerShape::Circle {
+            radius: 6.,
+            border_width: 2.,
+        },
+        ..editor(theme)
+    }
+}
+
+/// A high contrast style for editors with a light theme, with a finer round marker.
+pub fn editor_light(theme: &iced_core::Theme) -> Style {
+    Style {
+        marker_shape: MarkerShape::Circle {
+            radius: 5.,
+            border_width: 1.5,
+        },
+        ..editor(theme)
+    }
+}
+
+/// The [normal] style with markers outlined in pure black or white, the most contrast there
+/// is against the picked color, and a subtle border setting the spectrum apart from its panel.
+fn editor(theme: &iced_core::Theme) -> Style {
+    Style {
+        marker_contrast: Contrast::default(),
+        border: editor_border(theme),
+        ..normal(theme)
+    }
+}
+
+/// A subtle, rounded border in a weak background color of the theme.
+fn editor_border(theme: &iced_core::Theme) -> Border {
+    border::color(theme.extended_palette().background.weak.color)
+        .width(1.)
+        .rounded(4.)
+}
+
+/// The background and text colors of the theme, as the outlines over light and dark colors.
+fn marker_contrast(theme: &iced_core::Theme) -> Contrast {
+    let palette = theme.extended_palette();
+    let background = palette.background.base;
+
+    match palette.is_dark {
+        true => Contrast {
+            dark: background.color,
+            light: background.text,
+        },
+        false => Contrast {
+            dark: background.text,
+            light: background.color,
+        },
     }
 }
+
+/// A ring in the primary color of the theme.
+fn focus_ring(theme: &iced_core::Theme) -> Border {
+    border::color(theme.palette().primary).width(2.)
+}
+
+/// The background of the theme, translucent so the spectrum shows through faintly.
+fn disabled(theme: &iced_core::Theme) -> Color {
+    theme.palette().background.scale_alpha(0.6)
+}
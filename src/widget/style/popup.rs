@@ -0,0 +1,57 @@
+//! The style of the popups that hold a picker, i.e. of a `ColorButton` and a `ColorDialog`.
+
+use super::Checkerboard;
+
+use iced_core::{Border, Color, border};
+
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+pub struct Style {
+    /// The background of the popup.
+    pub background: Color,
+    /// The border of the popup, and of the swatch of a `ColorButton`.
+    pub border: Border,
+    /// Drawn behind the swatch of a `ColorButton`, so the transparency of the color is visible.
+    pub checkerboard: Checkerboard,
+}
+
+impl Default for Style {
+    /// The [default] style of the light theme.
+    fn default() -> Self {
+        default(&iced_core::Theme::Light)
+    }
+}
+
+/// The theme of the popups, which [iced_core::Theme] implements out of the box with [default].
+pub trait Catalog {
+    type Class<'a>;
+
+    fn default<'a>() -> Self::Class<'a>;
+
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+impl Catalog for iced_core::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The base background of the theme, in a thin, rounded border in a strong background color.
+pub fn default(theme: &iced_core::Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.base.color,
+        border: border::color(palette.background.strong.color)
+            .width(1.)
+            .rounded(4.),
+        checkerboard: Checkerboard::default(),
+    }
+}
@@ -0,0 +1,162 @@
+//! A plain rectangle of a color, e.g. to preview the picked color.
+
+use super::style::{Catalog, Checkerboard, Style, StyleFn};
+use super::{ColorBlindness, Hsv, draw_checkerboard};
+
+use iced_core::widget::{Tree, Widget};
+use iced_core::{Border, Color, Element, Length, Point, Rectangle, Size, layout, mouse, renderer};
+use iced_graphics::geometry::{self, Frame};
+
+/// Creates a new [Swatch] of the [Hsv] (or [Color]) value.
+pub fn swatch<'a, Theme>(color: impl Into<Hsv>) -> Swatch<'a, Theme>
+where
+    Theme: Catalog + 'a,
+{
+    Swatch::new(color)
+}
+
+/// A rectangle filled with a color, over the checkerboard of the [Style] when it's translucent.
+pub struct Swatch<'a, Theme>
+where
+    Theme: Catalog,
+{
+    color: Hsv,
+    width: Length,
+    height: Length,
+    color_blindness: Option<ColorBlindness>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Theme> Swatch<'a, Theme>
+where
+    Theme: Catalog,
+{
+    pub fn new(color: impl Into<Hsv>) -> Self {
+        Self {
+            color: color.into(),
+            width: Length::Fixed(32.0),
+            height: Length::Fixed(32.0),
+            color_blindness: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Set the width of the [Swatch].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Set the height of the [Swatch].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Fill the [Swatch] with the color as it appears with a color vision deficiency.
+    pub fn simulate(mut self, color_blindness: ColorBlindness) -> Self {
+        self.color_blindness = Some(color_blindness);
+        self
+    }
+
+    /// Set the [Style] of the [Swatch].
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = Theme::Class::from(Box::new(style));
+        self
+    }
+
+    /// Set the style class of the [Swatch].
+    pub fn class(mut self, class: Theme::Class<'a>) -> Self {
+        self.class = class;
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Swatch<'a, Theme>
+where
+    Theme: Catalog,
+    Renderer: geometry::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.width, self.height)
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &iced_core::renderer::Style,
+        layout: layout::Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let Style { checkerboard, .. } = theme.style(&self.class);
+
+        let color = match self.color_blindness {
+            Some(color_blindness) => Hsv::from(color_blindness.simulate(Color::from(self.color))),
+            None => self.color,
+        };
+
+        draw_swatch(renderer, layout.bounds(), color, checkerboard, None);
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Swatch<'a, Theme>> for Element<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog + 'a,
+    Renderer: geometry::Renderer,
+{
+    fn from(value: Swatch<'a, Theme>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Draws the color over the bounds, above a checkerboard when it's translucent, and
+/// with an optional border on top.
+pub(super) fn draw_swatch<Renderer: geometry::Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    color: Hsv,
+    checkerboard: Checkerboard,
+    border: Option<Border>,
+) {
+    let color = Color::from(color);
+    let mut frame = Frame::new(renderer, bounds.size());
+
+    if color.a < 1.0 {
+        draw_checkerboard(&mut frame, checkerboard, None);
+    }
+
+    frame.fill_rectangle(Point::ORIGIN, bounds.size(), color);
+
+    renderer.with_layer(bounds, |renderer| {
+        renderer.with_translation(bounds.position() - Point::ORIGIN, |renderer| {
+            renderer.draw_geometry(frame.into_geometry());
+        });
+
+        // Drawn within the layer, so it isn't hidden behind the swatch
+        if let Some(border) = border {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border,
+                    ..Default::default()
+                },
+                Color::TRANSPARENT,
+            );
+        }
+    });
+}
@@ -0,0 +1,323 @@
+//! A palette of colors laid out in a wrapping grid of swatches.
+
+use super::style::{Catalog, Style, StyleFn};
+use super::swatch::draw_swatch;
+use super::{Hsv, outline, quantize};
+
+use iced_core::keyboard;
+use iced_core::widget::{Tree, Widget, tree};
+use iced_core::{
+    Element, Event, Length, Pixels, Point, Rectangle, Shell, Size, border, layout, mouse, touch,
+};
+use iced_graphics::geometry;
+
+/// Creates a new [SwatchGrid] of the colors, and a closure to produce a message when one is clicked.
+pub fn swatch_grid<'a, Message, Theme, FromHsv>(
+    colors: impl IntoIterator<Item = impl Into<Hsv>>,
+    on_select: impl Fn(FromHsv) -> Message + 'a,
+) -> SwatchGrid<'a, Message, Theme>
+where
+    Theme: Catalog + 'a,
+    FromHsv: From<Hsv> + 'a,
+{
+    SwatchGrid::new(colors, move |color| on_select(color.into()))
+}
+
+/// A grid of swatches, wrapping onto as many rows as its width requires.
+///
+/// Clicking a swatch selects its color, while right clicking it (or clicking it with Alt
+/// held) triggers [SwatchGrid::on_alt_select], e.g. to remove it from the palette.
+pub struct SwatchGrid<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    colors: Vec<Hsv>,
+    selected: Option<Hsv>,
+    on_select: Box<dyn Fn(Hsv) -> Message + 'a>,
+    on_alt_select: Option<Box<dyn Fn(usize, Hsv) -> Message + 'a>>,
+    dropping: Option<Hsv>,
+    on_drop: Option<Box<dyn Fn(usize, Hsv) -> Message + 'a>>,
+    swatch_size: f32,
+    spacing: f32,
+    width: Length,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme> SwatchGrid<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    pub fn new(
+        colors: impl IntoIterator<Item = impl Into<Hsv>>,
+        on_select: impl Fn(Hsv) -> Message + 'a,
+    ) -> Self {
+        Self {
+            colors: colors.into_iter().map(Into::into).collect(),
+            selected: None,
+            on_select: Box::new(on_select),
+            on_alt_select: None,
+            dropping: None,
+            on_drop: None,
+            swatch_size: 20.0,
+            spacing: 4.0,
+            width: Length::Fill,
+            class: Theme::default(),
+        }
+    }
+
+    /// Highlight the swatches of the color, e.g. the current color of a picker.
+    pub fn selected(mut self, color: impl Into<Hsv>) -> Self {
+        self.selected = Some(color.into());
+        self
+    }
+
+    /// Produce a message with the index and color of a swatch when it's right clicked,
+    /// or clicked with Alt held.
+    pub fn on_alt_select(mut self, on_alt_select: impl Fn(usize, Hsv) -> Message + 'a) -> Self {
+        self.on_alt_select = Some(Box::new(on_alt_select));
+        self
+    }
+
+    /// Produce a message with the color being dragged, if any, when it's dropped on the
+    /// grid, along with the index of the swatch it's dropped on, or the number of colors
+    /// when it's dropped past them, e.g. to insert it into the palette there.
+    ///
+    /// See [ColorPicker::on_drag](super::ColorPicker::on_drag) for dragging colors.
+    pub fn on_drop(
+        mut self,
+        dragged: Option<Hsv>,
+        on_drop: impl Fn(usize, Hsv) -> Message + 'a,
+    ) -> Self {
+        self.dropping = dragged;
+        self.on_drop = Some(Box::new(on_drop));
+        self
+    }
+
+    /// Set the length of the side of each swatch.
+    pub fn swatch_size(mut self, swatch_size: impl Into<Pixels>) -> Self {
+        self.swatch_size = swatch_size.into().0;
+        self
+    }
+
+    /// Set the space between the swatches.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Set the width of the [SwatchGrid], which decides how many swatches fit in a row.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Set the [Style] of the [SwatchGrid].
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = Theme::Class::from(Box::new(style));
+        self
+    }
+
+    /// Set the style class of the [SwatchGrid].
+    pub fn class(mut self, class: Theme::Class<'a>) -> Self {
+        self.class = class;
+        self
+    }
+
+    /// How many swatches fit in a row of the width, at least one.
+    fn columns(&self, width: f32) -> usize {
+        let columns = ((width + self.spacing) / (self.swatch_size + self.spacing)).floor();
+
+        (columns.max(1.0) as usize).min(self.colors.len().max(1))
+    }
+
+    /// The colors paired with the bounds of their swatch.
+    fn swatches(&self, bounds: Rectangle) -> impl Iterator<Item = (Hsv, Rectangle)> + '_ {
+        let columns = self.columns(bounds.width);
+        let step = self.swatch_size + self.spacing;
+
+        self.colors.iter().enumerate().map(move |(i, color)| {
+            let position = Point::new(
+                bounds.x + (i % columns) as f32 * step,
+                bounds.y + (i / columns) as f32 * step,
+            );
+
+            (
+                *color,
+                Rectangle::new(position, Size::new(self.swatch_size, self.swatch_size)),
+            )
+        })
+    }
+
+    /// Returns the index and color of the swatch under the point, if any.
+    fn swatch_at(&self, bounds: Rectangle, point: Point) -> Option<(usize, Hsv)> {
+        self.swatches(bounds)
+            .enumerate()
+            .find(|(_, (_, swatch))| swatch.contains(point))
+            .map(|(i, (color, _))| (i, color))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for SwatchGrid<'a, Message, Theme>
+where
+    Theme: Catalog,
+    Renderer: geometry::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width);
+        let columns = self.columns(limits.max().width);
+        let rows = self.colors.len().div_ceil(columns);
+
+        let length = |count: usize| {
+            (count as f32 * (self.swatch_size + self.spacing) - self.spacing).max(0.0)
+        };
+
+        let size = limits.resolve(
+            self.width,
+            Length::Shrink,
+            Size::new(length(columns), length(rows)),
+        );
+
+        layout::Node::new(size)
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.dropping.is_some() && cursor.is_over(layout.bounds()) {
+            return mouse::Interaction::Copy;
+        }
+
+        let over_swatch = cursor
+            .position()
+            .and_then(|cursor| self.swatch_at(layout.bounds(), cursor))
+            .is_some();
+
+        match over_swatch {
+            true => mouse::Interaction::Pointer,
+            false => Default::default(),
+        }
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let State { modifiers }: &mut State = tree.state.downcast_mut();
+
+        let (position, alt) = match event {
+            Event::Keyboard(keyboard::Event::ModifiersChanged(new_modifiers)) => {
+                *modifiers = *new_modifiers;
+
+                return;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                (cursor.position(), modifiers.alt())
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)) => {
+                (cursor.position(), true)
+            }
+            Event::Touch(touch::Event::FingerPressed { position, .. }) => (Some(*position), false),
+            Event::Mouse(mouse::Event::ButtonReleased(_)) => {
+                if let Some(dropped) = self.dropping
+                    && let Some(on_drop) = &self.on_drop
+                    && let Some(position) = cursor.position_over(layout.bounds())
+                {
+                    let index = self
+                        .swatch_at(layout.bounds(), position)
+                        .map_or(self.colors.len(), |(index, _)| index);
+
+                    shell.publish(on_drop(index, dropped));
+                }
+
+                return;
+            }
+            _ => return,
+        };
+
+        let Some((index, color)) =
+            position.and_then(|position| self.swatch_at(layout.bounds(), position))
+        else {
+            return;
+        };
+
+        let message = match (alt, &self.on_alt_select) {
+            (true, Some(on_alt_select)) => on_alt_select(index, color),
+            (true, None) => return,
+            (false, _) => (self.on_select)(color),
+        };
+
+        shell.publish(message);
+        shell.capture_event();
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &iced_core::renderer::Style,
+        layout: layout::Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let Style { checkerboard, .. } = theme.style(&self.class);
+
+        for (color, swatch) in self.swatches(layout.bounds()) {
+            let border = self
+                .selected
+                .is_some_and(|selected| quantize(selected) == quantize(color))
+                .then(|| border::color(outline(color.into())).width(2.0));
+
+            draw_swatch(renderer, swatch, color, checkerboard, border);
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<SwatchGrid<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: geometry::Renderer,
+{
+    fn from(value: SwatchGrid<'a, Message, Theme>) -> Self {
+        Element::new(value)
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    modifiers: keyboard::Modifiers,
+}
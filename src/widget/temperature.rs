@@ -0,0 +1,111 @@
+//! The colors of light given off by a black body at a temperature, e.g. of light bulbs
+//! and daylight, as used for white balance.
+
+use iced_core::Color;
+
+/// The warmest temperature in Kelvin, at the start of a [Temperature](super::HsvComponent::Temperature) axis.
+pub const MIN_KELVIN: f32 = 1000.0;
+/// The coolest temperature in Kelvin, at the end of a [Temperature](super::HsvComponent::Temperature) axis.
+pub const MAX_KELVIN: f32 = 12000.0;
+
+/// The color of a black body at the temperature in Kelvin, at full brightness, going from
+/// red at [MIN_KELVIN] through white around 6500K to blue at [MAX_KELVIN].
+///
+/// Temperatures outside of that range are clamped. Follows the fit by Tanner Helland,
+/// which is close enough for picking colors, if not for colorimetry.
+pub fn color_temperature(kelvin: f32) -> Color {
+    let t = kelvin.clamp(MIN_KELVIN, MAX_KELVIN) / 100.0;
+
+    let r = match t <= 66.0 {
+        true => 255.0,
+        false => 329.69873 * (t - 60.0).powf(-0.13320476),
+    };
+    let g = match t <= 66.0 {
+        true => 99.4708 * t.ln() - 161.11957,
+        false => 288.12217 * (t - 60.0).powf(-0.07551485),
+    };
+    let b = match t {
+        t if t >= 66.0 => 255.0,
+        t if t <= 19.0 => 0.0,
+        t => 138.51773 * (t - 10.0).ln() - 305.0448,
+    };
+
+    Color::from_rgb(
+        (r / 255.0).clamp(0.0, 1.0),
+        (g / 255.0).clamp(0.0, 1.0),
+        (b / 255.0).clamp(0.0, 1.0),
+    )
+}
+
+/// The temperature in Kelvin of the black body whose color is closest to the color, on
+/// `[MIN_KELVIN, MAX_KELVIN]`.
+///
+/// Only the balance of the channels is compared, so brightness doesn't matter.
+pub fn temperature_of(color: Color) -> f32 {
+    // Green and blue rise against red as the temperature does, so this only goes up
+    let warmth = |color: Color| match color.r > 0.0 {
+        true => (color.g + color.b) / color.r,
+        false => f32::INFINITY,
+    };
+
+    let target = warmth(color);
+    let (mut low, mut high) = (MIN_KELVIN, MAX_KELVIN);
+
+    // Well under a Kelvin apart by the end
+    for _ in 0..16 {
+        let middle = (low + high) / 2.0;
+
+        match warmth(color_temperature(middle)) < target {
+            true => low = middle,
+            false => high = middle,
+        }
+    }
+
+    (low + high) / 2.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn range_is_clamped() {
+        assert_eq!(color_temperature(0.0), color_temperature(MIN_KELVIN));
+        assert_eq!(color_temperature(-500.0), color_temperature(MIN_KELVIN));
+        assert_eq!(color_temperature(40000.0), color_temperature(MAX_KELVIN));
+
+        // Redder and bluer than any black body, found within a Kelvin of the ends
+        assert!((temperature_of(Color::from_rgb(1.0, 0.0, 0.0)) - MIN_KELVIN).abs() < 1.0);
+        assert!((temperature_of(Color::from_rgb(0.0, 0.0, 1.0)) - MAX_KELVIN).abs() < 1.0);
+    }
+
+    #[test]
+    fn ends_are_red_and_blue() {
+        let warm = color_temperature(MIN_KELVIN);
+        assert!(warm.r == 1.0 && warm.g < 0.3 && warm.b == 0.0, "{warm:?}");
+
+        let cool = color_temperature(MAX_KELVIN);
+        assert!(
+            cool.b == 1.0 && cool.r < cool.g && cool.g < cool.b,
+            "{cool:?}"
+        );
+    }
+
+    #[test]
+    fn daylight_is_roughly_white() {
+        let daylight = color_temperature(6500.0);
+
+        for channel in [daylight.r, daylight.g, daylight.b] {
+            assert!(channel > 0.95, "{daylight:?}");
+        }
+    }
+
+    #[test]
+    fn temperature_round_trip() {
+        for kelvin in [1500.0, 3000.0, 5000.0, 6500.0, 9000.0] {
+            let found = temperature_of(color_temperature(kelvin));
+
+            assert!((found - kelvin).abs() < 25.0, "{found} for {kelvin}");
+        }
+    }
+}
@@ -0,0 +1,92 @@
+//! Simulations of color blindness, to preview colors as they appear to people with a
+//! color vision deficiency.
+
+use super::srgb::{from_linear, to_linear};
+
+use iced_core::Color;
+
+/// A color vision deficiency, simulated at full severity with the matrices of
+/// Machado, Oliveira and Fernandes (2009).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorBlindness {
+    /// Missing red cones.
+    Protanopia,
+    /// Missing green cones.
+    Deuteranopia,
+    /// Missing blue cones.
+    Tritanopia,
+}
+
+impl ColorBlindness {
+    /// Returns the color as it would appear with the deficiency, keeping its alpha.
+    pub fn simulate(self, color: Color) -> Color {
+        // The matrices work on linear light
+        let [r, g, b] = [color.r, color.g, color.b].map(to_linear);
+        let [r, g, b] = self
+            .matrix()
+            .map(|[mr, mg, mb]| from_linear((mr * r + mg * g + mb * b).clamp(0., 1.)));
+
+        Color { r, g, b, ..color }
+    }
+
+    /// The matrix simulating the deficiency, on linear sRGB.
+    fn matrix(self) -> [[f32; 3]; 3] {
+        match self {
+            ColorBlindness::Protanopia => [
+                [0.152_286, 1.052_583, -0.204_868],
+                [0.114_503, 0.786_281, 0.099_216],
+                [-0.003_882, -0.048_116, 1.051_998],
+            ],
+            ColorBlindness::Deuteranopia => [
+                [0.367_322, 0.860_646, -0.227_968],
+                [0.280_085, 0.672_501, 0.047_413],
+                [-0.011_820, 0.042_940, 0.968_881],
+            ],
+            ColorBlindness::Tritanopia => [
+                [1.255_528, -0.076_749, -0.178_779],
+                [-0.078_411, 0.930_809, 0.147_602],
+                [0.004_733, 0.691_367, 0.303_900],
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [ColorBlindness; 3] = [
+        ColorBlindness::Protanopia,
+        ColorBlindness::Deuteranopia,
+        ColorBlindness::Tritanopia,
+    ];
+
+    #[test]
+    fn rows_sum_to_1() {
+        for deficiency in ALL {
+            for row in deficiency.matrix() {
+                let sum: f32 = row.iter().sum();
+
+                assert!((sum - 1.0).abs() < 1e-4, "{deficiency:?}: {row:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn grays_are_invariant() {
+        for deficiency in ALL {
+            for gray in [0.0, 0.2, 0.5, 0.8, 1.0] {
+                let color = Color::from_rgba(gray, gray, gray, 0.5);
+                let simulated = deficiency.simulate(color);
+
+                for channel in [simulated.r, simulated.g, simulated.b] {
+                    assert!(
+                        (channel - gray).abs() < 1e-3,
+                        "{deficiency:?}: {simulated:?}"
+                    );
+                }
+                assert_eq!(simulated.a, 0.5);
+            }
+        }
+    }
+}